@@ -198,7 +198,13 @@ pub mod pallet {
 		type ProofLimit: Get<u32>;
 	}
 
+	/// The in-code storage version.
+	///
+	/// Increase when the layout of `PendingSwaps` changes, e.g. with the runtimes `SwapAction`
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -26,6 +26,14 @@ pub trait NetworkCustomApi<BlockHash> {
 	fn get_subnet_nodes_subnet_unconfirmed_count(&self, subnet_id: u32, at: Option<BlockHash>) -> RpcResult<u32>;
 	#[method(name = "network_getConsensusData")]
 	fn get_consensus_data(&self, subnet_id: u32, epoch: u32, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+	#[method(name = "network_getSubnetValidatorCommittee")]
+	fn get_subnet_validator_committee(&self, subnet_id: u32, epoch: u32, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+	#[method(name = "network_getSubnetValidatorSelection")]
+	fn get_subnet_validator_selection(&self, subnet_id: u32, block: u32, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+	#[method(name = "network_getSubnetValidatorProof")]
+	fn get_subnet_validator_proof(&self, subnet_id: u32, epoch: u32, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+	#[method(name = "network_getSubnetRewardsPreview")]
+	fn get_subnet_rewards_preview(&self, subnet_id: u32, epoch: u32, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 	#[method(name = "network_getMinimumSubnetNodes")]
 	fn get_minimum_subnet_nodes(&self, memory_mb: u128, at: Option<BlockHash>) -> RpcResult<u32>;
 	#[method(name = "network_getMinimumDelegateStake")]
@@ -121,6 +129,34 @@ where
 			Error::RuntimeError(format!("Unable to get consensus data: {:?}", e)).into()
 		})
 	}
+	fn get_subnet_validator_committee(&self, subnet_id: u32, epoch: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_subnet_validator_committee(at, subnet_id, epoch).map_err(|e| {
+			Error::RuntimeError(format!("Unable to get subnet validator committee: {:?}", e)).into()
+		})
+	}
+	fn get_subnet_validator_selection(&self, subnet_id: u32, block: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_subnet_validator_selection(at, subnet_id, block).map_err(|e| {
+			Error::RuntimeError(format!("Unable to get subnet validator selection: {:?}", e)).into()
+		})
+	}
+	fn get_subnet_validator_proof(&self, subnet_id: u32, epoch: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_subnet_validator_proof(at, subnet_id, epoch).map_err(|e| {
+			Error::RuntimeError(format!("Unable to get subnet validator proof: {:?}", e)).into()
+		})
+	}
+	fn get_subnet_rewards_preview(&self, subnet_id: u32, epoch: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_subnet_rewards_preview(at, subnet_id, epoch).map_err(|e| {
+			Error::RuntimeError(format!("Unable to get subnet rewards preview: {:?}", e)).into()
+		})
+	}
 	fn get_minimum_subnet_nodes(&self, memory_mb: u128, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
 		let api = self.client.runtime_api();
 		let at = at.unwrap_or_else(|| self.client.info().best_hash);
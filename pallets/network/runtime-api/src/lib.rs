@@ -29,6 +29,10 @@ sp_api::decl_runtime_apis! {
     fn get_subnet_nodes_submittable(subnet_id: u32) -> Vec<u8>;
     fn get_subnet_nodes_subnet_unconfirmed_count(subnet_id: u32) -> u32;
     fn get_consensus_data(subnet_id: u32, epoch: u32) -> Vec<u8>;
+    fn get_subnet_validator_committee(subnet_id: u32, epoch: u32) -> Vec<u8>;
+    fn get_subnet_validator_selection(subnet_id: u32, block: u32) -> Vec<u8>;
+    fn get_subnet_validator_proof(subnet_id: u32, epoch: u32) -> Vec<u8>;
+    fn get_subnet_rewards_preview(subnet_id: u32, epoch: u32) -> Vec<u8>;
     fn get_minimum_subnet_nodes(memory_mb: u128) -> u32;
     fn get_minimum_delegate_stake(memory_mb: u128) -> u128;
     fn get_subnet_node_info(subnet_id: u32) -> Vec<u8>;
@@ -94,6 +94,114 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
+  pub fn do_set_validator_selection_stake_cap(value: u128) -> DispatchResult {
+    ValidatorSelectionStakeCap::<T>::set(value);
+
+    Self::deposit_event(Event::SetValidatorSelectionStakeCap(value));
+
+    Ok(())
+  }
+
+  pub fn do_set_backup_validators_count(value: u32) -> DispatchResult {
+    ensure!(
+      value <= MaxValidatorCommitteeSize::<T>::get(),
+      Error::<T>::InvalidBackupValidatorsCount
+    );
+
+    // --- The last backup must be able to take over within the epoch
+    ensure!(
+      BackupValidatorBlockOffset::<T>::get().saturating_mul(value) < T::EpochLength::get(),
+      Error::<T>::InvalidBackupValidatorsCount
+    );
+
+    BackupValidatorsCount::<T>::set(value);
+
+    Self::deposit_event(Event::SetBackupValidatorsCount(value));
+
+    Ok(())
+  }
+
+  pub fn do_set_backup_validator_block_offset(value: u32) -> DispatchResult {
+    // --- The last backup must be able to take over within the epoch
+    ensure!(
+      value > 0 &&
+      value.saturating_mul(BackupValidatorsCount::<T>::get().max(1)) < T::EpochLength::get(),
+      Error::<T>::InvalidBackupValidatorBlockOffset
+    );
+
+    BackupValidatorBlockOffset::<T>::set(value);
+
+    Self::deposit_event(Event::SetBackupValidatorBlockOffset(value));
+
+    Ok(())
+  }
+
+  pub fn do_set_max_rewards_items_per_block(value: u32) -> DispatchResult {
+    ensure!(
+      value > 0,
+      Error::<T>::InvalidMaxRewardsItemsPerBlock
+    );
+
+    MaxRewardsItemsPerBlock::<T>::set(value);
+
+    Self::deposit_event(Event::SetMaxRewardsItemsPerBlock(value));
+
+    Ok(())
+  }
+
+  pub fn do_set_max_subnet_removal_items_per_block(value: u32) -> DispatchResult {
+    ensure!(
+      value > 0,
+      Error::<T>::InvalidMaxSubnetRemovalItemsPerBlock
+    );
+
+    MaxSubnetRemovalItemsPerBlock::<T>::set(value);
+
+    Self::deposit_event(Event::SetMaxSubnetRemovalItemsPerBlock(value));
+
+    Ok(())
+  }
+
+  pub fn do_set_rewards_history_epochs(value: u32) -> DispatchResult {
+    ensure!(
+      value > 0,
+      Error::<T>::InvalidRewardsHistoryEpochs
+    );
+
+    RewardsHistoryEpochs::<T>::set(value);
+
+    Self::deposit_event(Event::SetRewardsHistoryEpochs(value));
+
+    Ok(())
+  }
+
+  pub fn do_set_rewards_submission_history_epochs(value: u32) -> DispatchResult {
+    // --- Submissions are rewarded on the following epoch
+    ensure!(
+      value > 1,
+      Error::<T>::InvalidRewardsSubmissionHistoryEpochs
+    );
+
+    RewardsSubmissionHistoryEpochs::<T>::set(value);
+
+    Self::deposit_event(Event::SetRewardsSubmissionHistoryEpochs(value));
+
+    Ok(())
+  }
+
+  pub fn do_set_dissent_slash_percentage(value: u128) -> DispatchResult {
+    ensure!(
+      value > 0 && value <= Self::PERCENTAGE_FACTOR,
+      Error::<T>::InvalidDissentSlashPercentage
+    );
+
+    DissentSlashPercentage::<T>::set(value);
+
+    Self::deposit_event(Event::SetDissentSlashPercentage(value));
+
+    Ok(())
+  }
+
   pub fn do_set_max_subnet_nodes(value: u32) -> DispatchResult {
     ensure!(
       value > MinSubnetNodes::<T>::get(),
@@ -107,6 +215,27 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
+  pub fn do_set_node_delegate_slash_share(value: u128) -> DispatchResult {
+    ensure!(
+      value <= Self::PERCENTAGE_FACTOR,
+      Error::<T>::InvalidPercent
+    );
+
+    NodeDelegateSlashShare::<T>::set(value);
+
+    Self::deposit_event(Event::SetNodeDelegateSlashShare(value));
+
+    Ok(())
+  }
+
+  pub fn do_set_stake_transfer_period(value: u32) -> DispatchResult {
+    StakeTransferPeriod::<T>::set(value);
+
+    Self::deposit_event(Event::SetStakeTransferPeriod(value));
+
+    Ok(())
+  }
+
   pub fn do_set_tx_rate_limit(value: u32) -> DispatchResult {
     TxRateLimit::<T>::set(value);
 
@@ -29,10 +29,12 @@ use frame_benchmarking::v2::*;
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{EnsureOrigin, Get, OnInitialize, UnfilteredDispatchable},
+	weights::Weight,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_runtime::Vec;
 use sp_core::OpaquePeerId as PeerId;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use scale_info::prelude::vec;
 use scale_info::prelude::format;
 use sp_runtime::SaturatedConversion;
@@ -40,15 +42,17 @@ const SEED: u32 = 0;
 
 
 const DEFAULT_SCORE: u128 = 5000;
-const DEFAULT_SUBNET_MEM_MB: u128 = 50000;
-const DEFAULT_SUBNET_INIT_COST: u128 = 100e+18 as u128;
 const DEFAULT_SUBNET_PATH: &str = "petals-team/StableBeluga2";
 const DEFAULT_SUBNET_PATH_2: &str = "petals-team/StableBeluga3";
 const DEFAULT_SUBNET_NODE_STAKE: u128 = 1000e+18 as u128;
-const DEFAULT_SUBNET_REGISTRATION_BLOCKS: u64 = 130_000;
 const DEFAULT_STAKE_TO_BE_ADDED: u128 = 1000e+18 as u128;
 const DEFAULT_DELEGATE_STAKE_TO_BE_ADDED: u128 = 1000e+18 as u128;
 const DEFAULT_DEPOSIT_AMOUNT: u128 = 10000e+18 as u128;
+// --- Upper bounds of the epoch phase components
+const MAX_SUBNETS: u32 = 64;
+const MAX_SUBNET_NODES: u32 = 512;
+const MAX_DEACTIVATIONS: u32 = 128;
+const MAX_TOTAL_SUBNET_NODES: u32 = MAX_SUBNETS * MAX_SUBNET_NODES;
 
 pub type BalanceOf<T> = <T as Config>::Currency;
 
@@ -62,112 +66,121 @@ fn get_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	caller
 }
 
+// Subnet nodes of each subnet use the subnet ID as the seed so they are unique across subnets
+fn subnet_node_account<T: Config>(subnet_id: u32, index: u32) -> T::AccountId {
+	account("subnet_node_account", index, subnet_id)
+}
+
+fn fund<T: Config>(account: &T::AccountId, amount: u128) {
+	T::Currency::deposit_creating(account, amount.try_into().ok().expect("REASON"));
+}
+
 fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	let caller: T::AccountId = account(name, index, SEED);
 	// Give the account half of the maximum value of the `Balance` type.
 	// Otherwise some transfers will fail with an overflow error.
 	let deposit_amount: u128 = MinStakeBalance::<T>::get() + 10000;
-	T::Currency::deposit_creating(&caller, deposit_amount.try_into().ok().expect("REASON"));
+	fund::<T>(&caller, deposit_amount);
 	caller
 }
 
 fn funded_initializer<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	let caller: T::AccountId = account(name, index, SEED);
-	// Give the account half of the maximum value of the `Balance` type.
-	// Otherwise some transfers will fail with an overflow error.
-	let deposit_amount: u128 = Network::<T>::registration_cost(0) + 1000000;
-	T::Currency::deposit_creating(&caller, deposit_amount.try_into().ok().expect("REASON"));
+	let epoch: u32 = Network::<T>::get_current_epoch_as_u32();
+	let deposit_amount: u128 = Network::<T>::registration_cost(epoch) + 1000000;
+	fund::<T>(&caller, deposit_amount);
 	caller
 }
 
-fn get_min_subnet_nodes<T: Config>() -> u32 {
-	let base_node_memory: u128 = BaseSubnetNodeMemoryMB::<T>::get();
-	Network::<T>::get_min_subnet_nodes(base_node_memory, DEFAULT_SUBNET_MEM_MB)
+fn set_epoch<T: Config>(epoch: u32) {
+	let epoch_length: u32 = T::EpochLength::get();
+	frame_system::Pallet::<T>::set_block_number(u64_to_block::<T>(epoch as u64 * epoch_length as u64));
+}
+
+fn increase_epochs<T: Config>(epochs: u32) {
+	let epoch: u32 = Network::<T>::get_current_epoch_as_u32();
+	set_epoch::<T>(epoch + epochs);
 }
 
+// Registers and activates a subnet with ``end - start`` subnet nodes and returns the subnet ID
 fn build_activated_subnet<T: Config>(
 	subnet_path: Vec<u8>, 
 	start: u32, 
 	mut end: u32, 
 	deposit_amount: u128, 
 	amount: u128
-) {
-	let funded_initializer = funded_initializer::<T>("funded_initializer", 0);
-	let registration_blocks = MinSubnetRegistrationBlocks::<T>::get();
+) -> u32 {
+	// --- Subnets can only register on registration epochs
+	let epoch: u32 = Network::<T>::get_current_epoch_as_u32();
+	let next_registration_epoch: u32 = Network::<T>::get_next_registration_epoch(epoch);
+	increase_epochs::<T>(next_registration_epoch.saturating_sub(epoch));
+
+	let owner = funded_initializer::<T>("funded_initializer", 0);
+
+	let min_nodes = MinSubnetNodes::<T>::get();
+
+	if end == 0 {
+		end = min_nodes;
+	}
+
+	let subnet_id: u32 = TotalSubnetUids::<T>::get() + 1;
+
+	let mut whitelist: BTreeSet<T::AccountId> = BTreeSet::new();
+	for n in start..end {
+		whitelist.insert(subnet_node_account::<T>(subnet_id, n));
+	}
 
   let add_subnet_data = RegistrationSubnetData {
     path: subnet_path.clone().into(),
-    memory_mb: DEFAULT_SUBNET_MEM_MB,
-    registration_blocks: registration_blocks,
+    max_node_registration_epochs: 16,
+    node_registration_interval: 0,
+    node_activation_interval: 0,
+    node_queue_period: 1,
+    max_node_penalties: 3,
+    coldkey_whitelist: whitelist,
+    validator_args_type: None,
   };
 
   // --- Register subnet for activation
   assert_ok!(
     Network::<T>::register_subnet(
-      RawOrigin::Signed(funded_initializer.clone()).into(),
+      RawOrigin::Signed(owner.clone()).into(),
       add_subnet_data,
     )
   );
 
-  let subnet_id = SubnetPaths::<T>::get(subnet_path.clone()).unwrap();
-  let subnet = SubnetsData::<T>::get(subnet_id).unwrap();
-
-  let min_nodes = subnet.min_nodes;
-
-  if end == 0 {
-    end = min_nodes;
-  }
-
-  let epoch_length = T::EpochLength::get();
-  let epoch = get_current_block_as_u64::<T>() / epoch_length;
+  assert_eq!(SubnetPaths::<T>::get(subnet_path.clone()), Some(subnet_id));
 
   // --- Add subnet nodes
-  let block_number = get_current_block_as_u64::<T>();
   let mut amount_staked = 0;
   for n in start..end {
-		let subnet_node_account: T::AccountId = funded_account::<T>("subnet_node_account", n);
-		T::Currency::deposit_creating(&subnet_node_account, DEFAULT_STAKE_TO_BE_ADDED.try_into().ok().expect("REASON"));
+		let hotkey: T::AccountId = subnet_node_account::<T>(subnet_id, n);
+		fund::<T>(&hotkey, deposit_amount);
     amount_staked += amount;
     assert_ok!(
       Network::<T>::add_subnet_node(
-        RawOrigin::Signed(subnet_node_account.clone()).into(),
+        RawOrigin::Signed(hotkey.clone()).into(),
         subnet_id,
-        peer(n),
+        hotkey.clone(),
+        peer(subnet_id * MAX_SUBNET_NODES + n),
+        peer(subnet_id * MAX_SUBNET_NODES + n),
+        0,
         amount,
         None,
         None,
         None,
       ) 
     );
-		// subnet_nodes
-    let subnet_node_data = SubnetNodesData::<T>::try_get(subnet_id, subnet_node_account.clone()).unwrap();
-    assert_eq!(subnet_node_data.account_id, subnet_node_account.clone());
-    assert_eq!(subnet_node_data.hotkey, subnet_node_account.clone());
-    assert_eq!(subnet_node_data.peer_id, peer(n));
-    assert_eq!(subnet_node_data.initialized, block_number);
-    // --- Is ``Validator`` if registered before subnet activation
-    assert_eq!(subnet_node_data.classification.class, SubnetNodeClass::Validator);
-    assert!(subnet_node_data.has_classification(&SubnetNodeClass::Validator, epoch));
-
-    let subnet_node_account = SubnetNodeAccount::<T>::get(subnet_id, peer(n));
-    assert_eq!(subnet_node_account, subnet_node_account.clone());
-
-    let account_subnet_stake = AccountSubnetStake::<T>::get(subnet_node_account.clone(), subnet_id);
-    assert_eq!(account_subnet_stake, amount);
   }
 
   let total_subnet_stake = TotalSubnetStake::<T>::get(subnet_id);
   assert_eq!(total_subnet_stake, amount_staked);
 
-  let total_stake = TotalStake::<T>::get();
-  assert_eq!(total_subnet_stake, amount_staked);
-
-
-  let min_subnet_delegate_stake = Network::<T>::get_min_subnet_delegate_stake_balance(min_nodes);
   // --- Add the minimum required delegate stake balance to activate the subnet
-
-	let delegate_staker_account: T::AccountId = funded_account::<T>("subnet_node_account", 1);
-	T::Currency::deposit_creating(&delegate_staker_account, min_subnet_delegate_stake.try_into().ok().expect("REASON"));
+  // Add 100e18 to account for block increase on activation
+  let min_subnet_delegate_stake = Network::<T>::get_min_subnet_delegate_stake_balance() + 100e+18 as u128;
+	let delegate_staker_account: T::AccountId = get_account::<T>("delegate_staker_account", subnet_id);
+	fund::<T>(&delegate_staker_account, min_subnet_delegate_stake + 500);
   assert_ok!(
     Network::<T>::add_to_delegate_stake(
       RawOrigin::Signed(delegate_staker_account.clone()).into(),
@@ -176,50 +189,17 @@ fn build_activated_subnet<T: Config>(
     ) 
   );
 
-  let delegate_shares = AccountSubnetDelegateStakeShares::<T>::get(delegate_staker_account.clone(), subnet_id);
-  // 1000 is for inflation attack mitigation
-  assert_eq!(min_subnet_delegate_stake - 1000, delegate_shares);
-
-  // --- Increase blocks to max registration block
-	frame_system::Pallet::<T>::set_block_number(
-		frame_system::Pallet::<T>::block_number() + 
-		u64_to_block::<T>(subnet.registration_blocks + 1)
-	);
+  // --- Increase epochs to max registration epoch
+	increase_epochs::<T>(SubnetRegistrationEpochs::<T>::get() + 1);
 
-  let current_block_number = get_current_block_as_u64::<T>();
-  
   assert_ok!(
     Network::<T>::activate_subnet(
-      RawOrigin::Signed(funded_initializer.clone()).into(),
+      RawOrigin::Signed(owner.clone()).into(),
       subnet_id,
     )
   );
 
-  // --- Check validator chosen on activation
-  // let next_epoch = get_current_block_as_u64::<T>() / epoch_length + 1;
-  // let validator = SubnetRewardsValidator::<T>::get(subnet_id, next_epoch as u32);
-  // assert!(validator != None, "Validator is None");
-}
-
-// Returns total staked on subnet
-fn build_subnet_nodes<T: Config>(subnet_id: u32, start: u32, end: u32, amount: u128) -> u128 {
-  let mut amount_staked = 0;
-  for n in start..end {
-    let subnet_node = funded_account::<T>("subnet_node_account", n);
-    amount_staked += amount;
-    assert_ok!(
-      Network::<T>::add_subnet_node(
-        RawOrigin::Signed(subnet_node).into(),
-        subnet_id,
-        peer(n),
-        amount,
-				None,
-				None,
-				None,
-      ) 
-    );
-  }
-  amount_staked
+	subnet_id
 }
 
 fn subnet_node_data(start: u32, end: u32) -> Vec<SubnetNodeData> {
@@ -235,6 +215,55 @@ fn subnet_node_data(start: u32, end: u32) -> Vec<SubnetNodeData> {
   subnet_node_data
 }
 
+// Submits rewards data of ``data_len`` entries attested by every subnet node
+// The subnet nodes are the last entries so each subnet node searches the full data
+fn submit_rewards_data<T: Config>(subnet_id: u32, epoch: u32, data_len: u32) {
+	let total_subnet_nodes: u32 = TotalSubnetNodes::<T>::get(subnet_id);
+	let validator_id: u32 = SubnetRewardsValidator::<T>::get(subnet_id, epoch).unwrap();
+
+	let mut data: Vec<SubnetNodeData> = subnet_node_data(
+		0, 
+		data_len.saturating_sub(total_subnet_nodes)
+	);
+	let mut attests: BTreeMap<u32, u32> = BTreeMap::new();
+	for (subnet_node_id, subnet_node) in SubnetNodesData::<T>::iter_prefix(subnet_id) {
+		data.push(SubnetNodeData {
+			peer_id: subnet_node.peer_id,
+			score: DEFAULT_SCORE,
+		});
+		attests.insert(subnet_node_id, Network::<T>::get_current_block_as_u32());
+	}
+
+	SubnetRewardsSubmission::<T>::insert(
+		subnet_id, 
+		epoch, 
+		RewardsData {
+			validator_id: validator_id,
+			attests: attests,
+			data: data,
+			args: None,
+		}
+	);
+}
+
+// Chooses the validators of the current epoch and submits their rewards data, then moves to the next epoch
+// Returns the epoch to be rewarded
+fn build_rewards_epoch<T: Config>(data_len: u32) -> u32 {
+	let epoch_length: u32 = T::EpochLength::get();
+	let block: u32 = Network::<T>::get_current_block_as_u32();
+	let epoch: u32 = block / epoch_length;
+
+	Network::<T>::do_epoch_preliminaries(block, epoch, epoch_length);
+
+	for (subnet_id, _) in SubnetsData::<T>::iter() {
+		submit_rewards_data::<T>(subnet_id, epoch, data_len);
+	}
+
+	increase_epochs::<T>(1);
+
+	epoch
+}
+
 pub fn u64_to_block<T: frame_system::Config>(input: u64) -> BlockNumberFor<T> {
 	input.try_into().ok().expect("REASON")
 }
@@ -1181,95 +1210,43 @@ mod benchmarks {
 	// }
 
 	#[benchmark]
-	fn do_deactivation_ledger(x: Linear<0, 64>, d: Linear<0, 128>) {
-		let max_subnets: u32 = Network::<T>::max_subnets();
-		let n_peers: u32 = Network::<T>::max_subnet_nodes();
-
-		for s in 0..x {
-			let path: Vec<u8> = format!("model-name-{s}").into(); 
-			build_activated_subnet::<T>(path, 0, n_peers, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
-		}
+	fn do_deactivation_ledger(x: Linear<0, MAX_SUBNETS>, d: Linear<0, MAX_DEACTIVATIONS>) {
+		// --- Spread the deactivations across the subnets
+		let n_peers: u32 = if x == 0 { 0 } else { d.div_ceil(x) };
 
-		let epoch_length = T::EpochLength::get();
-		let block_number = get_current_block_as_u64::<T>();
-		let epoch = get_current_block_as_u64::<T>() / epoch_length as u64;
-
-		// Insert validator and validate
-		for s in 0..x {
-			let path: Vec<u8> = format!("model-name-{s}").into(); 
-			let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(path).unwrap();
-
-			let subnet_node_account: T::AccountId = get_account::<T>("subnet_node_account", 0);
-			SubnetRewardsValidator::<T>::insert(subnet_id, epoch as u32, subnet_node_account.clone());
-			let subnet_node_data_vec = subnet_node_data(0, n_peers);
-			assert_ok!(
-				Network::<T>::validate(
-					RawOrigin::Signed(subnet_node_account.clone()).into(), 
-					subnet_id, 
-					subnet_node_data_vec.clone(),
-					None,
-				)
-			);		
-		}
-
-		let epoch = get_current_block_as_u64::<T>() / epoch_length as u64;
-
-		// Attest so node can be in the deactivate ledger
+		let mut subnet_ids: Vec<u32> = Vec::new();
 		for s in 0..x {
 			let path: Vec<u8> = format!("model-name-{s}").into(); 
-			let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(path).unwrap();
-
-			for n in 0..n_peers {
-				if n == 0 {
-					continue
-				}	
-				let subnet_node_account: T::AccountId = get_account::<T>("subnet_node_account", n);
-        assert_ok!(
-          Network::<T>::attest(
-            RawOrigin::Signed(subnet_node_account.clone()).into(), 
-            subnet_id,
-          )
-        );
-			}
+			let subnet_id = build_activated_subnet::<T>(path, 0, n_peers.max(1), DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+			subnet_ids.push(subnet_id);
 		}
 
-		// let path: Vec<u8> = "model-name-{0}".into(); 
-		// let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(path).unwrap();
-		let mut i = 0;
-
-		for s in 0..x {
-			let path: Vec<u8> = format!("model-name-{s}").into(); 
-			let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(path).unwrap();
-
-			for n in 0..d {
-				if i == 128 {
+		// --- Attestors and validators are deactivated through the ledger
+		let mut deactivation_ledger: BTreeSet<SubnetNodeDeactivation> = BTreeSet::new();
+		for subnet_id in subnet_ids.iter() {
+			for (subnet_node_id, _) in SubnetNodesData::<T>::iter_prefix(subnet_id) {
+				if deactivation_ledger.len() as u32 == d {
 					break
 				}
-				let subnet_node_account: T::AccountId = get_account::<T>("subnet_node_account", n);
-				assert_ok!(
-					Network::<T>::deactivate_subnet_node(
-						RawOrigin::Signed(subnet_node_account.clone()).into(),
-						subnet_id,
-					)
+				deactivation_ledger.insert(
+					SubnetNodeDeactivation {
+						subnet_id: *subnet_id,
+						subnet_node_id: subnet_node_id,
+					}
 				);
-				i += 1;
 			}
 		}
+		DeactivationLedger::<T>::set(deactivation_ledger.clone());
 
 		#[block]
 		{
 			Network::<T>::do_deactivation_ledger();
 		}
 
-		for s in 0..x {
-			let path: Vec<u8> = format!("model-name-{s}").into(); 
-			let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(path).unwrap();
-
-			for n in 0..n_peers {
-				let subnet_node_account: T::AccountId = get_account::<T>("subnet_node_account", n);
-				let subnet_node = SubnetNodesData::<T>::get(subnet_id, subnet_node_account.clone());
-				// assert_eq!(subnet_node.classification.class, SubnetNodeClass::Deactivated);		
-			}
+		assert!(DeactivationLedger::<T>::get().is_empty());
+		for data in deactivation_ledger.iter() {
+			let subnet_node = SubnetNodesData::<T>::get(data.subnet_id, data.subnet_node_id);
+			assert_eq!(subnet_node.classification.class, SubnetNodeClass::Deactivated);
 		}
 	}
 
@@ -1563,5 +1540,134 @@ mod benchmarks {
 	// 	}
 	// }
 
-	impl_benchmark_test_suite!(Network, crate::mock::new_test_ext(), crate::mock::Test);
+	#[benchmark]
+	fn begin_reward_subnets(s: Linear<0, MAX_SUBNETS>) {
+		for i in 0..s {
+			let path: Vec<u8> = format!("model-name-{i}").into(); 
+			build_activated_subnet::<T>(path, 0, 0, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		}
+
+		let block: u32 = Network::<T>::get_current_block_as_u32();
+		let epoch: u32 = Network::<T>::get_current_epoch_as_u32();
+
+		#[block]
+		{
+			Network::<T>::begin_reward_subnets(block, epoch);
+		}
+
+		assert_eq!(EpochRewardsCursor::<T>::get().unwrap().subnets.len() as u32, s);
+	}
+
+	#[benchmark]
+	fn reward_subnet(n: Linear<1, MAX_SUBNET_NODES>, d: Linear<1, MAX_SUBNET_NODES>) {
+		let path: Vec<u8> = DEFAULT_SUBNET_PATH.into(); 
+		let subnet_id = build_activated_subnet::<T>(path, 0, n.max(MinSubnetNodes::<T>::get()), DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+
+		let epoch: u32 = build_rewards_epoch::<T>(d);
+		Network::<T>::begin_reward_subnets(Network::<T>::get_current_block_as_u32(), epoch);
+
+		#[block]
+		{
+			Network::<T>::do_reward_subnets_step(1, Weight::MAX);
+		}
+
+		// --- Subnet nodes are rewarded next
+		let subnet_cursor = EpochRewardsCursor::<T>::get().unwrap().subnet.unwrap();
+		assert_eq!(subnet_cursor.subnet_id, subnet_id);
+		assert_eq!(subnet_cursor.subnet_node_id, None);
+	}
+
+	#[benchmark]
+	fn reward_subnet_node(d: Linear<1, MAX_SUBNET_NODES>) {
+		let path: Vec<u8> = DEFAULT_SUBNET_PATH.into(); 
+		let subnet_id = build_activated_subnet::<T>(path, 0, MinSubnetNodes::<T>::get().max(1), DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+
+		let epoch: u32 = build_rewards_epoch::<T>(d);
+		Network::<T>::begin_reward_subnets(Network::<T>::get_current_block_as_u32(), epoch);
+
+		// --- Reward the subnet so the next item is its first subnet node
+		Network::<T>::do_reward_subnets_step(1, Weight::MAX);
+
+		let hotkey: T::AccountId = subnet_node_account::<T>(subnet_id, 0);
+		let stake_before: u128 = AccountSubnetStake::<T>::get(&hotkey, subnet_id);
+
+		#[block]
+		{
+			Network::<T>::do_reward_subnets_step(1, Weight::MAX);
+		}
+
+		assert!(AccountSubnetStake::<T>::get(&hotkey, subnet_id) > stake_before);
+	}
+
+	#[benchmark]
+	fn do_epoch_preliminaries(s: Linear<1, MAX_SUBNETS>, n: Linear<1, MAX_TOTAL_SUBNET_NODES>) {
+		// --- Spread the subnet nodes across the subnets
+		let n: u32 = n.clamp(s, s * MAX_SUBNET_NODES);
+
+		let mut subnet_ids: Vec<u32> = Vec::new();
+		for i in 0..s {
+			let path: Vec<u8> = format!("model-name-{i}").into(); 
+			let subnet_nodes: u32 = n / s + if i < n % s { 1 } else { 0 };
+			let subnet_id = build_activated_subnet::<T>(path, 0, subnet_nodes, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+			subnet_ids.push(subnet_id);
+		}
+
+		let epoch_length: u32 = T::EpochLength::get();
+		let block: u32 = Network::<T>::get_current_block_as_u32();
+		let epoch: u32 = block / epoch_length;
+
+		#[block]
+		{
+			Network::<T>::do_epoch_preliminaries(block, epoch, epoch_length);
+		}
+
+		for subnet_id in subnet_ids.iter() {
+			assert!(SubnetRewardsValidator::<T>::get(subnet_id, epoch).is_some());
+		}
+	}
+
+	#[benchmark]
+	fn do_queue(s: Linear<1, MAX_SUBNETS>) {
+		let mut subnet_ids: Vec<u32> = Vec::new();
+		for i in 0..s {
+			let path: Vec<u8> = format!("model-name-{i}").into(); 
+			let subnet_id = build_activated_subnet::<T>(path, 0, 0, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+			subnet_ids.push(subnet_id);
+		}
+
+		// --- Queue a subnet node on each subnet that is past the queue epochs
+		increase_epochs::<T>(16);
+
+		for subnet_id in subnet_ids.iter() {
+			let mut subnet_node = SubnetNodesData::<T>::get(subnet_id, 1);
+			subnet_node.id = TotalSubnetNodeUids::<T>::get(subnet_id) + 1;
+			subnet_node.classification = SubnetNodeClassification {
+				class: SubnetNodeClass::Queue,
+				start_epoch: 0,
+			};
+
+			let mut queue: BTreeMap<u32, SubnetNode<T::AccountId>> = BTreeMap::new();
+			queue.insert(subnet_node.id, subnet_node);
+			QueuedSubnetNodes::<T>::insert(subnet_id, queue);
+		}
+
+		// --- Move to a block open to activating from the queue
+		let epoch_length: u32 = T::EpochLength::get();
+		let churn_limit: u32 = Network::<T>::get_subnet_churn_limit(subnet_ids[0]).min(epoch_length);
+		let registration_interval: u32 = epoch_length / churn_limit;
+		let block: u32 = Network::<T>::get_current_block_as_u32();
+		let block: u32 = block - block % registration_interval + registration_interval;
+		frame_system::Pallet::<T>::set_block_number(u64_to_block::<T>(block as u64));
+
+		#[block]
+		{
+			Network::<T>::do_queue(block);
+		}
+
+		for subnet_id in subnet_ids.iter() {
+			assert!(QueuedSubnetNodes::<T>::get(subnet_id).is_empty());
+		}
+	}
+
+	impl_benchmark_test_suite!(Network, crate::tests::mock::build_test_ext(), crate::tests::mock::Test);
 }
@@ -1,2 +1,3 @@
 use super::*;
-pub mod subnet_validator;
\ No newline at end of file
+pub mod subnet_validator;
+pub mod validator_args;
\ No newline at end of file
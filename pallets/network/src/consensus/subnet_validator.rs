@@ -16,8 +16,14 @@
 use super::*;
 use frame_support::pallet_prelude::DispatchResultWithPostInfo;
 use frame_support::pallet_prelude::Pays;
+use sp_runtime::traits::Hash;
+use sp_runtime::DispatchError;
 
 impl<T: Config> Pallet<T> {
+  // Max sum of the scores in a submission
+  // Scores are multiplied by `PERCENTAGE_FACTOR` when divvying rewards
+  pub const MAX_SCORE_SUM: u128 = u128::MAX / Self::PERCENTAGE_FACTOR;
+
   /// Submit subnet scores per subnet node
   /// Validator of the epoch receives rewards when attestation passes consensus
   pub fn do_validate(
@@ -26,28 +32,285 @@ impl<T: Config> Pallet<T> {
     block: u32, 
     epoch_length: u32,
     epoch: u32,
-    mut data: Vec<SubnetNodeData>,
+    data: Vec<SubnetNodeData>,
+    args: Option<BoundedVec<u8, DefaultValidatorArgsLimit>>,
+  ) -> DispatchResultWithPostInfo {
+    // --- Commit-reveal subnets must submit through ``commit_validate`` and ``reveal_validate``
+    ensure!(
+      !SubnetCommitReveal::<T>::get(subnet_id),
+      Error::<T>::CommitRevealEnabled
+    );
+
+    Self::ensure_rewards_data(&data)?;
+
+    let validator_id = Self::ensure_epoch_validator(subnet_id, &hotkey, block, epoch)?;
+
+    Self::perform_validate(
+      subnet_id, 
+      validator_id,
+      hotkey,
+      block,
+      epoch,
+      data,
+      args,
+    )
+  }
+
+  /// Commit to the subnet scores of the epoch without exposing them
+  /// The data is revealed using ``do_reveal_validate``
+  pub fn do_commit_validate(
+    subnet_id: u32, 
+    hotkey: T::AccountId,
+    block: u32, 
+    epoch: u32,
+    commitment: T::Hash,
+  ) -> DispatchResultWithPostInfo {
+    ensure!(
+      SubnetCommitReveal::<T>::get(subnet_id),
+      Error::<T>::CommitRevealNotEnabled
+    );
+
+    let validator_id = Self::ensure_epoch_validator(subnet_id, &hotkey, block, epoch)?;
+
+    // --- Ensure not committed or submitted already
+    ensure!(
+      !SubnetRewardsCommit::<T>::contains_key((subnet_id, epoch, validator_id)) &&
+      !SubnetCommitteeSubmissions::<T>::get(subnet_id, epoch).contains_key(&validator_id),
+      Error::<T>::SubnetRewardsAlreadyCommitted
+    );
+
+    SubnetRewardsCommit::<T>::insert(
+      (subnet_id, epoch, validator_id),
+      RewardsCommit {
+        commitment: commitment,
+        block: block,
+      }
+    );
+
+    Self::deposit_event(
+      Event::ValidatorCommit { 
+        subnet_id: subnet_id, 
+        account_id: hotkey, 
+        epoch: epoch,
+      }
+    );
+
+    Ok(Pays::No.into())
+  }
+
+  /// Reveal the committed subnet scores of the epoch
+  /// On success the data becomes the epochs submission and can be attested
+  pub fn do_reveal_validate(
+    subnet_id: u32, 
+    hotkey: T::AccountId,
+    block: u32, 
+    epoch: u32,
+    data: Vec<SubnetNodeData>,
     args: Option<BoundedVec<u8, DefaultValidatorArgsLimit>>,
+    salt: Vec<u8>,
   ) -> DispatchResultWithPostInfo {
-    // TODO: Add max sum to avoid overflow
+    // --- Commitments left from before the owner disabled commit-reveal can't be revealed
+    ensure!(
+      SubnetCommitReveal::<T>::get(subnet_id),
+      Error::<T>::CommitRevealNotEnabled
+    );
+
+    Self::ensure_rewards_data(&data)?;
+
+    let validator_id = Self::ensure_epoch_validator(subnet_id, &hotkey, block, epoch)?;
+
+    let commit = SubnetRewardsCommit::<T>::get((subnet_id, epoch, validator_id)).ok_or(Error::<T>::SubnetRewardsNotCommitted)?;
+
+    // --- The commitment must be public for at least one block before the data is
+    ensure!(
+      block > commit.block,
+      Error::<T>::RevealTooEarly
+    );
+
+    ensure!(
+      Self::get_rewards_commitment(&data, &args, &salt) == commit.commitment,
+      Error::<T>::InvalidRewardsReveal
+    );
+
+    SubnetRewardsCommit::<T>::remove((subnet_id, epoch, validator_id));
+
+    Self::perform_validate(
+      subnet_id, 
+      validator_id,
+      hotkey,
+      block,
+      epoch,
+      data,
+      args,
+    )
+  }
+
+  /// Ensure the rewards data is within `MaxSubnetNodes` and includes each peer once
+  pub fn ensure_rewards_data(data: &Vec<SubnetNodeData>) -> DispatchResult {
+    ensure!(
+      data.len() as u32 <= MaxSubnetNodes::<T>::get(),
+      Error::<T>::InvalidRewardsDataLength
+    );
+
+    let peer_ids: BTreeSet<&PeerId> = data.iter().map(|x| &x.peer_id).collect();
+    ensure!(
+      peer_ids.len() == data.len(),
+      Error::<T>::DuplicateRewardsData
+    );
+
+    Ok(())
+  }
+
+  /// Hash of the rewards data a validator commits to on commit-reveal subnets
+  pub fn get_rewards_commitment(
+    data: &Vec<SubnetNodeData>,
+    args: &Option<BoundedVec<u8, DefaultValidatorArgsLimit>>,
+    salt: &Vec<u8>,
+  ) -> T::Hash {
+    T::Hashing::hash_of(&(data, args, salt))
+  }
+
+  /// Ensure ``hotkey`` is in the validator committee of the epoch, or an active backup validator,
+  /// and return its subnet node ID
+  pub fn ensure_epoch_validator(
+    subnet_id: u32, 
+    hotkey: &T::AccountId,
+    block: u32,
+    epoch: u32,
+  ) -> Result<u32, DispatchError> {
+    let validator_id = HotkeySubnetNodeId::<T>::get(subnet_id, hotkey).ok_or(Error::<T>::InvalidValidator)?;
 
     // --- Ensure current subnet validator by its hotkey
-    let validator_id = SubnetRewardsValidator::<T>::get(subnet_id, epoch).ok_or(Error::<T>::InvalidValidator)?;
+    if Self::get_validator_committee(subnet_id, epoch).contains(&validator_id) {
+      return Ok(validator_id)
+    }
+
+    // --- Backups take over in rank order, each after another ``BackupValidatorBlockOffset`` blocks
+    let rank: u32 = SubnetBackupValidators::<T>::get(subnet_id, epoch)
+      .iter()
+      .position(|x| *x == validator_id)
+      .ok_or(Error::<T>::InvalidValidator)? as u32;
+
+    let epoch_start_block: u32 = epoch.saturating_mul(T::EpochLength::get());
+    let backup_start_block: u32 = epoch_start_block.saturating_add(
+      BackupValidatorBlockOffset::<T>::get().saturating_mul(rank.saturating_add(1))
+    );
 
-    // --- If hotkey is hotkey, ensure it matches validator, otherwise if coldkey -> get hotkey
     ensure!(
-      SubnetNodeIdHotkey::<T>::get(subnet_id, validator_id) == Some(hotkey.clone()),
-      Error::<T>::InvalidValidator
+      block >= backup_start_block,
+      Error::<T>::BackupValidatorNotActive
     );
 
-    // --- Ensure not submitted already
+    // --- Backups can only submit if no validator has
     ensure!(
       !SubnetRewardsSubmission::<T>::contains_key(subnet_id, epoch),
       Error::<T>::SubnetRewardsAlreadySubmitted
     );
 
-    // Remove duplicates based on peer_id
-    data.dedup_by(|a, b| a.peer_id == b.peer_id);
+    Ok(validator_id)
+  }
+
+  /// Block the committee submissions of the epoch close at, when backups can start taking over
+  pub fn get_committee_submission_end_block(epoch: u32) -> u32 {
+    epoch
+      .saturating_mul(T::EpochLength::get())
+      .saturating_add(BackupValidatorBlockOffset::<T>::get())
+  }
+
+  /// If other nodes can attest or dissent, once every committee member submitted or the
+  /// committee submissions closed
+  ///
+  /// Committee submissions change the combined data, so they must close before it's attested
+  pub fn is_committee_submission_closed(subnet_id: u32, epoch: u32, block: u32) -> bool {
+    if block >= Self::get_committee_submission_end_block(epoch) {
+      return true
+    }
+
+    let submissions: BTreeMap<u32, Vec<SubnetNodeData>> = SubnetCommitteeSubmissions::<T>::get(subnet_id, epoch);
+    Self::get_validator_committee(subnet_id, epoch)
+      .iter()
+      .all(|validator_id| submissions.contains_key(validator_id))
+  }
+
+  /// Get the validator committee of the epoch
+  /// Falls back to the ``SubnetRewardsValidator`` for epochs chosen without a committee
+  pub fn get_validator_committee(subnet_id: u32, epoch: u32) -> Vec<u32> {
+    match SubnetValidatorCommittee::<T>::try_get(subnet_id, epoch) {
+      Ok(committee) => committee,
+      Err(()) => SubnetRewardsValidator::<T>::get(subnet_id, epoch).into_iter().collect(),
+    }
+  }
+
+  /// Combine the committee submissions into one view of the subnet
+  ///
+  /// Each subnet node score is the stake-weighted median of the committee members scores. A member
+  /// that left a node out of its data votes for its exclusion, so a node is only included if
+  /// members holding at least half of the committee stake included it.
+  pub fn aggregate_committee_submissions(
+    subnet_id: u32,
+    submissions: &BTreeMap<u32, Vec<SubnetNodeData>>,
+  ) -> Vec<SubnetNodeData> {
+    let mut members: Vec<(u128, BTreeMap<PeerId, u128>)> = Vec::new();
+    let mut peer_ids: BTreeSet<PeerId> = BTreeSet::new();
+
+    for (validator_id, data) in submissions {
+      let stake: u128 = match SubnetNodeIdHotkey::<T>::try_get(subnet_id, validator_id) {
+        Ok(hotkey) => AccountSubnetStake::<T>::get(&hotkey, subnet_id),
+        Err(()) => 0,
+      };
+
+      let scores: BTreeMap<PeerId, u128> = data
+        .iter()
+        .map(|x| (x.peer_id.clone(), x.score))
+        .collect();
+
+      peer_ids.extend(scores.keys().cloned());
+      // --- Members count at least 1 in case of no stake balance
+      members.push((stake.max(1), scores));
+    }
+
+    peer_ids
+      .into_iter()
+      .filter_map(|peer_id| {
+        let values: Vec<(Option<u128>, u128)> = members
+          .iter()
+          .map(|(weight, scores)| (scores.get(&peer_id).copied(), *weight))
+          .collect();
+
+        Self::get_weighted_median(values).map(|score| SubnetNodeData {
+          peer_id: peer_id,
+          score: score,
+        })
+      })
+      .collect()
+  }
+
+  fn perform_validate(
+    subnet_id: u32, 
+    validator_id: u32,
+    hotkey: T::AccountId,
+    block: u32, 
+    epoch: u32,
+    mut data: Vec<SubnetNodeData>,
+    args: Option<BoundedVec<u8, DefaultValidatorArgsLimit>>,
+  ) -> DispatchResultWithPostInfo {
+    Self::ensure_validator_args(subnet_id, &args)?;
+
+    let mut submissions: BTreeMap<u32, Vec<SubnetNodeData>> = SubnetCommitteeSubmissions::<T>::get(subnet_id, epoch);
+
+    // --- Ensure not submitted already
+    ensure!(
+      !submissions.contains_key(&validator_id),
+      Error::<T>::SubnetRewardsAlreadySubmitted
+    );
+
+    let previous: Option<RewardsData> = SubnetRewardsSubmission::<T>::get(subnet_id, epoch);
+
+    // --- Late committee members can't change data that may already be attested
+    ensure!(
+      previous.is_none() || block < Self::get_committee_submission_end_block(epoch),
+      Error::<T>::CommitteeSubmissionClosed
+    );
 
     // Remove queue classified entries
     // Each peer must have an inclusion classification at minimum
@@ -76,18 +339,47 @@ impl<T: Config> Pallet<T> {
       data.len() as u32 <= included_nodes_count as u32,
       Error::<T>::InvalidRewardsDataLength
     );
+
+    // --- Ensure the sum of the scores can't overflow when divvying rewards
+    let score_sum: Option<u128> = data
+      .iter()
+      .try_fold(0u128, |acc, x| acc.checked_add(x.score));
+    ensure!(
+      score_sum.map_or(false, |sum| sum <= Self::MAX_SCORE_SUM),
+      Error::<T>::InvalidScoreSum
+    );
     
-    // --- Validator auto-attests the epoch
-    let mut attests: BTreeMap<u32, u32> = BTreeMap::new();
-    attests.insert(validator_id, block);
+    submissions.insert(validator_id, data);
+
+    // --- Committee members auto-attest the epoch
+    // Other nodes can only attest or dissent once the committee submissions close, so the
+    // combined data doesn't change after it's attested
+    let mut attests: BTreeMap<u32, u32> = previous
+      .as_ref()
+      .map(|x| x.attests.clone())
+      .unwrap_or_default();
+    attests.entry(validator_id).or_insert(block);
+
+    // --- The primary validator of the epoch, or the backup validator that took over
+    let primary_validator_id: u32 = SubnetRewardsValidator::<T>::get(subnet_id, epoch).unwrap_or(validator_id);
+    let submission_validator_id: u32 = match &previous {
+      Some(previous) if !submissions.contains_key(&primary_validator_id) => previous.validator_id,
+      None if !submissions.contains_key(&primary_validator_id) => validator_id,
+      _ => primary_validator_id,
+    };
 
     let rewards_data: RewardsData = RewardsData {
-      validator_id: validator_id,
+      validator_id: submission_validator_id,
       attests: attests,
-      data: data,
-      args: args,
+      data: Self::aggregate_committee_submissions(subnet_id, &submissions),
+      // --- First submitted args are kept
+      args: match previous {
+        Some(previous) => previous.args,
+        None => args,
+      },
     };
 
+    SubnetCommitteeSubmissions::<T>::insert(subnet_id, epoch, submissions);
     SubnetRewardsSubmission::<T>::insert(subnet_id, epoch, rewards_data);
   
     Self::deposit_event(
@@ -128,6 +420,23 @@ impl<T: Config> Pallet<T> {
       Err(()) => return Err(Error::<T>::SubnetNodeNotExist.into()),
     };
 
+    // --- Commit-reveal subnets can only be attested once the data is revealed
+    ensure!(
+      SubnetRewardsSubmission::<T>::contains_key(subnet_id, epoch) ||
+      SubnetRewardsCommit::<T>::iter_key_prefix((subnet_id, epoch)).next().is_none(),
+      Error::<T>::SubnetRewardsNotRevealed
+    );
+
+    ensure!(
+      Self::is_committee_submission_closed(subnet_id, epoch, block),
+      Error::<T>::CommitteeSubmissionOpen
+    );
+
+    ensure!(
+      !SubnetRewardsDissents::<T>::get(subnet_id, epoch).contains_key(&subnet_node_id),
+      Error::<T>::AlreadyDissented
+    );
+
     SubnetRewardsSubmission::<T>::try_mutate_exists(
       subnet_id,
       epoch,
@@ -153,6 +462,70 @@ impl<T: Config> Pallet<T> {
     Ok(Pays::No.into())
   }
 
+  /// Dissent from validator subnet rewards data
+  // Dissents tell apart a wrong validator from offline subnet nodes
+  pub fn do_dissent(
+    subnet_id: u32, 
+    hotkey: T::AccountId,
+    epoch: u32,
+    reason: Option<T::Hash>,
+  ) -> DispatchResultWithPostInfo {
+    // --- Ensure subnet node exists under hotkey
+    let subnet_node_id = match HotkeySubnetNodeId::<T>::try_get(
+      subnet_id, 
+      &hotkey
+    ) {
+      Ok(subnet_node_id) => subnet_node_id,
+      Err(()) => return Err(Error::<T>::SubnetNodeNotExist.into()),
+    };
+
+    // --- Ensure node classified to dissent
+    match SubnetNodesData::<T>::try_get(
+      subnet_id, 
+      subnet_node_id
+    ) {
+      Ok(subnet_node) => ensure!(
+        subnet_node.has_classification(&SubnetNodeClass::Validator, epoch),
+        Error::<T>::SubnetNodeNotActivated
+      ),
+      Err(()) => return Err(Error::<T>::SubnetNodeNotExist.into()),
+    };
+
+    // --- Ensure there is data to dissent from
+    let submission: RewardsData = SubnetRewardsSubmission::<T>::get(subnet_id, epoch)
+      .ok_or(Error::<T>::InvalidSubnetRewardsSubmission)?;
+
+    ensure!(
+      Self::is_committee_submission_closed(subnet_id, epoch, Self::get_current_block_as_u32()),
+      Error::<T>::CommitteeSubmissionOpen
+    );
+
+    ensure!(
+      !submission.attests.contains_key(&subnet_node_id),
+      Error::<T>::AlreadyAttested
+    );
+
+    SubnetRewardsDissents::<T>::try_mutate(
+      subnet_id,
+      epoch,
+      |dissents| -> DispatchResult {
+        ensure!(dissents.insert(subnet_node_id, reason) == None, Error::<T>::AlreadyDissented);
+        Ok(())
+      }
+    )?;
+
+    Self::deposit_event(
+      Event::Dissent { 
+        subnet_id: subnet_id, 
+        account_id: hotkey, 
+        epoch: epoch,
+        reason: reason,
+      }
+    );
+
+    Ok(Pays::No.into())
+  }
+
   pub fn choose_validator(
     block: u32,
     subnet_id: u32,
@@ -177,14 +550,92 @@ impl<T: Config> Pallet<T> {
       return
     }
 
-    // --- n-1 to get 0 index in the randomization
-    let rand_index = Self::get_random_number(subnet_nodes_len as u32, block as u32);
+    let (committee, backups) = Self::select_validator_committee(block, subnet_id, subnet_node_ids);
 
-    // --- Choose random accountant from eligible accounts
-    let validator: &u32 = &subnet_node_ids[rand_index as usize];
+    // --- The first member is the primary validator
+    let validator: u32 = match committee.first() {
+      Some(validator) => *validator,
+      None => return,
+    };
 
     // --- Insert validator for next epoch
     SubnetRewardsValidator::<T>::insert(subnet_id, epoch, validator);
+    SubnetValidatorCommittee::<T>::insert(subnet_id, epoch, committee);
+    SubnetBackupValidators::<T>::insert(subnet_id, epoch, backups);
+
+    // --- Store the randomness for audits
+    let (randomness, known_since) = Self::get_random_proof(block);
+    SubnetValidatorSelectionProof::<T>::insert(
+      subnet_id,
+      epoch,
+      ValidatorSelectionProof {
+        block: block,
+        randomness: randomness,
+        known_since: known_since,
+      }
+    );
+  }
+
+  /// Select the validator committee and the ranked backup validators from ``subnet_node_ids``
+  /// weighted by each nodes selection weight
+  /// The draw is reproducible from the ``block`` seed
+  pub fn select_validator_committee(
+    block: u32,
+    subnet_id: u32,
+    subnet_node_ids: Vec<u32>,
+  ) -> (Vec<u32>, Vec<u32>) {
+    let committee_size: u32 = SubnetValidatorCommitteeSize::<T>::get(subnet_id)
+      .max(1)
+      .min(subnet_node_ids.len() as u32);
+    let draws: u32 = committee_size
+      .saturating_add(BackupValidatorsCount::<T>::get())
+      .min(subnet_node_ids.len() as u32);
+
+    let mut candidates: Vec<(u32, u128)> = subnet_node_ids
+      .into_iter()
+      .map(|subnet_node_id| (subnet_node_id, Self::get_validator_selection_weight(subnet_id, subnet_node_id)))
+      .collect();
+    let mut committee: Vec<u32> = Vec::new();
+
+    for n in 0..draws {
+      let seed: u32 = block.saturating_add(n);
+      let total_weight: u128 = candidates.iter().fold(0u128, |acc, x| acc.saturating_add(x.1));
+
+      let index: usize = if total_weight == 0 {
+        // --- Uniform draw if no candidate has a balance
+        Self::get_random_number(candidates.len() as u32, seed) as usize
+      } else {
+        let target: u128 = Self::get_random_u128(total_weight, seed);
+        let mut cumulative_weight: u128 = 0;
+        candidates
+          .iter()
+          .position(|(_, weight)| {
+            cumulative_weight = cumulative_weight.saturating_add(*weight);
+            target < cumulative_weight
+          })
+          .unwrap_or(candidates.len() - 1)
+      };
+
+      // --- Choose random accountant from eligible accounts
+      committee.push(candidates.swap_remove(index).0);
+    }
+
+    let backups: Vec<u32> = committee.split_off(committee_size as usize);
+
+    (committee, backups)
+  }
+
+  /// Get the weight of a subnet node when choosing validators
+  /// This is the nodes stake plus its node delegate stake balance, capped by ``ValidatorSelectionStakeCap``
+  pub fn get_validator_selection_weight(subnet_id: u32, subnet_node_id: u32) -> u128 {
+    let stake: u128 = match SubnetNodeIdHotkey::<T>::try_get(subnet_id, subnet_node_id) {
+      Ok(hotkey) => AccountSubnetStake::<T>::get(&hotkey, subnet_id),
+      Err(()) => 0,
+    };
+
+    stake
+      .saturating_add(TotalNodeDelegateStakeBalance::<T>::get(subnet_id, subnet_node_id))
+      .min(ValidatorSelectionStakeCap::<T>::get())
   }
 
   /// Return the validators reward that submitted data on the previous epoch
@@ -206,7 +657,11 @@ impl<T: Config> Pallet<T> {
   ) {
     // We never ensure balance is above 0 because any hotkey chosen must have the target stake
     // balance at a minimum
-    let hotkey = SubnetNodeIdHotkey::<T>::get(subnet_id, subnet_node_id).unwrap();
+    // --- Subnet nodes removed since they were chosen have nothing left to slash
+    let hotkey = match SubnetNodeIdHotkey::<T>::try_get(subnet_id, subnet_node_id) {
+      Ok(hotkey) => hotkey,
+      Err(()) => return,
+    };
 
     // --- Get stake balance
     // This could be greater than the target stake balance
@@ -230,6 +685,24 @@ impl<T: Config> Pallet<T> {
       slash_amount,
     );
 
+    // --- Slash the nodes delegate stake pool by `NodeDelegateSlashShare` of the same slash rate
+    // This lowers the share price for all of the nodes delegators
+    let node_delegate_stake_balance: u128 = TotalNodeDelegateStakeBalance::<T>::get(subnet_id, subnet_node_id);
+    let mut node_delegate_slash_amount: u128 = Self::percent_mul(node_delegate_stake_balance, SlashPercentage::<T>::get());
+    node_delegate_slash_amount = Self::percent_mul(node_delegate_slash_amount, Self::PERCENTAGE_FACTOR.saturating_sub(attestation_percentage));
+    if node_delegate_slash_amount > max_slash {
+      node_delegate_slash_amount = max_slash
+    }
+    node_delegate_slash_amount = Self::percent_mul(node_delegate_slash_amount, NodeDelegateSlashShare::<T>::get());
+
+    if node_delegate_slash_amount > 0 {
+      Self::do_decrease_node_delegate_stake(
+        subnet_id,
+        subnet_node_id,
+        node_delegate_slash_amount,
+      );
+    }
+
     // --- Increase validator penalty count
     let penalties = SubnetNodePenalties::<T>::get(subnet_id, subnet_node_id);
     SubnetNodePenalties::<T>::insert(subnet_id, subnet_node_id, penalties + 1);
@@ -247,6 +720,7 @@ impl<T: Config> Pallet<T> {
         subnet_id: subnet_id, 
         account_id: hotkey, 
         amount: slash_amount,
+        node_delegate_amount: node_delegate_slash_amount,
       }
     );
 
@@ -0,0 +1,114 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use codec::Compact;
+
+impl<T: Config> Pallet<T> {
+  // Max nesting of a validator args type
+  pub const MAX_VALIDATOR_ARGS_DEPTH: u32 = 8;
+
+  /// Ensure the validator args type isn't nested over `MAX_VALIDATOR_ARGS_DEPTH`
+  ///
+  /// Empty tuples are rejected so each value of a `Vec` consumes at least one byte when decoding
+  pub fn is_valid_validator_args_type(args_type: &ValidatorArgsType, depth: u32) -> bool {
+    if depth >= Self::MAX_VALIDATOR_ARGS_DEPTH {
+      return false
+    }
+
+    match args_type {
+      ValidatorArgsType::Option(inner) | ValidatorArgsType::Vec(inner) => {
+        Self::is_valid_validator_args_type(inner, depth + 1)
+      },
+      ValidatorArgsType::Tuple(inner) => {
+        !inner.is_empty() && inner.iter().all(|x| Self::is_valid_validator_args_type(x, depth + 1))
+      },
+      _ => true,
+    }
+  }
+
+  /// Decode the validator args of ``subnet_id`` by its `SubnetValidatorArgsType`
+  ///
+  /// Returns `None` if the subnet has no args type or the args don't decode as it
+  pub fn decode_validator_args(
+    subnet_id: u32,
+    args: &Option<BoundedVec<u8, DefaultValidatorArgsLimit>>,
+  ) -> Option<ValidatorArgsValue> {
+    let args_type: ValidatorArgsType = SubnetValidatorArgsType::<T>::get(subnet_id)?;
+    let mut input: &[u8] = args.as_ref()?;
+
+    let value: ValidatorArgsValue = Self::decode_validator_args_value(&args_type, &mut input)?;
+
+    // --- The args must be fully consumed
+    if !input.is_empty() {
+      return None
+    }
+
+    Some(value)
+  }
+
+  /// Ensure the validator args decode as the subnets `SubnetValidatorArgsType`, if it has one
+  pub fn ensure_validator_args(
+    subnet_id: u32,
+    args: &Option<BoundedVec<u8, DefaultValidatorArgsLimit>>,
+  ) -> DispatchResult {
+    if SubnetValidatorArgsType::<T>::contains_key(subnet_id) {
+      ensure!(
+        Self::decode_validator_args(subnet_id, args).is_some(),
+        Error::<T>::InvalidValidatorArgs
+      );
+    }
+
+    Ok(())
+  }
+
+  fn decode_validator_args_value(args_type: &ValidatorArgsType, input: &mut &[u8]) -> Option<ValidatorArgsValue> {
+    let value = match args_type {
+      ValidatorArgsType::Bool => ValidatorArgsValue::Bool(bool::decode(input).ok()?),
+      ValidatorArgsType::U8 => ValidatorArgsValue::U8(u8::decode(input).ok()?),
+      ValidatorArgsType::U16 => ValidatorArgsValue::U16(u16::decode(input).ok()?),
+      ValidatorArgsType::U32 => ValidatorArgsValue::U32(u32::decode(input).ok()?),
+      ValidatorArgsType::U64 => ValidatorArgsValue::U64(u64::decode(input).ok()?),
+      ValidatorArgsType::U128 => ValidatorArgsValue::U128(u128::decode(input).ok()?),
+      ValidatorArgsType::Bytes => ValidatorArgsValue::Bytes(Vec::<u8>::decode(input).ok()?),
+      ValidatorArgsType::Option(inner) => match u8::decode(input).ok()? {
+        0 => ValidatorArgsValue::Option(None),
+        1 => ValidatorArgsValue::Option(Some(Box::new(Self::decode_validator_args_value(inner, input)?))),
+        _ => return None,
+      },
+      ValidatorArgsType::Vec(inner) => {
+        let len: u32 = Compact::<u32>::decode(input).ok()?.0;
+        // --- Each value is at least one byte
+        if len as usize > input.len() {
+          return None
+        }
+        let mut values: Vec<ValidatorArgsValue> = Vec::new();
+        for _ in 0..len {
+          values.push(Self::decode_validator_args_value(inner, input)?);
+        }
+        ValidatorArgsValue::Vec(values)
+      },
+      ValidatorArgsType::Tuple(inner) => {
+        let mut values: Vec<ValidatorArgsValue> = Vec::new();
+        for x in inner {
+          values.push(Self::decode_validator_args_value(x, input)?);
+        }
+        ValidatorArgsValue::Tuple(values)
+      },
+    };
+
+    Some(value)
+  }
+}
@@ -56,6 +56,7 @@ use frame_system::{self as system, ensure_signed};
 use scale_info::prelude::string::String;
 use scale_info::prelude::vec::Vec;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use sp_std::boxed::Box;
 use sp_core::OpaquePeerId as PeerId;
 use strum::IntoEnumIterator;
 use strum_macros::{EnumIter, FromRepr};
@@ -95,6 +96,7 @@ pub mod supply;
 pub use supply::*;
 pub mod consensus;
 pub use consensus::*;
+pub mod migrations;
 
 mod rewards;
 mod rewards_v2;
@@ -109,10 +111,16 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use sp_std::vec;
 	use sp_std::vec::Vec;
+
+	/// The in-code storage version.
+	///
+	/// Increase when a storage layout changes and add its migration in ``migrations``
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 	
 	// The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
 	// (`Call`s) in this pallet.
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -176,6 +184,9 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Distribution of the epoch emissions between subnets and within each subnet
+		type RewardPolicy: RewardPolicy<Self>;
 	}
 
 	/// Events that functions in this pallet can emit.
@@ -196,6 +207,16 @@ pub mod pallet {
 		SubnetRegistered { account_id: T::AccountId, path: Vec<u8>, subnet_id: u32 },
 		SubnetActivated { subnet_id: u32 },
 		SubnetDeactivated { subnet_id: u32, reason: SubnetRemovalReason },
+		// Storage of a removed subnet is fully cleaned up
+		SubnetRemoved { subnet_id: u32 },
+		// Balances of a removed subnet moved into the unbonding ledgers
+		// (coldkey, balance), (account_id, balance), (account_id, subnet_node_id, balance)
+		SubnetBalancesUnbonded { 
+			subnet_id: u32, 
+			stake: Vec<(T::AccountId, u128)>, 
+			delegate_stake: Vec<(T::AccountId, u128)>, 
+			node_delegate_stake: Vec<(T::AccountId, u32, u128)> 
+		},
 
 		// Subnet Nodes
 		SubnetNodeRegistered { 
@@ -212,10 +233,24 @@ pub mod pallet {
 		// Stake
 		StakeAdded(u32, T::AccountId, T::AccountId, u128),
 		StakeRemoved(u32, T::AccountId, T::AccountId, u128),
+		StakeRebonded(u32, T::AccountId, T::AccountId, u128),
 
 		SubnetDelegateStakeAdded(u32, T::AccountId, u128),
 		SubnetDelegateStakeRemoved(u32, T::AccountId, u128),
 		SubnetDelegateStakeSwitched(u32, u32, T::AccountId, u128),
+		SubnetDelegateStakeRebonded(u32, T::AccountId, u128),
+		// Delegate stake shares of subnets with `SubnetDelegateStakeToken` enabled
+		DelegateStakeTokenMinted { subnet_id: u32, account_id: T::AccountId, shares: u128 },
+		DelegateStakeTokenBurned { subnet_id: u32, account_id: T::AccountId, shares: u128 },
+		DelegateStakeTokenTransferred { subnet_id: u32, from: T::AccountId, to: T::AccountId, shares: u128 },
+		SubnetDelegateStakeSharesTransferred { subnet_id: u32, from: T::AccountId, to: T::AccountId, shares: u128 },
+		NodeDelegateStakeSharesTransferred { 
+			subnet_id: u32, 
+			subnet_node_id: u32, 
+			from: T::AccountId, 
+			to: T::AccountId, 
+			shares: u128 
+		},
 
 		DelegateNodeStakeAdded { account_id: T::AccountId, subnet_id: u32, subnet_node_id: u32, amount: u128 },
 		DelegateNodeStakeRemoved { account_id: T::AccountId, subnet_id: u32, subnet_node_id: u32, amount: u128 },
@@ -234,6 +269,14 @@ pub mod pallet {
 			to_subnet_id: u32, 
 			amount: u128 
 		},
+		StakeTransferred { 
+			coldkey: T::AccountId, 
+			from_subnet_id: u32, 
+			from_hotkey: T::AccountId, 
+			to_subnet_id: u32, 
+			to_hotkey: T::AccountId, 
+			amount: u128 
+		},
 		SubnetDelegateToNodeDelegateStakeSwitched { 
 			account_id: T::AccountId, 
 			from_subnet_id: u32, 
@@ -250,6 +293,16 @@ pub mod pallet {
     SetTxRateLimit(u32),
 		SetSubnetInflationFactor(u128),
 		SetMinSubnetDelegateStakeFactor(u128),
+		SetValidatorSelectionStakeCap(u128),
+		SetBackupValidatorsCount(u32),
+		SetBackupValidatorBlockOffset(u32),
+		SetMaxRewardsItemsPerBlock(u32),
+		SetMaxSubnetRemovalItemsPerBlock(u32),
+		SetRewardsHistoryEpochs(u32),
+		SetRewardsSubmissionHistoryEpochs(u32),
+		SetDissentSlashPercentage(u128),
+		SetStakeTransferPeriod(u32),
+		SetNodeDelegateSlashShare(u128),
 
 		// Proposals
 		Proposal { subnet_id: u32, proposal_id: u32, epoch: u32, plaintiff: T::AccountId, defendant: T::AccountId, plaintiff_data: Vec<u8> },
@@ -261,15 +314,37 @@ pub mod pallet {
 
 		// Validation and Attestation
 		ValidatorSubmission { subnet_id: u32, account_id: T::AccountId, epoch: u32},
+		ValidatorCommit { subnet_id: u32, account_id: T::AccountId, epoch: u32},
 		Attestation { subnet_id: u32, account_id: T::AccountId, epoch: u32},
+		Dissent { subnet_id: u32, account_id: T::AccountId, epoch: u32, reason: Option<T::Hash> },
 
-		Slashing { subnet_id: u32, account_id: T::AccountId, amount: u128},
+		Slashing { subnet_id: u32, account_id: T::AccountId, amount: u128, node_delegate_amount: u128 },
 
 		// Rewards data
 		RewardResult { subnet_id: u32, attestation_percentage: u128 },
+		SubnetRewarded { 
+			subnet_id: u32, 
+			epoch: u32, 
+			subnet_owner_reward: u128, 
+			delegate_stake_reward: u128, 
+			subnet_node_reward: u128 
+		},
+		SubnetNodeRewarded { 
+			subnet_id: u32, 
+			epoch: u32, 
+			subnet_node_id: u32, 
+			hotkey: T::AccountId, 
+			reward: u128, 
+			node_delegate_reward: u128 
+		},
 
 		// Subnet owners
-		SubnetEntryIntervalUpdate { subnet_id: u32, owner: T::AccountId, value: u32 }
+		SubnetEntryIntervalUpdate { subnet_id: u32, owner: T::AccountId, value: u32 },
+		SubnetCommitRevealUpdate { subnet_id: u32, owner: T::AccountId, value: bool },
+		SubnetValidatorCommitteeSizeUpdate { subnet_id: u32, owner: T::AccountId, value: u32 },
+		SubnetMaxNodeRewardShareUpdate { subnet_id: u32, owner: T::AccountId, value: u128 },
+		SubnetDelegateStakeTokenUpdate { subnet_id: u32, owner: T::AccountId, value: bool },
+		SubnetMaxDelegateStakeUpdate { subnet_id: u32, owner: T::AccountId, value: Option<u128> },
 	}
 
 	/// Errors that can be returned by this pallet.
@@ -437,8 +512,74 @@ pub mod pallet {
 		InvalidValidator,
 		/// Already attested validator data
 		AlreadyAttested,
+		/// Already dissented from validator data
+		AlreadyDissented,
 		/// Invalid rewards data length
 		InvalidRewardsDataLength,
+		/// Subnet uses commit-reveal, submit rewards data with `commit_validate` and `reveal_validate`
+		CommitRevealEnabled,
+		/// Subnet doesn't use commit-reveal, submit rewards data with `validate`
+		CommitRevealNotEnabled,
+		/// Subnet rewards data already committed by validator
+		SubnetRewardsAlreadyCommitted,
+		/// No subnet rewards commitment found for the epoch
+		SubnetRewardsNotCommitted,
+		/// Subnet rewards commitment exists but hasn't been revealed yet
+		SubnetRewardsNotRevealed,
+		/// Revealed data and salt don't match the commitment
+		InvalidRewardsReveal,
+		/// Reveal must be submitted on a block after the commitment
+		RevealTooEarly,
+		/// Validator committee size must be between 1 and `MaxValidatorCommitteeSize`
+		InvalidValidatorCommitteeSize,
+		/// Committee submissions of the epoch closed at its `BackupValidatorBlockOffset`
+		CommitteeSubmissionClosed,
+		/// Can't attest or dissent until the committee submissions of the epoch close
+		CommitteeSubmissionOpen,
+		/// Backup validator can't submit until its block offset in the epoch is reached
+		BackupValidatorNotActive,
+		/// Backup validator block offset must be greater than 0 and less than the epoch length
+		InvalidBackupValidatorBlockOffset,
+		/// Backup validators count must not exceed `MaxValidatorCommitteeSize`
+		InvalidBackupValidatorsCount,
+		/// Max rewards items per block must be greater than 0
+		InvalidMaxRewardsItemsPerBlock,
+		/// Max subnet removal items per block must be greater than 0
+		InvalidMaxSubnetRemovalItemsPerBlock,
+		/// Rewards history epochs must be greater than 0
+		InvalidRewardsHistoryEpochs,
+		/// Max node reward share must be greater than 0 and not greater than 100%
+		InvalidMaxNodeRewardShare,
+		/// Sum of the scores must not be greater than `MAX_SCORE_SUM`
+		InvalidScoreSum,
+		/// Rewards data can't include a peer more than once
+		DuplicateRewardsData,
+		/// Rewards submission history epochs must be greater than 1
+		InvalidRewardsSubmissionHistoryEpochs,
+		/// Dissent slash percentage must be greater than 0 and not greater than 100%
+		InvalidDissentSlashPercentage,
+		/// Validator args type must not be nested over `MAX_VALIDATOR_ARGS_DEPTH` or have empty tuples
+		InvalidValidatorArgsType,
+		/// Validator args must decode as the subnets validator args type
+		InvalidValidatorArgs,
+		/// Not enough balance in the unbonding ledger to rebond
+		NotEnoughUnbondingBalance,
+		/// Stake can't be transferred to the same hotkey and subnet
+		InvalidStakeTransfer,
+		/// Stake transfers are limited to one per `StakeTransferPeriod`
+		StakeTransferPeriodExceeded,
+		/// Subnet delegate stake shares aren't a transferable token
+		DelegateStakeTokenDisabled,
+		/// Not enough delegate stake shares that aren't reserved
+		NotEnoughFreeDelegateStakeShares,
+		/// Delegate stake shares can't be transferred to the same account
+		InvalidDelegateStakeTokenTransfer,
+		/// Delegate stake shares can't be transferred to the same account
+		InvalidDelegateStakeSharesTransfer,
+		/// Subnet delegate stake is at the subnets `MaxSubnetDelegateStake`
+		MaxSubnetDelegateStakeReached,
+		/// `MaxSubnetDelegateStake` must be greater than zero
+		InvalidMaxSubnetDelegateStake,
 
 
 		ProposalInvalid,
@@ -597,6 +738,13 @@ pub mod pallet {
 		Owner,
   }
 
+	/// Kind of stake an unbonding was removed from
+	#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum UnbondingKind {
+		Stake,
+		DelegateStake,
+	}
+
 	/// Attests format for consensus
 	/// ``u64`` is the block number of the accounts attestation for subnets to utilize to measure attestation speed
 	/// The blockchain itself doesn't utilize this data
@@ -610,6 +758,200 @@ pub mod pallet {
 		pub args: Option<BoundedVec<u8, DefaultValidatorArgsLimit>>, // Optional arguements to pass for subnet to validate
 	}
 
+	/// SCALE type descriptor of the validator args of a subnet
+	///
+	/// Registered by the subnet owner so subnet software can rely on the shape of `RewardsData.args`
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum ValidatorArgsType {
+		Bool,
+		U8,
+		U16,
+		U32,
+		U64,
+		U128,
+		Bytes,
+		Option(Box<ValidatorArgsType>),
+		Vec(Box<ValidatorArgsType>),
+		Tuple(Vec<ValidatorArgsType>),
+	}
+
+	/// Validator args decoded by the subnets `ValidatorArgsType`
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum ValidatorArgsValue {
+		Bool(bool),
+		U8(u8),
+		U16(u16),
+		U32(u32),
+		U64(u64),
+		U128(u128),
+		Bytes(Vec<u8>),
+		Option(Option<Box<ValidatorArgsValue>>),
+		Vec(Vec<ValidatorArgsValue>),
+		Tuple(Vec<ValidatorArgsValue>),
+	}
+
+	/// Rewards data of an epoch with its args decoded
+	///
+	/// # Arguments
+	///
+	/// * `validator_id` - Chosen validator of the epoch.
+	/// * `attests` - Attestations of the submitted data.
+	/// * `data` - Data submitted by the validator.
+	/// * `args` - Optional arguments passed for the subnet to validate.
+	/// * `decoded_args` - Args decoded by the subnets `ValidatorArgsType`, if it has one.
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct ConsensusData {
+		pub validator_id: u32,
+		pub attests: BTreeMap<u32, u32>,
+		pub data: Vec<SubnetNodeData>,
+		pub args: Option<BoundedVec<u8, DefaultValidatorArgsLimit>>,
+		pub decoded_args: Option<ValidatorArgsValue>,
+	}
+
+	/// Validator commitment of the rewards data for commit-reveal subnets
+	///
+	/// # Arguments
+	///
+	/// * `commitment` - Hash of the encoded `(data, args, salt)` to be revealed.
+	/// * `block` - Block the commitment was made.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct RewardsCommit<Hash> {
+		pub commitment: Hash,
+		pub block: u32,
+	}
+
+	/// Randomness used to choose the validator committee of an epoch
+	///
+	/// # Arguments
+	///
+	/// * `block` - Block used as the seed of the first draw, each following draw increments it.
+	/// * `randomness` - Output of the randomness source for the first draw.
+	/// * `known_since` - Block the randomness source output became known, used to look up its proof.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct ValidatorSelectionProof<Hash> {
+		pub block: u32,
+		pub randomness: Hash,
+		pub known_since: u32,
+	}
+
+	/// Progress of the rewards of an epoch distributed across blocks
+	///
+	/// # Arguments
+	///
+	/// * `epoch` - Epoch being rewarded.
+	/// * `block` - Block the rewards began.
+	/// * `rewards` - Total emissions of the epoch.
+	/// * `subnets` - Subnets left to reward and their delegate stake weights, the first is in progress.
+	/// * `subnet` - Progress of the subnet nodes of the subnet in progress.
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct RewardsCursor {
+		pub epoch: u32,
+		pub block: u32,
+		pub rewards: u128,
+		pub subnets: Vec<(u32, u128)>,
+		pub subnet: Option<SubnetRewardsCursor>,
+	}
+
+	/// Progress of the subnet nodes rewards of a subnet
+	///
+	/// # Arguments
+	///
+	/// * `subnet_id` - Subnet being rewarded.
+	/// * `subnet_node_id` - Last rewarded subnet node.
+	/// * `subnet_node_reward` - Rewards to the subnet nodes.
+	/// * `delegate_stake_reward` - Rewards to the subnet delegate stakers once the subnet nodes are rewarded.
+	/// * `score_sum` - Sum of the scores in the submission.
+	/// * `attestation_percentage` - Attestation percentage of the submission.
+	/// * `validator_reward` - Reward to each validator that submitted.
+	/// * `submitters` - Validators that submitted.
+	/// * `data_len` - Amount of subnet nodes in the submission.
+	/// * `rewarded` - Rewards given to the subnet nodes and their node delegate stakers so far.
+	/// * `max_score` - Score each subnet node is capped at by `MaxNodeRewardShare`.
+	/// * `max_node_reward_share` - Max share of `subnet_node_reward` to a subnet node.
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SubnetRewardsCursor {
+		pub subnet_id: u32,
+		pub subnet_node_id: Option<u32>,
+		pub subnet_node_reward: u128,
+		pub delegate_stake_reward: u128,
+		pub score_sum: u128,
+		pub attestation_percentage: u128,
+		pub validator_reward: u128,
+		pub submitters: Vec<u32>,
+		pub data_len: u32,
+		pub rewarded: u128,
+		pub max_score: u128,
+		pub max_node_reward_share: u128,
+	}
+
+	/// Progress of the teardown of a removed subnet
+	///
+	/// # Arguments
+	///
+	/// * `step` - Teardown step in progress.
+	/// * `cursor` - Raw storage key or `clear_prefix` cursor the step continues from.
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SubnetRemovalCursor {
+		pub step: u32,
+		pub cursor: Option<Vec<u8>>,
+	}
+
+	/// Rewards of a subnet in an epoch
+	///
+	/// # Arguments
+	///
+	/// * `subnet_owner` - Coldkey of the subnet owner.
+	/// * `subnet_owner_reward` - Rewards to the subnet owner.
+	/// * `delegate_stake_reward` - Rewards to the subnet delegate stakers.
+	/// * `subnet_node_reward` - Rewards to the subnet nodes and their node delegate stakers.
+	/// * `attestation_percentage` - Attestation percentage of the submission.
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SubnetRewardsReceipt<AccountId> {
+		pub subnet_owner: Option<AccountId>,
+		pub subnet_owner_reward: u128,
+		pub delegate_stake_reward: u128,
+		pub subnet_node_reward: u128,
+		pub attestation_percentage: u128,
+	}
+
+	/// Rewards of a subnet node in an epoch
+	///
+	/// # Arguments
+	///
+	/// * `hotkey` - Hotkey of the subnet node.
+	/// * `reward` - Rewards added to the hotkeys stake, including the validator reward.
+	/// * `validator_reward` - Rewards for submitting the rewards data.
+	/// * `node_delegate_reward` - Rewards to the subnet nodes delegate stakers.
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SubnetNodeRewardsReceipt<AccountId> {
+		pub hotkey: AccountId,
+		pub reward: u128,
+		pub validator_reward: u128,
+		pub node_delegate_reward: u128,
+	}
+
+	/// Projected rewards of a subnet in an epoch from its current rewards submission
+	///
+	/// # Arguments
+	///
+	/// * `subnet` - Rewards of the subnet, `None` if the subnet isn't rewarded.
+	/// * `subnet_nodes` - Rewards of each rewarded subnet node ID.
+	/// * `slashes` - Stake slashed from each subnet node ID.
+	/// * `penalties` - Penalty count of each subnet node ID whose penalties changed.
+	/// * `removed_subnet_nodes` - Subnet node IDs removed.
+	/// * `subnet_penalty_count` - Penalty count of the subnet.
+	/// * `subnet_removed` - If the subnet is removed for its penalty count.
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SubnetRewardsPreview<AccountId> {
+		pub subnet: Option<SubnetRewardsReceipt<AccountId>>,
+		pub subnet_nodes: Vec<(u32, SubnetNodeRewardsReceipt<AccountId>)>,
+		pub slashes: Vec<(u32, u128)>,
+		pub penalties: Vec<(u32, u32)>,
+		pub removed_subnet_nodes: Vec<u32>,
+		pub subnet_penalty_count: u32,
+		pub subnet_removed: bool,
+	}
+
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
   pub struct CurveParametersSet {
 		pub x_curve_start: u128, // The range of ``max-min`` to start descending the curve
@@ -645,6 +987,7 @@ pub mod pallet {
 	/// * `node_queue_period` - Epochs a node stays in the Queue class before being Included in consensus.
 	/// * `max_node_penalties` - Maximum penalties a node can accrue before being removed.
 	/// * `coldkey_whitelist` - Whitelist of coldkeys for registration while subnets are registering. This is removed on activation.
+	/// * `validator_args_type` - Optional SCALE type descriptor the validator args must decode as.
 	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
 	pub struct RegistrationSubnetData<AccountId> {
 		pub path: Vec<u8>,
@@ -654,6 +997,7 @@ pub mod pallet {
 		pub node_queue_period: u32,
 		pub max_node_penalties: u32,
 		pub coldkey_whitelist: BTreeSet<AccountId>,
+		pub validator_args_type: Option<ValidatorArgsType>,
 	}
 	
 	// /// Subnet data used before activation
@@ -688,7 +1032,6 @@ pub mod pallet {
 	/// * `id` - Unique identifier.
 	/// * `path` - Path to download the model, this can be HuggingFace, IPFS, anything.
 	/// * `state` - Registered or Active.
-	/// * `registered` - Epoch subnet registered.
 	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
 	pub struct SubnetData {
 		pub id: u32,
@@ -848,13 +1191,17 @@ pub mod pallet {
 		1000
 	}
 	#[pallet::type_value]
+	pub fn DefaultStakeTransferPeriod() -> u32 {
+		1000
+	}
+	#[pallet::type_value]
 	pub fn DefaultDelegateStakeRewardsPercentage() -> u128 {
 		// 1100
 		110000000
 	}
 	#[pallet::type_value]
-	pub fn DefaultDelegateStakeCooldown() -> u32 {
-		0
+	pub fn DefaultDelegateStakeCooldown() -> BTreeMap<u32, u128> {
+		BTreeMap::new()
 	}
 	#[pallet::type_value]
 	pub fn DefaultDelegateStakeUnbondingLedger() -> BTreeMap<u32, u128> {
@@ -906,6 +1253,11 @@ pub mod pallet {
 		875000000
 	}
 	#[pallet::type_value]
+	pub fn DefaultDissentSlashPercentage() -> u128 {
+		// 1/3
+		333333333
+	}
+	#[pallet::type_value]
 	pub fn DefaultMinSubnetNodes() -> u32 {
 		// development and mainnet
 		// 6
@@ -967,6 +1319,48 @@ pub mod pallet {
 		4096
 	}
 	#[pallet::type_value]
+	pub fn DefaultSubnetValidatorCommitteeSize() -> u32 {
+		1
+	}
+	#[pallet::type_value]
+	pub fn DefaultMaxValidatorCommitteeSize() -> u32 {
+		16
+	}
+	#[pallet::type_value]
+	pub fn DefaultMaxNodeRewardShare() -> u128 {
+		// 100%
+		1_000_000_000
+	}
+	#[pallet::type_value]
+	pub fn DefaultValidatorSelectionStakeCap() -> u128 {
+		u128::MAX
+	}
+	#[pallet::type_value]
+	pub fn DefaultBackupValidatorsCount() -> u32 {
+		2
+	}
+	#[pallet::type_value]
+	pub fn DefaultBackupValidatorBlockOffset<T: Config>() -> u32 {
+		// Every default backup gets its turn within the epoch
+		(T::EpochLength::get() / DefaultBackupValidatorsCount().saturating_add(1)).max(1)
+	}
+	#[pallet::type_value]
+	pub fn DefaultMaxRewardsItemsPerBlock() -> u32 {
+		256
+	}
+	#[pallet::type_value]
+	pub fn DefaultMaxSubnetRemovalItemsPerBlock() -> u32 {
+		256
+	}
+	#[pallet::type_value]
+	pub fn DefaultRewardsHistoryEpochs() -> u32 {
+		100
+	}
+	#[pallet::type_value]
+	pub fn DefaultRewardsSubmissionHistoryEpochs() -> u32 {
+		100
+	}
+	#[pallet::type_value]
 	pub fn DefaultMinSubnetRegistrationFee() -> u128 {
 		100e+18 as u128
 	}
@@ -1413,6 +1807,10 @@ pub mod pallet {
 		u32,
 	>;
 
+	// SCALE type descriptor the validator args of a subnet must decode as
+	#[pallet::storage] // subnet ID => args type
+	pub type SubnetValidatorArgsType<T> = StorageMap<_, Identity, u32, ValidatorArgsType>;
+
 	#[pallet::storage] // subnet ID => epoch  => data
 	pub type SubnetRewardsSubmission<T> = StorageDoubleMap<
 		_,
@@ -1423,12 +1821,180 @@ pub mod pallet {
 		RewardsData,
 	>;
 
+	// Subnet nodes that rejected the rewards data of the epoch and their optional reason hash
+	// Dissents open once the committee submissions close, so the rewards data doesn't change after
+	#[pallet::storage] // subnet ID => epoch  => subnet node ID => reason
+	pub type SubnetRewardsDissents<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Identity,
+		u32,
+		BTreeMap<u32, Option<T::Hash>>,
+		ValueQuery,
+	>;
+
+	// Subnets that require validators to commit to their rewards data before revealing it
+	#[pallet::storage] // subnet ID => enabled
+	pub type SubnetCommitReveal<T> = StorageMap<_, Identity, u32, bool, ValueQuery>;
+
+	// subnet ID => epoch => subnet node ID => commitment
+	#[pallet::storage]
+	pub type SubnetRewardsCommit<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, u32>,
+			NMapKey<Identity, u32>,
+			NMapKey<Identity, u32>,
+		),
+		RewardsCommit<T::Hash>,
+	>;
+
+	// Amount of validators chosen per epoch to submit rewards data
+	// Each committee members data is combined by stake-weighted median per subnet node
+	#[pallet::storage] // subnet ID => committee size
+	pub type SubnetValidatorCommitteeSize<T> = StorageMap<_, Identity, u32, u32, ValueQuery, DefaultSubnetValidatorCommitteeSize>;
+
+	#[pallet::storage]
+	pub type MaxValidatorCommitteeSize<T> = StorageValue<_, u32, ValueQuery, DefaultMaxValidatorCommitteeSize>;
+
+	// Max share of the subnet node rewards a subnet node can receive
+	// Rewards over the cap are redistributed to the other subnet nodes by score
+	#[pallet::storage] // subnet ID => percentage
+	pub type MaxNodeRewardShare<T> = StorageMap<_, Identity, u32, u128, ValueQuery, DefaultMaxNodeRewardShare>;
+
+	// Max balance a subnet node is weighted by when choosing validators
+	// The weight is the subnet nodes stake plus its node delegate stake balance
+	#[pallet::storage]
+	pub type ValidatorSelectionStakeCap<T> = StorageValue<_, u128, ValueQuery, DefaultValidatorSelectionStakeCap>;
+
+	// The first validator in the committee is the epochs ``SubnetRewardsValidator``
+	#[pallet::storage] // subnet ID => epoch  => subnet node IDs
+	pub type SubnetValidatorCommittee<T> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Identity,
+		u32,
+		Vec<u32>,
+	>;
+
+	// Validators that can submit in rank order if the committee hasn't submitted in time
+	#[pallet::storage] // subnet ID => epoch  => subnet node IDs
+	pub type SubnetBackupValidators<T> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Identity,
+		u32,
+		Vec<u32>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	pub type BackupValidatorsCount<T> = StorageValue<_, u32, ValueQuery, DefaultBackupValidatorsCount>;
+
+	// Blocks into the epoch before the first backup validator can submit
+	// Each following backup validator waits another offset
+	#[pallet::storage]
+	pub type BackupValidatorBlockOffset<T: Config> = StorageValue<_, u32, ValueQuery, DefaultBackupValidatorBlockOffset<T>>;
+
+	// Rewards of the previous epoch in progress
+	// Begins on the first block of an epoch and continues each block until all subnets are rewarded
+	#[pallet::storage]
+	pub type EpochRewardsCursor<T> = StorageValue<_, RewardsCursor>;
+
+	// Epochs due while the rewards of a previous epoch were still in progress, and the block each was due
+	// Each begins once the rewards before it finish
+	#[pallet::storage]
+	pub type PendingRewardsEpochs<T> = StorageValue<_, Vec<(u32, u32)>, ValueQuery>;
+
+	// Max subnets and subnet nodes rewarded in `on_initialize` per block, within `get_rewards_weight_per_block`
+	// Leftover block weight in `on_idle` rewards more
+	#[pallet::storage]
+	pub type MaxRewardsItemsPerBlock<T> = StorageValue<_, u32, ValueQuery, DefaultMaxRewardsItemsPerBlock>;
+
+	// Removed subnets whose storage is being cleaned up in `on_idle`
+	// These are excluded from rewards and validator choice
+	#[pallet::storage]
+	pub type RemovingSubnets<T> = StorageMap<_, Identity, u32, SubnetRemovalCursor, OptionQuery>;
+
+	// Max storage items of removed subnets cleaned up per block in `on_idle`
+	#[pallet::storage]
+	pub type MaxSubnetRemovalItemsPerBlock<T> = StorageValue<_, u32, ValueQuery, DefaultMaxSubnetRemovalItemsPerBlock>;
+
+	// Epochs the rewards receipts are kept for before being pruned
+	#[pallet::storage]
+	pub type RewardsHistoryEpochs<T> = StorageValue<_, u32, ValueQuery, DefaultRewardsHistoryEpochs>;
+
+	// Epochs the `SubnetRewardsSubmission` and `SubnetRewardsValidator` entries, and the rest of the validator
+	// choice and submissions of an epoch, are kept for before being pruned
+	#[pallet::storage]
+	pub type RewardsSubmissionHistoryEpochs<T> = StorageValue<_, u32, ValueQuery, DefaultRewardsSubmissionHistoryEpochs>;
+
+	// Oldest epoch of each subnet whose submission, dissents and validators may not be pruned yet
+	#[pallet::storage] // subnet ID => epoch
+	pub type SubnetRewardsSubmissionPruneEpoch<T> = StorageMap<_, Identity, u32, u32>;
+
+	#[pallet::storage] // subnet ID => epoch => rewards of the subnet
+	pub type SubnetEpochRewards<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Identity,
+		u32,
+		SubnetRewardsReceipt<T::AccountId>,
+	>;
+
+	// subnet ID => epoch => subnet node ID => rewards of the subnet node
+	#[pallet::storage]
+	pub type SubnetNodeEpochRewards<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, u32>,
+			NMapKey<Identity, u32>,
+			NMapKey<Identity, u32>,
+		),
+		SubnetNodeRewardsReceipt<T::AccountId>,
+	>;
+
+	// Oldest epoch of each subnet whose rewards receipts may not be pruned yet
+	#[pallet::storage] // subnet ID => epoch
+	pub type SubnetEpochRewardsPruneEpoch<T> = StorageMap<_, Identity, u32, u32>;
+
+	#[pallet::storage] // subnet ID => epoch  => randomness of the validator choice
+	pub type SubnetValidatorSelectionProof<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Identity,
+		u32,
+		ValidatorSelectionProof<T::Hash>,
+	>;
+
+	// Data submitted by each committee member, removed once the epoch is rewarded or pruned
+	#[pallet::storage] // subnet ID => epoch  => subnet node ID => data
+	pub type SubnetCommitteeSubmissions<T> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Identity,
+		u32,
+		BTreeMap<u32, Vec<SubnetNodeData>>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	pub type MinAttestationPercentage<T> = StorageValue<_, u128, ValueQuery, DefaultMinAttestationPercentage>;
 
 	#[pallet::storage]
 	pub type MinVastMajorityAttestationPercentage<T> = StorageValue<_, u128, ValueQuery, DefaultMinVastMajorityAttestationPercentage>;
 
+	// A submission under the min attestation percentage slashes the validators, unless dissents are under half of this
+	// percentage of the subnet nodes, in which case the subnet nodes are assumed to have been offline
+	#[pallet::storage]
+	pub type DissentSlashPercentage<T> = StorageValue<_, u128, ValueQuery, DefaultDissentSlashPercentage>;
+
 	//
 	// Rewards (validator, incentives)
 	//
@@ -1443,6 +2009,10 @@ pub mod pallet {
 
 	#[pallet::storage]
 	pub type MaxSlashAmount<T> = StorageValue<_, u128, ValueQuery, DefaultMaxSlashAmount>;
+
+	// Percentage of a validators slash rate also taken from its node delegate stake pool
+	#[pallet::storage]
+	pub type NodeDelegateSlashShare<T> = StorageValue<_, u128, ValueQuery>;
 	
 	#[pallet::storage]
 	pub type MaxSubnetNodePenalties<T> = StorageMap<
@@ -1507,11 +2077,33 @@ pub mod pallet {
 		ValueQuery,
 		DefaultAccountTake,
 	>;
+
+	// Hotkeys with stake in a subnet, to iterate the `AccountSubnetStake` of a subnet
+	#[pallet::storage] // subnet_id --> account --> ()
+	pub type SubnetStakeAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
 	
 	#[pallet::storage]
 	pub type StakeUnbondingLedger<T: Config> = 
 		StorageMap<_, Blake2_128Concat, T::AccountId, BTreeMap<u32, u128>, ValueQuery, DefaultStakeUnbondingLedger>;
 
+	// Subnet and kind of stake the rebondable unbondings of the `StakeUnbondingLedger` were removed from
+	// Rebonding only moves balance back into the same subnet and kind of stake
+	#[pallet::storage] // coldkey --> (subnet_id, kind) --> claim epoch --> balance
+	pub type StakeUnbondingOrigins<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BTreeMap<(u32, UnbondingKind), BTreeMap<u32, u128>>,
+		ValueQuery,
+	>;
+
 	// Maximum stake balance per subnet
 	// Only checked on `do_add_stake` and ``
 	// A subnet staker can have greater than the max stake balance although any rewards
@@ -1543,6 +2135,14 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type LastDelegateStakeTransfer<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery, DefaultZeroU32>;
 
+	/// The required blocks between stake transfers
+	#[pallet::storage]
+	pub type StakeTransferPeriod<T> = StorageValue<_, u32, ValueQuery, DefaultStakeTransferPeriod>;
+
+	// coldkey => block of the last stake transfer
+	#[pallet::storage]
+	pub type LastStakeTransfer<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery, DefaultZeroU32>;
+
 	// Percentage of epoch rewards that go towards delegate stake pools
 	#[pallet::storage]
 	pub type DelegateStakeRewardsPercentage<T> = StorageValue<_, u128, ValueQuery, DefaultDelegateStakeRewardsPercentage>;
@@ -1574,14 +2174,27 @@ pub mod pallet {
 		DefaultAccountTake,
 	>;
 
-	#[pallet::storage] // account --> subnet_id --> epochs
+	// Accounts with delegate stake shares in a subnet, to iterate the `AccountSubnetDelegateStakeShares` of a subnet
+	#[pallet::storage] // subnet_id --> account --> ()
+	pub type SubnetDelegateStakeAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+
+	// Delegate stake shares an account received by transfer, by the epoch they can be removed or
+	// transferred from. These are part of `AccountSubnetDelegateStakeShares`
+	#[pallet::storage] // account --> subnet_id --> { epoch: shares }
 	pub type DelegateStakeCooldown<T: Config> = StorageDoubleMap<
 		_,
 		Identity,
 		T::AccountId,
 		Identity,
 		u32,
-		u32,
+		BTreeMap<u32, u128>,
 		ValueQuery,
 		DefaultDelegateStakeCooldown,
 	>;
@@ -1597,6 +2210,27 @@ pub mod pallet {
 		ValueQuery,
 		DefaultDelegateStakeUnbondingLedger,
 	>;
+
+	// Optional cap on the total delegate stake balance of a subnet, set by the subnet owner
+	#[pallet::storage] // subnet_id --> balance
+	pub type MaxSubnetDelegateStake<T> = StorageMap<_, Identity, u32, u128, OptionQuery>;
+
+	// Subnets whose delegate stake shares are a transferable token
+	#[pallet::storage] // subnet_id --> enabled
+	pub type SubnetDelegateStakeToken<T> = StorageMap<_, Identity, u32, bool, ValueQuery>;
+
+	// Delegate stake shares reserved out of an accounts balance, i.e. by an atomic swap
+	// These are part of `AccountSubnetDelegateStakeShares` but can't be removed or transferred
+	#[pallet::storage] // account --> subnet_id --> shares
+	pub type ReservedSubnetDelegateStakeShares<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Identity,
+		u32,
+		u128,
+		ValueQuery,
+	>;
 	
 	//
 	// Node Delegate Stake
@@ -1653,18 +2287,43 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
-	//
-	// Props
-	//
-
-	#[pallet::storage] // subnet => proposal_id => proposal
-	pub type Proposals<T> = StorageDoubleMap<
+	// Accounts with node delegate stake shares in a subnet, to iterate the `AccountNodeDelegateStakeShares` of a subnet
+	#[pallet::storage] // subnet_id -> account_id -> subnet_node_id -> ()
+	pub type SubnetNodeDelegateStakeAccounts<T: Config> = StorageNMap<
 		_,
-		Identity,
-		u32,
-		Identity,
-		u32,
-		ProposalParams,
+		(
+			NMapKey<Identity, u32>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Identity, u32>,
+		),
+		(),
+	>;
+
+	// Node delegate stake shares an account received by transfer, see `DelegateStakeCooldown`
+	#[pallet::storage] // account_id -> subnet_id -> subnet_node_id -> { epoch: shares }
+	pub type NodeDelegateStakeCooldown<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Identity, u32>,
+			NMapKey<Identity, u32>,
+		),
+		BTreeMap<u32, u128>,
+		ValueQuery,
+	>;
+
+	//
+	// Props
+	//
+
+	#[pallet::storage] // subnet => proposal_id => proposal
+	pub type Proposals<T> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Identity,
+		u32,
+		ProposalParams,
 		ValueQuery,
 		DefaultProposalParams<T>,
 	>;
@@ -2079,6 +2738,12 @@ pub mod pallet {
 
 			let key: T::AccountId = ensure_signed(origin)?;
 
+			// --- Removed subnets clean up their subnet nodes in `on_idle`
+			ensure!(
+				SubnetsData::<T>::contains_key(subnet_id),
+				Error::<T>::SubnetNotExist
+			);
+
 			ensure!(
 				Self::is_keys_owner(
 					subnet_id, 
@@ -2611,6 +3276,11 @@ pub mod pallet {
 			Self::is_paused()?;
 
 			let account_id: T::AccountId = ensure_signed(origin)?;
+
+			ensure!(
+				SubnetsData::<T>::contains_key(subnet_id),
+				Error::<T>::SubnetNotExist
+			);
 			
 			// --- Ensure subnet node exists, otherwise at risk of burning tokens
 			ensure!(
@@ -2718,6 +3388,11 @@ pub mod pallet {
 		/// * `data` - Vector of SubnetNodeData on each subnet node for scoring each
 		/// * `args` (Optional) - Data that can be used by the subnet 
 		/// 
+		/// # Requirements
+		/// 
+		/// * Caller must be a hotkey of the epochs validator committee
+		/// * Submissions of a committee are combined by the stake-weighted median score of each subnet node
+		/// 
 		#[pallet::call_index(26)]
 		#[pallet::weight({0})]
 		pub fn validate(
@@ -2774,6 +3449,35 @@ pub mod pallet {
 			)
 		}
 
+		/// Reject validators view of the subnet
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `reason` - Optional hash of the reason for dissenting.
+		/// 
+		#[pallet::call_index(57)]
+		#[pallet::weight({0})]
+		pub fn dissent(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			reason: Option<T::Hash>,
+		) -> DispatchResultWithPostInfo {
+			Self::is_paused()?;
+
+			let hotkey: T::AccountId = ensure_signed(origin)?;
+
+			let block: u32 = Self::get_current_block_as_u32();
+			let epoch: u32 = block / T::EpochLength::get();
+
+			Self::do_dissent(
+				subnet_id, 
+				hotkey,
+				epoch,
+				reason,
+			)
+		}
+
 		/// Propose to remove someone from subnet
 		///
 		/// This acts as a governance system for each subnet
@@ -3100,6 +3804,7 @@ pub mod pallet {
 						// Condition met, update or remove
 						*maybe_coldkey = Some(new_coldkey.clone());
 						// Update StakeUnbondingLedger
+						StakeUnbondingOrigins::<T>::swap(&curr_coldkey, &new_coldkey);
 						StakeUnbondingLedger::<T>::swap(curr_coldkey, new_coldkey);
 						Ok(())
 					},
@@ -3328,6 +4033,529 @@ pub mod pallet {
 			T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
 			Self::do_set_subnet_owner_percentage(value)
 		}
+
+		/// Enable or disable commit-reveal for the subnets validator submissions
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `value` - If validators must commit and reveal their rewards data.
+		///
+		/// # Requirements
+		///
+		/// * Caller must be the subnet owner
+		///
+		#[pallet::call_index(46)]
+		#[pallet::weight({0})]
+		pub fn owner_update_commit_reveal(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			value: bool
+		) -> DispatchResult {
+			Self::is_paused()?;
+			Self::do_owner_update_commit_reveal(origin, subnet_id, value)
+		}
+
+		/// Validator extrinsic for committing to the incentives protocol data on commit-reveal subnets
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `commitment` - Hash of the encoded `(data, args, salt)`, see `get_rewards_commitment`.
+		///
+		/// # Requirements
+		///
+		/// * Subnet must have commit-reveal enabled
+		/// * Caller must be a hotkey of the epochs validator committee
+		///
+		#[pallet::call_index(47)]
+		#[pallet::weight({0})]
+		pub fn commit_validate(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			commitment: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::is_paused()?;
+
+			let hotkey: T::AccountId = ensure_signed(origin)?;
+
+			let block: u32 = Self::get_current_block_as_u32();
+			let epoch_length: u32 = T::EpochLength::get();
+			let epoch: u32 = block / epoch_length;
+
+			Self::do_commit_validate(
+				subnet_id, 
+				hotkey,
+				block,
+				epoch,
+				commitment,
+			)
+		}
+
+		/// Validator extrinsic for revealing the committed incentives protocol data
+		///
+		/// Once revealed, the data is stored as the epochs submission and can be attested
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `data` - Vector of SubnetNodeData on each subnet node for scoring each
+		/// * `args` (Optional) - Data that can be used by the subnet 
+		/// * `salt` - Salt used in the commitment
+		///
+		/// # Requirements
+		///
+		/// * Must be in the same epoch and on a later block than the commitment
+		///
+		#[pallet::call_index(48)]
+		#[pallet::weight({0})]
+		pub fn reveal_validate(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			data: Vec<SubnetNodeData>,
+			args: Option<BoundedVec<u8, DefaultValidatorArgsLimit>>,
+			salt: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			Self::is_paused()?;
+
+			let hotkey: T::AccountId = ensure_signed(origin)?;
+
+			let block: u32 = Self::get_current_block_as_u32();
+			let epoch_length: u32 = T::EpochLength::get();
+			let epoch: u32 = block / epoch_length;
+
+			Self::do_reveal_validate(
+				subnet_id, 
+				hotkey,
+				block,
+				epoch,
+				data,
+				args,
+				salt,
+			)
+		}
+
+		/// Update the amount of validators chosen per epoch
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `value` - Validator committee size.
+		///
+		/// # Requirements
+		///
+		/// * Caller must be the subnet owner
+		/// * `value` must be between 1 and `MaxValidatorCommitteeSize`
+		///
+		#[pallet::call_index(49)]
+		#[pallet::weight({0})]
+		pub fn owner_update_validator_committee_size(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			value: u32
+		) -> DispatchResult {
+			Self::is_paused()?;
+			Self::do_owner_update_validator_committee_size(origin, subnet_id, value)
+		}
+
+		#[pallet::call_index(50)]
+		#[pallet::weight({0})]
+		pub fn set_validator_selection_stake_cap(
+			origin: OriginFor<T>, 
+			value: u128
+		) -> DispatchResult {
+			T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+			Self::do_set_validator_selection_stake_cap(value)
+		}
+
+		#[pallet::call_index(51)]
+		#[pallet::weight({0})]
+		pub fn set_backup_validators_count(
+			origin: OriginFor<T>, 
+			value: u32
+		) -> DispatchResult {
+			T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+			Self::do_set_backup_validators_count(value)
+		}
+
+		#[pallet::call_index(52)]
+		#[pallet::weight({0})]
+		pub fn set_backup_validator_block_offset(
+			origin: OriginFor<T>, 
+			value: u32
+		) -> DispatchResult {
+			T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+			Self::do_set_backup_validator_block_offset(value)
+		}
+
+		#[pallet::call_index(53)]
+		#[pallet::weight({0})]
+		pub fn set_max_rewards_items_per_block(
+			origin: OriginFor<T>, 
+			value: u32
+		) -> DispatchResult {
+			T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+			Self::do_set_max_rewards_items_per_block(value)
+		}
+
+		#[pallet::call_index(54)]
+		#[pallet::weight({0})]
+		pub fn set_rewards_history_epochs(
+			origin: OriginFor<T>, 
+			value: u32
+		) -> DispatchResult {
+			T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+			Self::do_set_rewards_history_epochs(value)
+		}
+
+		/// Update the max share of the subnet node rewards a subnet node can receive
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `value` - Max node reward share out of `PERCENTAGE_FACTOR`.
+		///
+		/// # Requirements
+		///
+		/// * Caller must be the subnet owner
+		/// * `value` must be greater than 0 and not greater than `PERCENTAGE_FACTOR`
+		///
+		#[pallet::call_index(55)]
+		#[pallet::weight({0})]
+		pub fn owner_update_max_node_reward_share(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			value: u128
+		) -> DispatchResult {
+			Self::is_paused()?;
+			Self::do_owner_update_max_node_reward_share(origin, subnet_id, value)
+		}
+
+		#[pallet::call_index(56)]
+		#[pallet::weight({0})]
+		pub fn set_rewards_submission_history_epochs(
+			origin: OriginFor<T>, 
+			value: u32
+		) -> DispatchResult {
+			T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+			Self::do_set_rewards_submission_history_epochs(value)
+		}
+
+		#[pallet::call_index(58)]
+		#[pallet::weight({0})]
+		pub fn set_dissent_slash_percentage(
+			origin: OriginFor<T>, 
+			value: u128
+		) -> DispatchResult {
+			T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+			Self::do_set_dissent_slash_percentage(value)
+		}
+
+		/// Move unbonding stake back into a hotkeys subnet stake
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `hotkey` - Hotkey of subnet node
+		/// * `stake_to_be_rebonded` - Amount to take from the unbonding ledger
+		///
+		/// # Requirements
+		///
+		/// * Coldkey caller only
+		/// * Hotkey must be a subnet node of the subnet
+		/// * Stake balance must stay within `MinStakeBalance` and `MaxStakeBalance`
+		///
+		#[pallet::call_index(59)]
+		#[pallet::weight({0})]
+		pub fn rebond(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			hotkey: T::AccountId,
+			stake_to_be_rebonded: u128,
+		) -> DispatchResult {
+			Self::is_paused()?;
+
+			let coldkey: T::AccountId = ensure_signed(origin.clone())?;
+
+			ensure!(
+				SubnetsData::<T>::contains_key(subnet_id),
+				Error::<T>::SubnetNotExist
+			);
+
+			ensure!(
+				HotkeyOwner::<T>::get(&hotkey) == coldkey,
+				Error::<T>::NotKeyOwner
+			);
+
+			ensure!(
+				HotkeySubnetNodeId::<T>::contains_key(subnet_id, &hotkey),
+				Error::<T>::NotSubnetNodeOwner
+			);
+
+			Self::do_rebond(
+				origin, 
+				subnet_id,
+				hotkey,
+				stake_to_be_rebonded,
+			)
+		}
+
+		/// Move unbonding delegate stake back into subnet delegate stake
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `stake_to_be_rebonded` - Amount to take from the unbonding ledgers
+		///
+		/// # Requirements
+		///
+		/// * Subnet must exist
+		///
+		#[pallet::call_index(60)]
+		#[pallet::weight({0})]
+		pub fn rebond_delegate_stake(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			stake_to_be_rebonded: u128,
+		) -> DispatchResult {
+			Self::is_paused()?;
+
+			ensure!(
+				SubnetsData::<T>::contains_key(subnet_id),
+				Error::<T>::SubnetNotExist
+			);
+
+			Self::do_rebond_delegate_stake(
+				origin, 
+				subnet_id,
+				stake_to_be_rebonded,
+			)
+		}
+
+		/// Transfer stake between hotkeys and subnets of the same coldkey without unbonding
+		///
+		/// # Arguments
+		///
+		/// * `from_subnet_id` - Subnet ID unstaking from.
+		/// * `from_hotkey` - Hotkey unstaking from.
+		/// * `to_subnet_id` - Subnet ID staking to.
+		/// * `to_hotkey` - Hotkey of subnet node staking to.
+		/// * `stake_to_be_transferred` - Amount to transfer
+		///
+		/// # Requirements
+		///
+		/// * Coldkey caller only
+		/// * Coldkey must own both hotkeys
+		/// * `to_hotkey` must be a subnet node of `to_subnet_id`
+		/// * One transfer per `StakeTransferPeriod`
+		///
+		#[pallet::call_index(61)]
+		#[pallet::weight({0})]
+		pub fn transfer_stake(
+			origin: OriginFor<T>, 
+			from_subnet_id: u32,
+			from_hotkey: T::AccountId,
+			to_subnet_id: u32,
+			to_hotkey: T::AccountId,
+			stake_to_be_transferred: u128,
+		) -> DispatchResult {
+			Self::is_paused()?;
+
+			let coldkey: T::AccountId = ensure_signed(origin.clone())?;
+
+			ensure!(
+				SubnetsData::<T>::contains_key(to_subnet_id),
+				Error::<T>::SubnetNotExist
+			);
+
+			ensure!(
+				HotkeyOwner::<T>::get(&from_hotkey) == coldkey &&
+				HotkeyOwner::<T>::get(&to_hotkey) == coldkey,
+				Error::<T>::NotKeyOwner
+			);
+
+			ensure!(
+				HotkeySubnetNodeId::<T>::contains_key(to_subnet_id, &to_hotkey),
+				Error::<T>::NotSubnetNodeOwner
+			);
+
+			Self::do_transfer_stake(
+				origin, 
+				from_subnet_id,
+				from_hotkey,
+				to_subnet_id,
+				to_hotkey,
+				stake_to_be_transferred,
+			)
+		}
+
+		#[pallet::call_index(62)]
+		#[pallet::weight({0})]
+		pub fn set_stake_transfer_period(
+			origin: OriginFor<T>, 
+			value: u32
+		) -> DispatchResult {
+			T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+			Self::do_set_stake_transfer_period(value)
+		}
+
+		#[pallet::call_index(63)]
+		#[pallet::weight({0})]
+		pub fn set_node_delegate_slash_share(
+			origin: OriginFor<T>, 
+			value: u128
+		) -> DispatchResult {
+			T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+			Self::do_set_node_delegate_slash_share(value)
+		}
+
+		#[pallet::call_index(64)]
+		#[pallet::weight({0})]
+		pub fn set_max_subnet_removal_items_per_block(
+			origin: OriginFor<T>, 
+			value: u32
+		) -> DispatchResult {
+			T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+			Self::do_set_max_subnet_removal_items_per_block(value)
+		}
+
+		/// Transfer subnet delegate stake shares to another account
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `to` - Account receiving the shares.
+		/// * `shares` - Shares to transfer.
+		///
+		/// # Requirements
+		///
+		/// * `SubnetDelegateStakeToken` must be enabled for the subnet
+		/// * Caller must have `shares` that aren't reserved or in its `DelegateStakeCooldown`
+		/// * Both accounts must keep `MinDelegateStakeBalance`, or the caller transfers all of its shares
+		/// * `to` must stay under `MaxDelegateStakeBalance`
+		/// * The transferred shares are in the `DelegateStakeCooldown` of `to`
+		///
+		#[pallet::call_index(65)]
+		#[pallet::weight({0})]
+		pub fn transfer_delegate_stake_token(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			to: T::AccountId,
+			shares: u128,
+		) -> DispatchResult {
+			Self::is_paused()?;
+
+			let account_id: T::AccountId = ensure_signed(origin)?;
+
+			Self::do_transfer_delegate_stake_token(&account_id, subnet_id, &to, shares)
+		}
+
+		/// Enable or disable the subnets delegate stake shares as a transferable token
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `value` - If the subnets delegate stake shares are transferable.
+		///
+		/// # Requirements
+		///
+		/// * Caller must be the subnet owner
+		///
+		/// Shares reserved while enabled can still be claimed or unreserved after disabling
+		///
+		#[pallet::call_index(66)]
+		#[pallet::weight({0})]
+		pub fn owner_update_delegate_stake_token(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			value: bool
+		) -> DispatchResult {
+			Self::is_paused()?;
+			Self::do_owner_update_delegate_stake_token(origin, subnet_id, value)
+		}
+
+		/// Transfer subnet delegate stake shares to another account without unstaking
+		///
+		/// # Arguments
+		///
+		/// * `to` - Account receiving the shares.
+		/// * `subnet_id` - Subnet ID.
+		/// * `shares` - Shares to transfer.
+		///
+		/// # Requirements
+		///
+		/// * Both accounts must keep `MinDelegateStakeBalance`, or the caller transfers all of its shares
+		/// * `to` must stay under `MaxDelegateStakeBalance`
+		/// * Shares in the callers `DelegateStakeCooldown` can't be transferred
+		/// * The transferred shares are in the `DelegateStakeCooldown` of `to`
+		///
+		#[pallet::call_index(67)]
+		#[pallet::weight({0})]
+		pub fn transfer_delegate_stake_shares(
+			origin: OriginFor<T>, 
+			to: T::AccountId,
+			subnet_id: u32,
+			shares: u128,
+		) -> DispatchResult {
+			Self::is_paused()?;
+
+			let account_id: T::AccountId = ensure_signed(origin)?;
+
+			Self::do_transfer_delegate_stake_shares(&account_id, &to, subnet_id, shares)
+		}
+
+		/// Transfer node delegate stake shares to another account without unstaking
+		///
+		/// # Arguments
+		///
+		/// * `to` - Account receiving the shares.
+		/// * `subnet_id` - Subnet ID.
+		/// * `subnet_node_id` - Subnet node ID.
+		/// * `shares` - Shares to transfer.
+		///
+		/// # Requirements
+		///
+		/// * Both accounts must keep `MinDelegateStakeBalance`, or the caller transfers all of its shares
+		/// * Shares in the callers `NodeDelegateStakeCooldown` can't be transferred
+		/// * The transferred shares are in the `NodeDelegateStakeCooldown` of `to`
+		///
+		#[pallet::call_index(68)]
+		#[pallet::weight({0})]
+		pub fn transfer_node_delegate_stake_shares(
+			origin: OriginFor<T>, 
+			to: T::AccountId,
+			subnet_id: u32,
+			subnet_node_id: u32,
+			shares: u128,
+		) -> DispatchResult {
+			Self::is_paused()?;
+
+			let account_id: T::AccountId = ensure_signed(origin)?;
+
+			Self::do_transfer_node_delegate_stake_shares(&account_id, &to, subnet_id, subnet_node_id, shares)
+		}
+
+		/// Set or clear the cap on the total delegate stake balance of a subnet
+		///
+		/// # Arguments
+		///
+		/// * `subnet_id` - Subnet ID.
+		/// * `value` - Max delegate stake balance of the subnet, or `None` for no cap.
+		///
+		/// # Requirements
+		///
+		/// * Caller must be the subnet owner
+		///
+		#[pallet::call_index(69)]
+		#[pallet::weight({0})]
+		pub fn owner_update_max_subnet_delegate_stake(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			value: Option<u128>
+		) -> DispatchResult {
+			Self::is_paused()?;
+			Self::do_owner_update_max_subnet_delegate_stake(origin, subnet_id, value)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -3367,6 +4595,13 @@ pub mod pallet {
 				Error::<T>::MaxSubnetActivation
 			);
 
+			if let Some(validator_args_type) = &subnet_registration_data.validator_args_type {
+				ensure!(
+					Self::is_valid_validator_args_type(validator_args_type, 0),
+					Error::<T>::InvalidValidatorArgsType
+				);
+			}
+
 			let subnet_fee: u128 = Self::registration_cost(epoch);
 
 			if subnet_fee > 0 {
@@ -3406,6 +4641,10 @@ pub mod pallet {
 			SubnetNodeActivationInterval::<T>::insert(subnet_id, subnet_registration_data.node_activation_interval);
 			// Store max node penalties
 			MaxSubnetNodePenalties::<T>::insert(subnet_id, subnet_registration_data.max_node_penalties);
+			// Store validator args type
+			if let Some(validator_args_type) = subnet_registration_data.validator_args_type {
+				SubnetValidatorArgsType::<T>::insert(subnet_id, validator_args_type);
+			}
 
 			// Store whitelisted coldkeys for registration period
 			SubnetRegistrationColdkeyWhitelist::<T>::insert(
@@ -3548,34 +4787,28 @@ pub mod pallet {
 				TotalActiveSubnets::<T>::mutate(|n: &mut u32| n.saturating_dec());
 			}
 
-			// Remove all subnet nodes data
-			let _ = SubnetNodesData::<T>::clear_prefix(subnet_id, u32::MAX, None);
-
-			let subnet_node_ids: Vec<u32> = Self::get_classified_subnet_node_ids(subnet_id, &SubnetNodeClass::Validator, 0);
-			let subnet_nodes_count = subnet_node_ids.len();  
-			TotalActiveNodes::<T>::mutate(|n: &mut u32| n.saturating_reduce(subnet_nodes_count as u32));
+			let active_subnet_nodes: u32 = TotalActiveSubnetNodes::<T>::take(subnet_id);
+			TotalActiveNodes::<T>::mutate(|n: &mut u32| n.saturating_reduce(active_subnet_nodes));
 
 			let _ = TotalSubnetNodes::<T>::remove(subnet_id);
 			let _ = TotalSubnetNodeUids::<T>::remove(subnet_id);
-			let _ = PeerIdSubnetNode::<T>::clear_prefix(subnet_id, u32::MAX, None);
-			let _ = BootstrapPeerIdSubnetNode::<T>::clear_prefix(subnet_id, u32::MAX, None);			
-			let _ = SubnetNodeUniqueParam::<T>::clear_prefix(subnet_id, u32::MAX, None);
-			let _ = HotkeySubnetNodeId::<T>::clear_prefix(subnet_id, u32::MAX, None);
-			let _ = SubnetNodeIdHotkey::<T>::clear_prefix(subnet_id, u32::MAX, None);
-			let _ = SubnetNodeNonUniqueParamLastSet::<T>::clear_prefix(subnet_id, u32::MAX, None);
-			let _ = SubnetNodePenalties::<T>::clear_prefix(subnet_id, u32::MAX, None);
 			let _ = SubnetNodeRegistrationInterval::<T>::remove(subnet_id);
 
 			// Remove all subnet consensus data
 			let _ = SubnetPenaltyCount::<T>::remove(subnet_id);
+			let _ = SubnetEpochRewardsPruneEpoch::<T>::remove(subnet_id);
+			let _ = SubnetRewardsSubmissionPruneEpoch::<T>::remove(subnet_id);
+			let _ = SubnetValidatorArgsType::<T>::remove(subnet_id);
+			let _ = SubnetCommitReveal::<T>::remove(subnet_id);
+			let _ = SubnetValidatorCommitteeSize::<T>::remove(subnet_id);
+			let _ = MaxNodeRewardShare::<T>::remove(subnet_id);
+			let _ = SubnetDelegateStakeToken::<T>::remove(subnet_id);
+			let _ = MaxSubnetDelegateStake::<T>::remove(subnet_id);
+
+			// Unbond the balances and remove the subnet nodes, consensus data, rewards receipts,
+			// and proposals across blocks in `on_idle`
+			RemovingSubnets::<T>::insert(subnet_id, SubnetRemovalCursor::default());
 
-			// Remove consensus data
-			let _ = SubnetRewardsValidator::<T>::clear_prefix(subnet_id, u32::MAX, None);
-			let _ = SubnetRewardsSubmission::<T>::clear_prefix(subnet_id, u32::MAX, None);
-
-			// Remove proposals
-			let _ = Proposals::<T>::clear_prefix(subnet_id, u32::MAX, None);
-	
 			Self::deposit_event(Event::SubnetDeactivated { subnet_id: subnet_id, reason: reason });
 
 			Ok(())
@@ -3864,7 +5097,7 @@ pub mod pallet {
 				}
 			)?;
 
-			TotalActiveNodes::<T>::mutate(|n: &mut u32| *n += 1);
+			Self::increase_active_subnet_nodes(subnet_id);
 	
 			// --- Update last subnet entry only if it's not register / is active
 			if !is_registering {
@@ -3978,6 +5211,7 @@ pub mod pallet {
 						class: SubnetNodeClass::Deactivated,
 						start_epoch: epoch, // update to current epoch
 					};
+					Self::decrease_active_subnet_nodes(subnet_id);
 					Ok(())
 				}
 			)?;
@@ -4032,7 +5266,10 @@ pub mod pallet {
 						subnet_id,
 						subnet_node_id,
 						|maybe_params| -> DispatchResult {
-							let params = maybe_params.as_mut().ok_or(Error::<T>::SubnetNodeExist)?;							
+							let params = maybe_params.as_mut().ok_or(Error::<T>::SubnetNodeExist)?;
+							if params.classification.class >= SubnetNodeClass::Queue {
+								Self::decrease_active_subnet_nodes(subnet_id);
+							}
 							params.classification = SubnetNodeClassification {
 								class: SubnetNodeClass::Deactivated,
 								start_epoch: epoch + 1,
@@ -4053,12 +5290,19 @@ pub mod pallet {
 			T::WeightInfo::do_deactivation_ledger(subnet_ids.len() as u32, i)
 		}
 
-		pub fn do_queue(block: u32) {
+		/// Activate the first queued subnet node of each subnet on its registration interval
+		///
+		/// Returns the weight from the amount of subnets
+		pub fn do_queue(block: u32) -> Weight {
 			let current_epoch: u32 = Self::get_current_epoch_as_u32();
 			let epoch_length: u32 = T::EpochLength::get();
 			let queue_epochs: u32 = 16;
 
+			let mut subnets: u32 = 0;
+
 			for (subnet_id, _) in SubnetsData::<T>::iter() {
+				subnets += 1;
+
 				let mut max_nodes_per_epoch = Self::get_subnet_churn_limit(subnet_id);
 				if max_nodes_per_epoch > epoch_length {
 					max_nodes_per_epoch = epoch_length;
@@ -4084,8 +5328,8 @@ pub mod pallet {
 								new_subnet_node.classification = new_classification;
 
 								// --- Activate
+								// Counted as active once activated from `Registered`
 								SubnetNodesData::<T>::insert(subnet_id, subnet_node.id, new_subnet_node);	
-								TotalActiveSubnetNodes::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
 
 								// --- Remove from queue
 								subnet_nodes.remove(&uid.clone());
@@ -4094,6 +5338,8 @@ pub mod pallet {
 					});
 				}
 			}
+
+			T::WeightInfo::do_queue(subnets)
 		}
 	}
 
@@ -4123,39 +5369,48 @@ pub mod pallet {
 			let block: u32 = Self::convert_block_as_u32(block_number);
 			let epoch_length: u32 = T::EpochLength::get();
 
+			// --- Weight of ``do_queue`` in ``on_finalize`` and reading ``TxPause`` and ``MaxSubnets``
+			// Subnets over the max are removed in ``do_epoch_preliminaries``, one per epoch
+			let queue_weight: Weight = T::WeightInfo::do_queue(MaxSubnets::<T>::get().saturating_add(1))
+				.saturating_add(T::DbWeight::get().reads(2));
+
 			// Reward subnet nodes
 			if block >= epoch_length && block % epoch_length == 0 {
 				let epoch: u32 = block / epoch_length;
 
 				// Reward subnets for the previous epoch
 				// Reward before shifting
-				Self::reward_subnets_v2(block, epoch - 1);
+				// Rewards continue in the following blocks if they don't fit in this one
+				let weight: Weight = Self::begin_reward_subnets(block, epoch - 1);
+
+				return weight
+					.saturating_add(Self::do_reward_subnets_step(
+						MaxRewardsItemsPerBlock::<T>::get(),
+						Self::get_rewards_weight_per_block(),
+					))
+					.saturating_add(queue_weight)
+			}
 
-				// return T::WeightInfo::on_initialize_reward_subnets();
-				return Weight::from_parts(207_283_478_000, 22166406)
-					.saturating_add(T::DbWeight::get().reads(18250_u64))
-					.saturating_add(T::DbWeight::get().writes(12002_u64));
+			// --- Continue rewarding the previous epoch
+			let weight: Weight = Self::do_reward_subnets_step(
+				MaxRewardsItemsPerBlock::<T>::get(),
+				Self::get_rewards_weight_per_block(),
+			)
+				.saturating_add(queue_weight);
 
-			} else if (block - 1) >= epoch_length && (block - 1) % epoch_length == 0 {
+			if (block - 1) >= epoch_length && (block - 1) % epoch_length == 0 {
 				// --- Execute deactivate ledger before choosing validators
-				return Self::do_deactivation_ledger()
+				return Self::do_deactivation_ledger().saturating_add(weight)
 			} else if (block - 2) >= epoch_length && (block - 2) % epoch_length == 0 {
 				// We save some weight by waiting one more block to choose validators
 				// Run the block succeeding form consensus
 				let epoch: u32 = block / epoch_length;
 
 				// Choose validators for the current epoch
-				Self::do_epoch_preliminaries(block, epoch, epoch_length);
-
-				return Weight::from_parts(207_283_478_000, 22166406)
-					.saturating_add(T::DbWeight::get().reads(18250_u64))
-					.saturating_add(T::DbWeight::get().writes(12002_u64));
+				return Self::do_epoch_preliminaries(block, epoch, epoch_length).saturating_add(weight)
 			}
 
-			// return T::WeightInfo::on_initialize()
-			return Weight::from_parts(207_283_478_000, 22166406)
-				.saturating_add(T::DbWeight::get().reads(18250_u64))
-				.saturating_add(T::DbWeight::get().writes(12002_u64))
+			weight
 		}
 
 		fn on_finalize(block_number: BlockNumberFor<T>) {
@@ -4163,25 +5418,51 @@ pub mod pallet {
 			Self::do_queue(block);
 		}
 
-		fn on_idle(block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
-			let block: u32 = Self::convert_block_as_u32(block_number);
+		fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			if Self::is_paused().is_err() {
+				return Weight::from_parts(0, 0)
+			}
 
 			if remaining_weight.any_lt(T::DbWeight::get().reads(2)) {
 				return Weight::from_parts(0, 0)
 			}
 
-			return Weight::from_parts(0, 0)
+			Self::do_on_idle(remaining_weight)
+		}
 
-			// Self::do_on_idle(remaining_weight)
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_block_number: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		pub(crate) fn do_on_idle(remaining_weight: Weight) -> Weight {
-			// any weight that is unaccounted for
-			let mut unaccounted_weight = Weight::from_parts(0, 0);
+			let mut weight: Weight = T::DbWeight::get().reads(1);
+
+			// --- Continue rewarding the previous epoch with the leftover block weight
+			if EpochRewardsCursor::<T>::exists() {
+				let rewards_weight: Weight = remaining_weight.saturating_sub(T::DbWeight::get().reads_writes(3, 1));
+
+				weight = weight
+					.saturating_add(T::DbWeight::get().reads(1))
+					.saturating_add(Self::do_reward_subnets_step(u32::MAX, rewards_weight));
+			}
+
+			// --- Clean up removed subnets with what's left
+			let removal_weight: Weight = remaining_weight
+				.saturating_sub(weight)
+				.saturating_sub(T::DbWeight::get().reads_writes(3, 1));
+
+			let max_items: u64 = Self::max_items_within(removal_weight, Self::subnet_removal_item_weight())
+				.min(MaxSubnetRemovalItemsPerBlock::<T>::get() as u64);
+
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			if max_items == 0 {
+				return weight
+			}
 
-			unaccounted_weight
+			weight.saturating_add(Self::do_remove_subnets_step(max_items as u32))
 		}
 	}
 
@@ -4239,6 +5520,7 @@ pub mod pallet {
 			let min_subnet_delegate_stake_balance = total_network_issuance.saturating_mul(factor).saturating_div(1000000000);
 
 			TotalSubnetDelegateStakeBalance::<T>::insert(subnet_id, min_subnet_delegate_stake_balance);
+			TotalDelegateStake::<T>::mutate(|n: &mut u128| n.saturating_accrue(min_subnet_delegate_stake_balance));
 			
 			// --- Initialize subnet nodes
 			// Only initialize to test using subnet nodes
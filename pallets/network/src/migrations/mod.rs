@@ -0,0 +1,23 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Storage migrations
+//
+// Each `vN` module migrates the storage from version `N - 1` to `N` and is wrapped in a
+// `VersionedMigration` so it only runs when the on-chain storage version matches.
+// Add the migration to the runtimes `Migrations` for the next runtime upgrade.
+use super::*;
+pub mod v1;
+pub mod v2;
@@ -0,0 +1,213 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use codec::{DecodeAll, Input};
+use frame_support::{
+  migrations::VersionedMigration,
+  pallet_prelude::*,
+  traits::UncheckedOnRuntimeUpgrade,
+};
+use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::vec;
+use sp_std::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Storage layouts before storage version 1
+pub mod v0 {
+  use super::*;
+
+  /// Subnet data with the epoch the subnet registered
+  ///
+  /// The registration epoch is stored in `SubnetRegistrationEpoch` since version 1
+  #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+  pub struct SubnetData {
+    pub id: u32,
+    pub path: Vec<u8>,
+    pub state: SubnetState,
+    pub registered: u32,
+  }
+
+  /// Rewards data keyed by hotkeys with the block of each attestation as ``u64``
+  ///
+  /// Validators and attestors are subnet node IDs since version 1
+  #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+  pub struct RewardsData<AccountId: Ord> {
+    pub validator: AccountId,
+    pub attests: BTreeMap<AccountId, u64>,
+    pub data: Vec<SubnetNodeData>,
+    pub args: Option<BoundedVec<u8, DefaultValidatorArgsLimit>>,
+  }
+}
+
+/// A storage value in either the current layout or the version 0 layout
+///
+/// Chains that never declared a storage version can hold values in both layouts
+pub enum Versioned<Current, Old> {
+  Current(Current),
+  V0(Old),
+}
+
+impl<Current: Decode, Old: Decode> Decode for Versioned<Current, Old> {
+  fn decode<I: Input>(input: &mut I) -> Result<Self, codec::Error> {
+    // --- Storage values aren't length prefixed so the layout is found by decoding all of the bytes
+    let len: usize = input.remaining_len()?.ok_or("Unknown storage value length")?;
+    let mut bytes: Vec<u8> = vec![0u8; len];
+    input.read(&mut bytes)?;
+
+    if let Ok(value) = Current::decode_all(&mut &bytes[..]) {
+      return Ok(Self::Current(value))
+    }
+
+    Old::decode_all(&mut &bytes[..]).map(Self::V0)
+  }
+}
+
+/// Migrates `SubnetsData` and `SubnetRewardsSubmission` from the version 0 layouts
+///
+/// Use `MigrateV0ToV1` so the migration only runs on storage version 0
+pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+  fn on_runtime_upgrade() -> Weight {
+    let mut reads: u64 = 0;
+    let mut writes: u64 = 0;
+    let mut translated: u32 = 0;
+
+    // --- Move the registration epoch of registering subnets into `SubnetRegistrationEpoch`
+    SubnetsData::<T>::translate::<Versioned<SubnetData, v0::SubnetData>, _>(|subnet_id, subnet| {
+      reads += 1;
+      writes += 1;
+
+      let subnet: v0::SubnetData = match subnet {
+        Versioned::Current(subnet) => return Some(subnet),
+        Versioned::V0(subnet) => subnet,
+      };
+
+      translated += 1;
+
+      reads += 1;
+      if subnet.state == SubnetState::Registered && !SubnetRegistrationEpoch::<T>::contains_key(subnet_id) {
+        SubnetRegistrationEpoch::<T>::insert(subnet_id, subnet.registered);
+        writes += 1;
+      }
+
+      Some(SubnetData {
+        id: subnet.id,
+        path: subnet.path,
+        state: subnet.state,
+      })
+    });
+
+    // --- Key validators and attestors by subnet node ID
+    // Submissions of validators that are no longer subnet nodes are removed
+    SubnetRewardsSubmission::<T>::translate::<Versioned<RewardsData, v0::RewardsData<T::AccountId>>, _>(
+      |subnet_id, _, submission| {
+        reads += 1;
+        writes += 1;
+
+        let submission: v0::RewardsData<T::AccountId> = match submission {
+          Versioned::Current(submission) => return Some(submission),
+          Versioned::V0(submission) => submission,
+        };
+
+        translated += 1;
+
+        reads += 1 + submission.attests.len() as u64;
+        let validator_id: u32 = HotkeySubnetNodeId::<T>::get(subnet_id, &submission.validator)?;
+
+        let attests: BTreeMap<u32, u32> = submission.attests
+          .into_iter()
+          .filter_map(|(hotkey, block)| {
+            HotkeySubnetNodeId::<T>::get(subnet_id, &hotkey)
+              .map(|subnet_node_id| (subnet_node_id, block.saturated_into::<u32>()))
+          })
+          .collect();
+
+        Some(RewardsData {
+          validator_id: validator_id,
+          attests: attests,
+          data: submission.data,
+          args: submission.args,
+        })
+      }
+    );
+
+    log::info!("pallet-network v1 migration translated {:?} values", translated);
+
+    T::DbWeight::get().reads_writes(reads, writes)
+  }
+
+  #[cfg(feature = "try-runtime")]
+  fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+    let subnets: u32 = SubnetsData::<T>::iter_keys().count() as u32;
+    let submissions: u32 = SubnetRewardsSubmission::<T>::iter_keys().count() as u32;
+
+    Ok((subnets, submissions).encode())
+  }
+
+  #[cfg(feature = "try-runtime")]
+  fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+    let (subnets, submissions): (u32, u32) = Decode::decode(&mut &state[..])
+      .map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+    // --- No subnets are removed and all of them decode in the current layout
+    ensure!(
+      SubnetsData::<T>::iter_values().count() as u32 == subnets,
+      "Subnets were lost in the migration"
+    );
+
+    for (subnet_id, subnet) in SubnetsData::<T>::iter() {
+      ensure!(subnet.id == subnet_id, "Subnet data ID doesn't match its key");
+      if subnet.state == SubnetState::Registered {
+        ensure!(
+          SubnetRegistrationEpoch::<T>::contains_key(subnet_id),
+          "Registering subnet has no registration epoch"
+        );
+      }
+    }
+
+    // --- Only submissions of removed validators are dropped
+    let submissions_after: u32 = SubnetRewardsSubmission::<T>::iter_keys().count() as u32;
+    ensure!(submissions_after <= submissions, "Rewards submissions were added in the migration");
+    ensure!(
+      SubnetRewardsSubmission::<T>::iter_values().count() as u32 == submissions_after,
+      "Rewards submissions don't decode in the current layout"
+    );
+
+    // --- Subnet nodes and proposals have no version 0 layout and must already decode
+    ensure!(
+      SubnetNodesData::<T>::iter_values().count() == SubnetNodesData::<T>::iter_keys().count(),
+      "Subnet nodes don't decode in the current layout"
+    );
+    ensure!(
+      Proposals::<T>::iter_values().count() == Proposals::<T>::iter_keys().count(),
+      "Proposals don't decode in the current layout"
+    );
+
+    Ok(())
+  }
+}
+
+/// Migrates the storage from version 0 to version 1 and sets the on-chain storage version
+pub type MigrateV0ToV1<T> = VersionedMigration<
+  0,
+  1,
+  InnerMigrateV0ToV1<T>,
+  Pallet<T>,
+  <T as frame_system::Config>::DbWeight,
+>;
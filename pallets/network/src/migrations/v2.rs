@@ -0,0 +1,105 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+  migrations::VersionedMigration,
+  pallet_prelude::*,
+  traits::UncheckedOnRuntimeUpgrade,
+};
+use sp_std::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Indexes the stake, delegate stake and node delegate stake positions by subnet
+///
+/// Use `MigrateV1ToV2` so the migration only runs on storage version 1
+pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+  fn on_runtime_upgrade() -> Weight {
+    let mut reads: u64 = 0;
+    let mut writes: u64 = 0;
+
+    for (hotkey, subnet_id, balance) in AccountSubnetStake::<T>::iter() {
+      reads += 1;
+      if balance > 0 {
+        SubnetStakeAccounts::<T>::insert(subnet_id, hotkey, ());
+        writes += 1;
+      }
+    }
+
+    for (account_id, subnet_id, shares) in AccountSubnetDelegateStakeShares::<T>::iter() {
+      reads += 1;
+      if shares > 0 {
+        SubnetDelegateStakeAccounts::<T>::insert(subnet_id, account_id, ());
+        writes += 1;
+      }
+    }
+
+    for ((account_id, subnet_id, subnet_node_id), shares) in AccountNodeDelegateStakeShares::<T>::iter() {
+      reads += 1;
+      if shares > 0 {
+        SubnetNodeDelegateStakeAccounts::<T>::insert((subnet_id, account_id, subnet_node_id), ());
+        writes += 1;
+      }
+    }
+
+    log::info!("pallet-network v2 migration indexed {:?} positions", writes);
+
+    T::DbWeight::get().reads_writes(reads, writes)
+  }
+
+  #[cfg(feature = "try-runtime")]
+  fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+    Ok(Vec::new())
+  }
+
+  #[cfg(feature = "try-runtime")]
+  fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+    // --- Every position is indexed by its subnet
+    for (hotkey, subnet_id, balance) in AccountSubnetStake::<T>::iter() {
+      ensure!(
+        balance == 0 || SubnetStakeAccounts::<T>::contains_key(subnet_id, &hotkey),
+        "Stake isn't indexed by subnet"
+      );
+    }
+
+    for (account_id, subnet_id, shares) in AccountSubnetDelegateStakeShares::<T>::iter() {
+      ensure!(
+        shares == 0 || SubnetDelegateStakeAccounts::<T>::contains_key(subnet_id, &account_id),
+        "Delegate stake isn't indexed by subnet"
+      );
+    }
+
+    for ((account_id, subnet_id, subnet_node_id), shares) in AccountNodeDelegateStakeShares::<T>::iter() {
+      ensure!(
+        shares == 0 || SubnetNodeDelegateStakeAccounts::<T>::contains_key((subnet_id, &account_id, subnet_node_id)),
+        "Node delegate stake isn't indexed by subnet"
+      );
+    }
+
+    Ok(())
+  }
+}
+
+/// Migrates the storage from version 1 to version 2 and sets the on-chain storage version
+pub type MigrateV1ToV2<T> = VersionedMigration<
+  1,
+  2,
+  InnerMigrateV1ToV2<T>,
+  Pallet<T>,
+  <T as frame_system::Config>::DbWeight,
+>;
@@ -17,7 +17,8 @@ use super::*;
 use sp_runtime::Saturating;
 use frame_support::pallet_prelude::DispatchResultWithPostInfo;
 use frame_support::pallet_prelude::Pays;
-use libm::sqrt;
+use frame_support::weights::Weight;
+use sp_runtime::Perbill;
 
 impl<T: Config> Pallet<T> {
   pub fn reward_subnets(block: u32, epoch: u32) -> DispatchResultWithPostInfo {
@@ -374,363 +375,832 @@ impl<T: Config> Pallet<T> {
     Ok(None.into())
   }
 
+  /// Reward all subnets of ``epoch`` in one call
+  ///
+  /// `on_initialize` distributes the rewards across blocks using `begin_reward_subnets` and
+  /// `do_reward_subnets_step` instead
   pub fn reward_subnets_v2(block: u32, epoch: u32) -> DispatchResultWithPostInfo {
+    Self::begin_reward_subnets(block, epoch);
+    while EpochRewardsCursor::<T>::exists() || PendingRewardsEpochs::<T>::exists() {
+      Self::do_reward_subnets_step(u32::MAX, Weight::MAX);
+    }
+
+    Ok(None.into())
+  }
+
+  /// Start rewarding ``epoch``
+  ///
+  /// Gets the epochs emissions and stake weights of each subnet and stores the `RewardsCursor`
+  /// that subnets and subnet nodes are rewarded from in `do_reward_subnets_step`
+  ///
+  /// If the rewards of a previous epoch are still in progress, ``epoch`` is added to the
+  /// `PendingRewardsEpochs` and begins once they finish
+  pub fn begin_reward_subnets(block: u32, epoch: u32) -> Weight {
+    if EpochRewardsCursor::<T>::exists() || PendingRewardsEpochs::<T>::exists() {
+      PendingRewardsEpochs::<T>::append((block, epoch));
+      return T::DbWeight::get().reads_writes(2, 1)
+    }
+
+    Self::start_reward_subnets(block, epoch).saturating_add(T::DbWeight::get().reads(2))
+  }
+
+  /// Begin the first of the `PendingRewardsEpochs`
+  fn begin_pending_reward_subnets() -> Weight {
+    let mut pending_epochs: Vec<(u32, u32)> = PendingRewardsEpochs::<T>::get();
+    if pending_epochs.is_empty() {
+      return T::DbWeight::get().reads(2)
+    }
+
+    let (block, epoch) = pending_epochs.remove(0);
+    if pending_epochs.is_empty() {
+      PendingRewardsEpochs::<T>::kill();
+    } else {
+      PendingRewardsEpochs::<T>::put(pending_epochs);
+    }
+
+    Self::start_reward_subnets(block, epoch).saturating_add(T::DbWeight::get().reads_writes(2, 1))
+  }
+
+  /// Store the `RewardsCursor` of ``epoch``
+  fn start_reward_subnets(block: u32, epoch: u32) -> Weight {
     // --- Get total rewards for this epoch
     let rewards: u128 = Self::get_epoch_emissions(epoch);
-    log::error!("v2 rewards              {:?}", rewards);
+    log::debug!("v2 rewards              {:?}", rewards);
+
+    let stake_weights: Vec<(u32, u128)> = Self::get_subnet_stake_weights();
+    let total_subnets: u32 = stake_weights.len() as u32;
+
+    EpochRewardsCursor::<T>::put(RewardsCursor {
+      epoch: epoch,
+      block: block,
+      rewards: rewards,
+      subnets: stake_weights,
+      subnet: None,
+    });
+
+    T::WeightInfo::begin_reward_subnets(total_subnets)
+  }
 
-    let subnets: Vec<_> = SubnetsData::<T>::iter()
+  /// Share of the block weight the rewards can use in `on_initialize`
+  const REWARDS_BLOCK_WEIGHT_PERCENT: u32 = 25;
+
+  /// Max weight of the rewards in `on_initialize`
+  pub fn get_rewards_weight_per_block() -> Weight {
+    Perbill::from_percent(Self::REWARDS_BLOCK_WEIGHT_PERCENT) * T::BlockWeights::get().max_block
+  }
+
+  /// Get the share of the epoch emissions of each active subnet from the `RewardPolicy`
+  pub fn get_subnet_stake_weights() -> Vec<(u32, u128)> {
+    Self::get_subnet_weights::<T::RewardPolicy>()
+  }
+
+  /// Get the share of the epoch emissions of each active subnet from the weights of `P`
+  pub fn get_subnet_weights<P: RewardPolicy<T>>() -> Vec<(u32, u128)> {
+    let subnets: Vec<u32> = SubnetsData::<T>::iter()
       .filter(|(_, subnet)| subnet.state == SubnetState::Active)
+      .map(|(subnet_id, _)| subnet_id)
       .collect();
 
-    let total_subnets: u32 = subnets.len() as u32;
-    let total_delegate_stake = TotalDelegateStake::<T>::get();
-
-    let mut stake_weights: BTreeMap<&u32, f64> = BTreeMap::new();
-    let mut stake_weight_sum: f64 = 0.0;
+    let mut stake_weights: Vec<(u32, u128)> = Vec::new();
+    let mut stake_weight_sum: u128 = 0;
 
-    for (subnet_id, _) in &subnets {
-      let total_subnet_delegate_stake = TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
-      // 1. Get all weights in f64
-      // *We later use sqrt that uses floats
+    for subnet_id in subnets {
+      let weight: u128 = P::subnet_weight(&SubnetWeightParams {
+        subnet_id: subnet_id,
+        delegate_stake: TotalSubnetDelegateStakeBalance::<T>::get(subnet_id),
+        active_subnet_nodes: TotalActiveSubnetNodes::<T>::get(subnet_id),
+      });
 
-      let weight: f64 = total_subnet_delegate_stake as f64 / total_delegate_stake as f64;
-      let weight_sqrt: f64 = sqrt(weight);
+      stake_weights.push((subnet_id, weight));
+      stake_weight_sum = stake_weight_sum.saturating_add(weight);
+    }
 
-      stake_weights.insert(subnet_id, weight_sqrt);
-      stake_weight_sum += weight_sqrt;
+    // --- Normalize weights
+    for (_, weight) in stake_weights.iter_mut() {
+      *weight = Self::percent_div(*weight, stake_weight_sum);
     }
 
-    let mut stake_weights_normalized: BTreeMap<&u32, u128> = BTreeMap::new();
+    stake_weights
+  }
 
-    // --- Normalize delegate stake weights from `sqrt`
-    for (subnet_id, weight) in stake_weights {
-      let weight_normalized: u128 = (weight / stake_weight_sum * Self::PERCENTAGE_FACTOR as f64) as u128;
-      stake_weights_normalized.insert(subnet_id, weight_normalized);
-    }
+  /// Reward up to ``max_items`` subnets and subnet nodes from the `RewardsCursor`, within ``max_weight``
+  ///
+  /// Each subnet is rewarded in order, first the subnet, then each of its subnet nodes, then
+  /// the subnets delegate stakers
+  ///
+  /// Without rewards in progress, the next of the `PendingRewardsEpochs` begins instead
+  ///
+  /// Returns the weight used
+  pub fn do_reward_subnets_step(max_items: u32, max_weight: Weight) -> Weight {
+    let mut cursor: RewardsCursor = match EpochRewardsCursor::<T>::get() {
+      Some(cursor) => cursor,
+      None => return Self::begin_pending_reward_subnets(),
+    };
+
+    let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+    let mut items: u32 = 0;
+
+    while items < max_items {
+      let (subnet_id, stake_weight) = match cursor.subnets.first() {
+        Some(subnet) => *subnet,
+        None => break,
+      };
+
+      // --- Removed subnets are no longer rewarded
+      if RemovingSubnets::<T>::contains_key(subnet_id) {
+        cursor.subnet = None;
+        cursor.subnets.remove(0);
+        weight = weight.saturating_add(T::DbWeight::get().reads(1));
+        items += 1;
+        continue
+      }
 
-    let subnet_owner_percentage = SubnetOwnerPercentage::<T>::get();
-    let delegate_stake_rewards_percentage: u128 = DelegateStakeRewardsPercentage::<T>::get();
-    let min_attestation_percentage = MinAttestationPercentage::<T>::get();
-    let min_vast_majority_attestation_percentage = MinVastMajorityAttestationPercentage::<T>::get();
-    let min_subnet_nodes = MinSubnetNodes::<T>::get();
-    let node_attestation_removal_threshold = NodeAttestationRemovalThreshold::<T>::get();
-    let max_subnet_penalty_count = MaxSubnetPenaltyCount::<T>::get();
+      match cursor.subnet.take() {
+        None => {
+          let submission: Option<RewardsData> = SubnetRewardsSubmission::<T>::get(subnet_id, cursor.epoch);
+          let data_len: u32 = submission.as_ref().map_or(0, |submission| submission.data.len() as u32);
+          let total_subnet_nodes: u32 = TotalSubnetNodes::<T>::get(subnet_id);
 
-    for (subnet_id, data) in &subnets {
-      let mut attestation_percentage: u128 = 0;
+          let subnet_weight: Weight = T::WeightInfo::reward_subnet(total_subnet_nodes, data_len);
+          if weight.saturating_add(subnet_weight).any_gt(max_weight) {
+            break
+          }
 
-      // --- Get subnet validator submission
-      // --- - Run rewards logic
-      // --- Otherwise, check if validator exists since they didn't submit incentives consensus
-      // --- - Penalize and slash validator if existed
-      if let Ok(mut submission) = SubnetRewardsSubmission::<T>::try_get(subnet_id, epoch) {
-        // --- Get overall subnet rewards
-        let weight: u128 = match stake_weights_normalized.get(&subnet_id) {
-          Some(weight) => {
-            if weight == &0 {
-              continue
-            }
-            *weight
-          },
-          None => continue,
-        };
-        log::error!("v2 weight                {:?}", weight);
+          cursor.subnet = Self::reward_subnet(
+            cursor.block,
+            cursor.epoch,
+            cursor.rewards,
+            subnet_id,
+            stake_weight,
+            submission,
+          );
+          weight = weight.saturating_add(subnet_weight);
+          items += 1;
 
-        let overall_subnet_reward: u128 = Self::percent_mul(rewards, weight);
-        log::error!("v2 overall_subnet_reward {:?}", overall_subnet_reward);
+          // --- Nothing to reward to the subnet nodes
+          if cursor.subnet.is_none() {
+            cursor.subnets.remove(0);
+          }
+        },
+        Some(mut subnet_cursor) => {
+          let node_weight: Weight = T::WeightInfo::reward_subnet_node(subnet_cursor.data_len);
+          let max_nodes: u64 = Self::max_items_within(max_weight.saturating_sub(weight), node_weight)
+            .min((max_items - items) as u64);
+          if max_nodes == 0 {
+            cursor.subnet = Some(subnet_cursor);
+            break
+          }
 
-        // --- Get owner rewards
-        let subnet_owner_reward: u128 = Self::percent_mul(overall_subnet_reward, subnet_owner_percentage);
-        log::error!("v2 subnet_owner_reward   {:?}", subnet_owner_reward);
+          let (completed, count) = Self::reward_subnet_nodes(
+            cursor.block,
+            cursor.epoch,
+            &mut subnet_cursor,
+            max_nodes as u32,
+          );
+          weight = weight.saturating_add(node_weight.saturating_mul(count.into()));
+          items += count;
+
+          if completed {
+            Self::finish_reward_subnet(cursor.epoch, subnet_cursor);
+            cursor.subnets.remove(0);
+          } else {
+            cursor.subnet = Some(subnet_cursor);
+          }
+        },
+      }
+    }
 
-        // --- Get subnet rewards minus owner cut
-        let subnet_reward: u128 = overall_subnet_reward.saturating_sub(subnet_owner_reward);
-        log::error!("v2 subnet_reward         {:?}", subnet_reward);
+    if cursor.subnets.is_empty() {
+      EpochRewardsCursor::<T>::kill();
+    } else {
+      EpochRewardsCursor::<T>::put(cursor);
+    }
 
-        // --- Get delegators rewards
-        let delegate_stake_reward: u128 = Self::percent_mul(subnet_reward, delegate_stake_rewards_percentage);
-        log::error!("v2 delegate_stake_reward {:?}", delegate_stake_reward);
+    weight
+  }
 
-        // --- Get subnet nodes rewards total
-        let subnet_node_reward: u128 = subnet_reward.saturating_sub(delegate_stake_reward);
-        log::error!("v2 subnet_node_reward    {:?}", subnet_node_reward);
+  /// Items of ``item_weight`` that fit in ``max_weight``
+  pub fn max_items_within(max_weight: Weight, item_weight: Weight) -> u64 {
+    (max_weight.ref_time() / item_weight.ref_time().max(1))
+      .min(max_weight.proof_size() / item_weight.proof_size().max(1))
+  }
 
-        // --- Get subnet nodes count to check against attestation count and make sure min nodes are present during time of rewards
-        let subnet_nodes: Vec<T::AccountId> = Self::get_classified_hotkeys(*subnet_id, &SubnetNodeClass::Validator, epoch);
-        let subnet_node_count = subnet_nodes.len() as u128;
+  /// Reward a subnet, its owner, and slash its validators if needed
+  ///
+  /// ``submission`` is the subnets `SubnetRewardsSubmission` of ``epoch``, read by the caller to weigh the subnet
+  ///
+  /// Returns the `SubnetRewardsCursor` if the subnet nodes are to be rewarded next
+  pub fn reward_subnet(
+    block: u32,
+    epoch: u32,
+    rewards: u128,
+    subnet_id: u32,
+    weight: u128,
+    submission: Option<RewardsData>,
+  ) -> Option<SubnetRewardsCursor> {
+    // --- Subnet may have been removed since the rewards began
+    let data: SubnetData = match SubnetsData::<T>::try_get(subnet_id) {
+      Ok(data) if data.state == SubnetState::Active => data,
+      _ => return None,
+    };
+
+    Self::prune_epoch_rewards(subnet_id, epoch);
+    Self::prune_rewards_submissions(subnet_id, epoch);
 
-        // --- Ensure nodes are at min requirement to continue rewards operations
-        if subnet_node_count < min_subnet_nodes as u128 {
-          // We don't give penalties here because they will be given in the next step operation when selecting a new
-          // validator
-          continue
-        }
+    let min_attestation_percentage = MinAttestationPercentage::<T>::get();
+    let min_vast_majority_attestation_percentage = MinVastMajorityAttestationPercentage::<T>::get();
+    let min_subnet_nodes = MinSubnetNodes::<T>::get();
 
-        let attestations: u128 = submission.attests.len() as u128;
-        attestation_percentage = Self::percent_div(attestations, subnet_node_count);
+    let mut attestation_percentage: u128 = 0;
+
+    // --- Get subnet validator submission
+    // --- - Run rewards logic
+    // --- Otherwise, check if validator exists since they didn't submit incentives consensus
+    // --- - Penalize and slash validator if existed
+    if let Some(submission) = submission {
+      // --- Get the committee members that submitted data
+      let mut submitters: Vec<u32> = SubnetCommitteeSubmissions::<T>::take(subnet_id, epoch)
+        .into_keys()
+        .collect();
+      if submitters.is_empty() {
+        submitters.push(submission.validator_id);
+      }
 
-        // Redundant
-        // When subnet nodes exit, the consensus data is updated to remove them from it
-        if attestation_percentage > Self::PERCENTAGE_FACTOR {
-          attestation_percentage = Self::PERCENTAGE_FACTOR;
+      // --- Slash committee members that didn't submit
+      // This includes a primary validator that a backup validator submitted in place of
+      for validator_id in Self::get_validator_committee(subnet_id, epoch) {
+        if !submitters.contains(&validator_id) {
+          Self::slash_validator(subnet_id, validator_id, 0, block);
         }
-        
-        let validator_subnet_node_id: u32 = submission.validator_id;
+      }
+      let _ = SubnetRewardsCommit::<T>::clear_prefix((subnet_id, epoch), u32::MAX, None);
 
-        let data_len = submission.data.len();
-        log::error!("data_len {:?}", data_len);
-
-        /* 
-          - Ensures the subnet has enough nodes.
-            * If validator submits under the minimum nodes we assume the subnet is in an unusable state
-          - If the subnet agrees in the validators logic we don't skip rewards
-            * This is to not incentivize subnets from falsely attesting any epochs that have under the required nodes.
-          - Slashes the validator if attestation is below the required minimum.
-        */
-        // If the number of data points (data_len) is less than the required minimum subnet nodes
-        if (data_len as u32) < min_subnet_nodes {
-          // --- Subnet no longer submitting consensus
-          //     Increase the penalty count
-          SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
-          
-          // Check if the attestation percentage is below the "vast majority" threshold
-          if attestation_percentage < min_vast_majority_attestation_percentage {
-            // If the attestation percentage is also below the minimum required threshold, slash the validator
-            if attestation_percentage < min_attestation_percentage {
-              Self::slash_validator(*subnet_id, validator_subnet_node_id, attestation_percentage, block);
+      // --- Get overall subnet rewards
+      if weight == 0 {
+        return None
+      }
+      log::debug!("v2 weight                {:?}", weight);
+
+      let overall_subnet_reward: u128 = Self::percent_mul(rewards, weight);
+      log::debug!("v2 overall_subnet_reward {:?}", overall_subnet_reward);
+
+      // --- Split between the owner, delegators, and subnet nodes
+      let SubnetRewardsSplit {
+        subnet_owner_reward,
+        mut delegate_stake_reward,
+        mut subnet_node_reward,
+      } = T::RewardPolicy::subnet_rewards_split(subnet_id, overall_subnet_reward);
+      log::debug!("v2 subnet_owner_reward   {:?}", subnet_owner_reward);
+      log::debug!("v2 delegate_stake_reward {:?}", delegate_stake_reward);
+      log::debug!("v2 subnet_node_reward    {:?}", subnet_node_reward);
+
+      // --- Get subnet nodes count to check against attestation count and make sure min nodes are present during time of rewards
+      let subnet_nodes: Vec<T::AccountId> = Self::get_classified_hotkeys(subnet_id, &SubnetNodeClass::Validator, epoch);
+      let subnet_node_count = subnet_nodes.len() as u128;
+
+      // --- Ensure nodes are at min requirement to continue rewards operations
+      if subnet_node_count < min_subnet_nodes as u128 {
+        // We don't give penalties here because they will be given in the next step operation when selecting a new
+        // validator
+        return None
+      }
+
+      let attestations: u128 = submission.attests.len() as u128;
+      attestation_percentage = Self::percent_div(attestations, subnet_node_count);
+
+      // Redundant
+      // When subnet nodes exit, the consensus data is updated to remove them from it
+      if attestation_percentage > Self::PERCENTAGE_FACTOR {
+        attestation_percentage = Self::PERCENTAGE_FACTOR;
+      }
+
+      // --- A low attestation percentage is only from subnet nodes being offline if dissents are clearly low, under half
+      // of the dissent slash percentage
+      let dissents: u128 = SubnetRewardsDissents::<T>::get(subnet_id, epoch).len() as u128;
+      let dissent_percentage: u128 = Self::percent_div(dissents, subnet_node_count).min(Self::PERCENTAGE_FACTOR);
+      let dissent_slash_percentage: u128 = DissentSlashPercentage::<T>::get();
+      let is_offline: bool = dissent_percentage < dissent_slash_percentage / 2;
+
+      let data_len = submission.data.len();
+      log::debug!("data_len {:?}", data_len);
+
+      /* 
+        - Ensures the subnet has enough nodes.
+          * If validator submits under the minimum nodes we assume the subnet is in an unusable state
+        - If the subnet agrees in the validators logic we don't skip rewards
+          * This is to not incentivize subnets from falsely attesting any epochs that have under the required nodes.
+        - Slashes the validator if attestation is below the required minimum.
+      */
+      // If the number of data points (data_len) is less than the required minimum subnet nodes
+      if (data_len as u32) < min_subnet_nodes {
+        // --- Subnet no longer submitting consensus
+        //     Increase the penalty count
+        SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
+        
+        // Check if the attestation percentage is below the "vast majority" threshold
+        if attestation_percentage < min_vast_majority_attestation_percentage {
+          // If the attestation percentage is also below the minimum required threshold and the subnet nodes
+          // weren't offline, slash the validator
+          if attestation_percentage < min_attestation_percentage && !is_offline {
+            for validator_id in &submitters {
+              Self::slash_validator(subnet_id, *validator_id, attestation_percentage, block);
             }
-            // Skip further execution and continue to the next iteration
-            continue;
           }
-          // Subnet agrees with validators submission, continue unless results are None
-          if data_len == 0 {
-            continue
+          // Skip further execution and continue to the next subnet
+          return None
+        }
+        // Subnet agrees with validators submission, continue unless results are None
+        if data_len == 0 {
+          return None
+        }
+      }
+
+      // --- If the minimum required attestation not reached, no rewards are given
+      if attestation_percentage < min_attestation_percentage {
+        // --- Unless subnet nodes were offline, assume validator is dishonest and slash
+        if !is_offline {
+          for validator_id in &submitters {
+            Self::slash_validator(subnet_id, *validator_id, attestation_percentage, block);
           }
         }
 
-        // --- If the minimum required attestation not reached, assume validator is dishonest, slash, and continue
-        // We don't increase subnet penalty count here because this is likely the validators fault
-        if attestation_percentage < min_attestation_percentage {
-          // --- Slash validator and increase penalty score
-          Self::slash_validator(*subnet_id, validator_subnet_node_id, attestation_percentage, block);
-          
-          // --- Attestation not successful, move on to next subnet
-          continue
+        // --- Attestation not successful, move on to next subnet
+        return None
+      }
+
+      // --- Cap the share of the subnet node rewards to each subnet node
+      let max_node_reward_share: u128 = MaxNodeRewardShare::<T>::get(subnet_id);
+      let scores: Vec<u128> = submission.data.iter().map(|x| x.score).collect();
+      let (max_score, sum, excess_share) = Self::get_max_node_score(&scores, max_node_reward_share);
+
+      // --- Rewards no subnet node can receive go to the delegate stakers
+      // The cap is then a share of what's left, so each subnet node still receives ``max_node_reward_share``
+      // of the subnet node rewards
+      let mut node_reward_share: u128 = max_node_reward_share;
+      if excess_share > 0 {
+        let excess_reward: u128 = Self::percent_mul(subnet_node_reward, excess_share);
+        subnet_node_reward = subnet_node_reward.saturating_sub(excess_reward);
+        delegate_stake_reward = delegate_stake_reward.saturating_add(excess_reward);
+        node_reward_share = Self::percent_div(max_node_reward_share, Self::PERCENTAGE_FACTOR - excess_share);
+        log::debug!("v2 excess_reward         {:?}", excess_reward);
+      }
+
+      // --- Deposit owners rewards
+      let subnet_owner: Option<T::AccountId> = SubnetOwner::<T>::try_get(subnet_id).ok();
+      let mut subnet_owner_rewarded: u128 = 0;
+      if let Some(coldkey) = &subnet_owner {
+        let subnet_owner_reward_as_currency = Self::u128_to_balance(subnet_owner_reward);
+        if subnet_owner_reward_as_currency.is_some() {
+          Self::add_balance_to_coldkey_account(
+            coldkey,
+            subnet_owner_reward_as_currency.unwrap()
+          );
+          subnet_owner_rewarded = subnet_owner_reward;
         }
+      }
 
-        // --- Deposit owners rewards
-        match SubnetOwner::<T>::try_get(subnet_id) {
-          Ok(coldkey) => {
-            let subnet_owner_reward_as_currency = Self::u128_to_balance(subnet_owner_reward);
-            if subnet_owner_reward_as_currency.is_some() {
-              Self::add_balance_to_coldkey_account(
-                &coldkey,
-                subnet_owner_reward_as_currency.unwrap()
-              );    
-            }
-          },
-          Err(()) => (),
-        };
+      // --- Subnet nodes rewards are added to the receipt once they are all rewarded
+      SubnetEpochRewards::<T>::insert(
+        subnet_id,
+        epoch,
+        SubnetRewardsReceipt {
+          subnet_owner: subnet_owner,
+          subnet_owner_reward: subnet_owner_rewarded,
+          delegate_stake_reward: delegate_stake_reward,
+          subnet_node_reward: 0,
+          attestation_percentage: attestation_percentage,
+        }
+      );
 
-        // --- Get sum of subnet total scores for use of divvying rewards
-        let sum = submission.data.iter().fold(0, |acc, x| acc.saturating_add(x.score));
+      // --- Validator reward is split between the committee members that submitted
+      let validator_reward: u128 = Self::get_validator_reward(attestation_percentage)
+        .saturating_div(submitters.len() as u128);
+
+      // --- Subnet nodes are rewarded next
+      return Some(SubnetRewardsCursor {
+        subnet_id: subnet_id,
+        subnet_node_id: None,
+        subnet_node_reward: subnet_node_reward,
+        delegate_stake_reward: delegate_stake_reward,
+        score_sum: sum,
+        attestation_percentage: attestation_percentage,
+        validator_reward: validator_reward,
+        submitters: submitters,
+        data_len: data_len as u32,
+        rewarded: 0,
+        max_score: max_score,
+        max_node_reward_share: node_reward_share,
+      })
+    } else if SubnetRewardsValidator::<T>::contains_key(subnet_id, epoch) {
+      // --- If a validator has been chosen that means they are supposed to be submitting consensus data
+      // --- If there is no submission but validator chosen, increase penalty on subnet and validator
+      // --- Increase the penalty count for the subnet
+      // The next validator on the next epoch can increment the penalty score down
+      SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
+
+      // NOTE:
+      //  Each subnet increases the penalty score if they don't have the minimum subnet nodes required by the time
+      //  the subnet is enabled for emissions. This happens by the blockchain validator before choosing the subnet validator
+
+      // If validator didn't submit anything, then slash
+      // Even if a subnet is in a broken state, the chosen validator must submit blank data
+      // On commit-reveal subnets, a commitment that was never revealed is treated as no submission
+      for validator_id in Self::get_validator_committee(subnet_id, epoch) {
+        Self::slash_validator(subnet_id, validator_id, 0, block);
+      }
 
-        let max_subnet_node_registration_epochs = SubnetNodeRegistrationEpochs::<T>::get(subnet_id);
-        let max_subnet_node_penalties = MaxSubnetNodePenalties::<T>::get(subnet_id);
+      let _ = SubnetRewardsCommit::<T>::clear_prefix((subnet_id, epoch), u32::MAX, None);
+    }
 
-        for (subnet_node_id, subnet_node) in SubnetNodesData::<T>::iter_prefix(subnet_id) {
-          let hotkey: T::AccountId = match SubnetNodeIdHotkey::<T>::try_get(subnet_id, subnet_node_id) {
-            Ok(hotkey) => hotkey,
-            Err(()) => continue,
-          };
+    Self::conclude_reward_subnet(subnet_id, data.path, attestation_percentage);
 
-          // --- (if) Check if subnet node is past the max registration epochs to activate (if registered or deactivated)
-          // --- (else if) Check if past Queue and can be included in validation data
-          //
-          // Note: Only ``included`` or above nodes can get emissions
-          if subnet_node.classification.class <= SubnetNodeClass::Registered {
-            if epoch > subnet_node.classification.start_epoch.saturating_add(max_subnet_node_registration_epochs) {
-              Self::perform_remove_subnet_node(block, *subnet_id, subnet_node_id);
-            }
-            continue
-          } else if subnet_node.classification.class == SubnetNodeClass::Queue {
-            // --- Automatically upgrade to Inclusion if activated into Queue class
-            Self::increase_class(*subnet_id, subnet_node_id, epoch);
-            continue
-          }
+    None
+  }
 
-          // --- At this point, all nodes can be included in consensus data and receive rewards
+  /// Get the score subnet nodes are capped at so none receive over ``max_share`` of the subnet node rewards
+  ///
+  /// Rewards over the cap are redistributed pro-rata by score to the subnet nodes under the cap. If every
+  /// subnet node is over the cap, the rest of the subnet node rewards can't be redistributed and each subnet
+  /// node scores the same
+  ///
+  /// Returns the max score, the sum of the capped scores, and the share of the subnet node rewards no subnet
+  /// node can receive
+  pub fn get_max_node_score(scores: &Vec<u128>, max_share: u128) -> (u128, u128, u128) {
+    let mut scores: Vec<u128> = scores.iter().copied().filter(|score| *score > 0).collect();
+    let sum: u128 = scores.iter().fold(0, |acc, score| acc.saturating_add(*score));
+
+    if max_share >= Self::PERCENTAGE_FACTOR || scores.is_empty() {
+      return (u128::MAX, sum, 0)
+    }
 
-          let peer_id: PeerId = subnet_node.peer_id;
+    // --- Cap the highest scores first
+    scores.sort_unstable_by(|a, b| b.cmp(a));
 
-          let subnet_node_data_find = submission.data
-            .iter()
-            .find(|data| data.peer_id == peer_id);
-    
-          let penalties = SubnetNodePenalties::<T>::get(subnet_id, subnet_node_id);
+    let mut rest: u128 = sum;
+    for (capped, score) in scores.iter().enumerate() {
+      let capped_share: u128 = (capped as u128).saturating_mul(max_share);
+      if capped_share >= Self::PERCENTAGE_FACTOR {
+        break
+      }
 
-          if subnet_node_data_find.is_none() {
-            // --- Mutate nodes penalties count if not in consensus
-            SubnetNodePenalties::<T>::insert(subnet_id, subnet_node_id, penalties + 1);
+      // --- Score where a subnet node receives exactly ``max_share`` with ``capped`` subnet nodes capped
+      // max_score / (capped * max_score + rest) == max_share
+      let max_score: u128 = rest
+        .saturating_mul(max_share)
+        .saturating_div(Self::PERCENTAGE_FACTOR - capped_share);
 
-            // --- To be removed or increase penalty count, the consensus threshold must be reached
-            if attestation_percentage > node_attestation_removal_threshold {
-              // We don't slash nodes for not being in consensus
-              // A node can be removed for any reason such as shutting their node down and may not be due to dishonesty
-              // If subnet validators want to remove and slash a node, they can use the proposals mechanism
+      if *score <= max_score {
+        let capped_sum: u128 = (capped as u128).saturating_mul(max_score).saturating_add(rest);
+        return (max_score, capped_sum, 0)
+      }
 
-              // --- Ensure maximum sequential removal consensus threshold is reached
-              // We make sure the super majority are in agreeance to remove someone
-              // TODO: Check the size of subnet and scale it from there
-              if penalties + 1 > max_subnet_node_penalties {
-                // --- Increase account penalty count
-                Self::perform_remove_subnet_node(block, *subnet_id, subnet_node_id);
-              }
-            }
+      rest = rest.saturating_sub(*score);
+    }
 
-            continue
-          }
-          
-          // --- At this point, the subnet node is in the consensus data
+    // --- Every subnet node is capped and receives ``max_share``
+    let total_share: u128 = (scores.len() as u128).saturating_mul(max_share);
+    (1, scores.len() as u128, Self::PERCENTAGE_FACTOR.saturating_sub(total_share))
+  }
 
-          // --- Check if can be included in validation data
-          // By this point, node is validated, update to submittable if they have no penalties
-          let is_included = subnet_node.classification.class == SubnetNodeClass::Included;
-          if is_included && penalties == 0 {
-            // --- Upgrade to Validator
-            Self::increase_class(*subnet_id, subnet_node_id, epoch);
-            continue
-          } else if is_included && penalties != 0 {
-            // --- Decrease subnet node penalty count by one if in consensus and attested consensus
-            SubnetNodePenalties::<T>::mutate(subnet_id, subnet_node_id, |n: &mut u32| n.saturating_dec());
-            continue
-          }
+  /// Reward up to ``max_items`` subnet nodes of the subnet in ``cursor``
+  ///
+  /// Returns if all subnet nodes were rewarded and the amount of subnet nodes rewarded
+  pub fn reward_subnet_nodes(
+    block: u32,
+    epoch: u32,
+    cursor: &mut SubnetRewardsCursor,
+    max_items: u32,
+  ) -> (bool, u32) {
+    let subnet_id: u32 = cursor.subnet_id;
+
+    let submission: RewardsData = match SubnetRewardsSubmission::<T>::try_get(subnet_id, epoch) {
+      Ok(submission) => submission,
+      Err(()) => return (true, 0),
+    };
 
-          // --- At this point, the subnet node is submittable and included in consensus data
+    let min_vast_majority_attestation_percentage = MinVastMajorityAttestationPercentage::<T>::get();
+    let node_attestation_removal_threshold = NodeAttestationRemovalThreshold::<T>::get();
+    let max_subnet_node_registration_epochs = SubnetNodeRegistrationEpochs::<T>::get(subnet_id);
+    let max_subnet_node_penalties = MaxSubnetNodePenalties::<T>::get(subnet_id);
+
+    let attestation_percentage: u128 = cursor.attestation_percentage;
+    let subnet_node_reward: u128 = cursor.subnet_node_reward;
+    let dissents: BTreeMap<u32, Option<T::Hash>> = SubnetRewardsDissents::<T>::get(subnet_id, epoch);
+
+    // --- Continue after the last rewarded subnet node
+    let subnet_nodes = match cursor.subnet_node_id {
+      Some(subnet_node_id) => SubnetNodesData::<T>::iter_prefix_from(
+        subnet_id,
+        SubnetNodesData::<T>::hashed_key_for(subnet_id, subnet_node_id),
+      ),
+      None => SubnetNodesData::<T>::iter_prefix(subnet_id),
+    };
+
+    let mut count: u32 = 0;
+
+    for (subnet_node_id, subnet_node) in subnet_nodes {
+      if count >= max_items {
+        return (false, count)
+      }
 
-          // --- If subnet node does not attest a super majority attested era, we penalize and skip them
-          if !submission.attests.contains_key(&subnet_node_id) {
-            if attestation_percentage > min_vast_majority_attestation_percentage {
-              // --- Penalize on vast majority only
-              SubnetNodePenalties::<T>::insert(subnet_id, subnet_node_id, penalties + 1);
-              continue
-            }  
-          }
+      cursor.subnet_node_id = Some(subnet_node_id);
+      count += 1;
+
+      let hotkey: T::AccountId = match SubnetNodeIdHotkey::<T>::try_get(subnet_id, subnet_node_id) {
+        Ok(hotkey) => hotkey,
+        Err(()) => continue,
+      };
+
+      // --- (if) Check if subnet node is past the max registration epochs to activate (if registered or deactivated)
+      // --- (else if) Check if past Queue and can be included in validation data
+      //
+      // Note: Only ``included`` or above nodes can get emissions
+      if subnet_node.classification.class <= SubnetNodeClass::Registered {
+        if epoch > subnet_node.classification.start_epoch.saturating_add(max_subnet_node_registration_epochs) {
+          Self::perform_remove_subnet_node(block, subnet_id, subnet_node_id);
+        }
+        continue
+      } else if subnet_node.classification.class == SubnetNodeClass::Queue {
+        // --- Automatically upgrade to Inclusion if activated into Queue class
+        Self::increase_class(subnet_id, subnet_node_id, epoch);
+        continue
+      }
 
-          let subnet_node_data: SubnetNodeData = subnet_node_data_find.unwrap().clone();
+      // --- At this point, all nodes can be included in consensus data and receive rewards
 
-          let score = subnet_node_data.score;
+      let peer_id: PeerId = subnet_node.peer_id;
 
-          // --- Validators are allowed to submit scores of 0
-          // This is useful if a subnet wants to keep a node around but not give them rewards
-          // This can be used in scenarios when the max subnet nodes are reached and they don't
-          // want to kick them out as a way to have a waitlist.
-          if score == 0 {
-            continue
-          }
+      let subnet_node_data_find = submission.data
+        .iter()
+        .find(|data| data.peer_id == peer_id);
 
-          // --- Decrease subnet node penalty count by one if in consensus and attested consensus
-          // Don't hit the db unless we have to
-          if penalties != 0 {
-            SubnetNodePenalties::<T>::mutate(subnet_id, subnet_node_id, |n: &mut u32| n.saturating_dec());
-          }
+      let penalties = SubnetNodePenalties::<T>::get(subnet_id, subnet_node_id);
 
-          // --- Calculate score percentage of peer versus sum
-          let score_percentage: u128 = Self::percent_div(subnet_node_data.score, sum as u128);
-          log::error!("v2 score_percentage:      {:?}", score_percentage);
+      if subnet_node_data_find.is_none() {
+        // --- Mutate nodes penalties count if not in consensus
+        SubnetNodePenalties::<T>::insert(subnet_id, subnet_node_id, penalties + 1);
 
-          // --- Calculate score percentage of total subnet generated epoch rewards
-          let mut account_reward: u128 = Self::percent_mul(score_percentage, subnet_node_reward);
-          log::error!("v2 account_reward:             {:?}", account_reward);
-          log::error!("v2 subnet_node_reward:         {:?}", subnet_node_reward);
+        // --- To be removed or increase penalty count, the consensus threshold must be reached
+        if attestation_percentage > node_attestation_removal_threshold {
+          // We don't slash nodes for not being in consensus
+          // A node can be removed for any reason such as shutting their node down and may not be due to dishonesty
+          // If subnet validators want to remove and slash a node, they can use the proposals mechanism
 
-          // --- Skip if no rewards to give
-          // Unlikely to happen
-          if account_reward == 0 {
-            continue
+          // --- Ensure maximum sequential removal consensus threshold is reached
+          // We make sure the super majority are in agreeance to remove someone
+          // TODO: Check the size of subnet and scale it from there
+          if penalties + 1 > max_subnet_node_penalties {
+            // --- Increase account penalty count
+            Self::perform_remove_subnet_node(block, subnet_id, subnet_node_id);
           }
+        }
 
-          if subnet_node.delegate_reward_rate != 0 {
-            // --- Ensure users are staked to subnet node
-            let total_node_delegated_stake_shares = TotalNodeDelegateStakeShares::<T>::get(subnet_id, subnet_node_id);
-            if total_node_delegated_stake_shares != 0 {
-              log::error!("v2 subnet_node.delegate_reward_rate: {:?}", subnet_node.delegate_reward_rate);
-
-              let node_delegate_reward = Self::percent_mul(account_reward, subnet_node.delegate_reward_rate);
-              log::error!("v2 node_delegate_reward:    {:?}", node_delegate_reward);
-              log::error!("v2 b4 account_reward:       {:?}", account_reward);
+        continue
+      }
+      
+      // --- At this point, the subnet node is in the consensus data
+
+      // --- Check if can be included in validation data
+      // By this point, node is validated, update to submittable if they have no penalties
+      let is_included = subnet_node.classification.class == SubnetNodeClass::Included;
+      if is_included && penalties == 0 {
+        // --- Upgrade to Validator
+        Self::increase_class(subnet_id, subnet_node_id, epoch);
+        continue
+      } else if is_included && penalties != 0 {
+        // --- Decrease subnet node penalty count by one if in consensus and attested consensus
+        SubnetNodePenalties::<T>::mutate(subnet_id, subnet_node_id, |n: &mut u32| n.saturating_dec());
+        continue
+      }
 
-              account_reward = account_reward - node_delegate_reward;
-              log::error!("v2 a4 account_reward:       {:?}", account_reward);
+      // --- At this point, the subnet node is submittable and included in consensus data
 
-              Self::do_increase_node_delegate_stake(
-                *subnet_id,
-                subnet_node_id,
-                node_delegate_reward,
-              );  
-            }
+      // --- If subnet node does not attest a super majority attested era, we penalize and skip them
+      // Subnet nodes that dissented are skipped without a penalty
+      if !submission.attests.contains_key(&subnet_node_id) {
+        if attestation_percentage > min_vast_majority_attestation_percentage {
+          // --- Penalize on vast majority only
+          if !dissents.contains_key(&subnet_node_id) {
+            SubnetNodePenalties::<T>::insert(subnet_id, subnet_node_id, penalties + 1);
           }
+          continue
+        }  
+      }
 
-          // --- Increase reward if validator
-          if subnet_node_id == validator_subnet_node_id {
-            log::error!("attestation_percentage: {:?}", attestation_percentage);
+      let subnet_node_data: SubnetNodeData = subnet_node_data_find.unwrap().clone();
 
-            account_reward += Self::get_validator_reward(attestation_percentage);    
-            log::error!("validator reward here:  {:?}", account_reward);
-          }
-          
-          // --- Increase account stake and emit event
-          Self::increase_account_stake(
-            &hotkey,
-            *subnet_id, 
-            account_reward,
-          );
-        }
-        // --- Portion of rewards to delegate stakers
-        Self::do_increase_delegate_stake(
-          *subnet_id,
-          delegate_stake_reward,
-        );
+      let score = subnet_node_data.score;
 
-        // --- Increment down subnet penalty score on successful epochs if result were greater than or equal to the min required nodes
-        if data_len as u32 >= min_subnet_nodes {
-          SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| n.saturating_dec());
+      // --- Validators are allowed to submit scores of 0
+      // This is useful if a subnet wants to keep a node around but not give them rewards
+      // This can be used in scenarios when the max subnet nodes are reached and they don't
+      // want to kick them out as a way to have a waitlist.
+      if score == 0 {
+        continue
+      }
+
+      // --- Decrease subnet node penalty count by one if in consensus and attested consensus
+      // Don't hit the db unless we have to
+      if penalties != 0 {
+        SubnetNodePenalties::<T>::mutate(subnet_id, subnet_node_id, |n: &mut u32| n.saturating_dec());
+      }
+
+      // --- Calculate score percentage of peer versus sum
+      // Scores over the cap get the max node reward share
+      let score_percentage: u128 = T::RewardPolicy::subnet_node_percentage(
+        subnet_node_data.score.min(cursor.max_score),
+        cursor.score_sum
+      ).min(cursor.max_node_reward_share);
+      log::debug!("v2 score_percentage:      {:?}", score_percentage);
+
+      // --- Calculate score percentage of total subnet generated epoch rewards
+      let mut account_reward: u128 = Self::percent_mul(score_percentage, subnet_node_reward);
+      log::debug!("v2 account_reward:             {:?}", account_reward);
+      log::debug!("v2 subnet_node_reward:         {:?}", subnet_node_reward);
+
+      // --- Skip if no rewards to give
+      // Unlikely to happen
+      if account_reward == 0 {
+        continue
+      }
+
+      let mut node_delegate_reward: u128 = 0;
+      if subnet_node.delegate_reward_rate != 0 {
+        // --- Ensure users are staked to subnet node
+        let total_node_delegated_stake_shares = TotalNodeDelegateStakeShares::<T>::get(subnet_id, subnet_node_id);
+        if total_node_delegated_stake_shares != 0 {
+          log::debug!("v2 subnet_node.delegate_reward_rate: {:?}", subnet_node.delegate_reward_rate);
+
+          node_delegate_reward = Self::percent_mul(account_reward, subnet_node.delegate_reward_rate);
+          log::debug!("v2 node_delegate_reward:    {:?}", node_delegate_reward);
+          log::debug!("v2 b4 account_reward:       {:?}", account_reward);
+
+          account_reward = account_reward - node_delegate_reward;
+          log::debug!("v2 a4 account_reward:       {:?}", account_reward);
+
+          Self::do_increase_node_delegate_stake(
+            subnet_id,
+            subnet_node_id,
+            node_delegate_reward,
+          );  
         }
-      } else if let Ok(validator_id) = SubnetRewardsValidator::<T>::try_get(subnet_id, epoch) {
-        // --- If a validator has been chosen that means they are supposed to be submitting consensus data
-        // --- If there is no submission but validator chosen, increase penalty on subnet and validator
-        // --- Increase the penalty count for the subnet
-        // The next validator on the next epoch can increment the penalty score down
-        SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
+      }
 
-        // NOTE:
-        //  Each subnet increases the penalty score if they don't have the minimum subnet nodes required by the time
-        //  the subnet is enabled for emissions. This happens by the blockchain validator before choosing the subnet validator
+      // --- Increase reward if validator
+      let mut validator_reward: u128 = 0;
+      if cursor.submitters.contains(&subnet_node_id) {
+        log::debug!("attestation_percentage: {:?}", attestation_percentage);
 
-        // If validator didn't submit anything, then slash
-        // Even if a subnet is in a broken state, the chosen validator must submit blank data
-        Self::slash_validator(*subnet_id, validator_id, 0, block);
+        validator_reward = cursor.validator_reward;
+        account_reward += validator_reward;
+        log::debug!("validator reward here:  {:?}", account_reward);
       }
-      // TODO: Get benchmark for removing max subnets in one epoch to ensure does not surpass max weights
+      
+      // --- Increase account stake and emit event
+      Self::increase_account_stake(
+        &hotkey,
+        subnet_id, 
+        account_reward,
+      );
+
+      cursor.rewarded = cursor.rewarded
+        .saturating_add(account_reward)
+        .saturating_add(node_delegate_reward);
+
+      SubnetNodeEpochRewards::<T>::insert(
+        (subnet_id, epoch, subnet_node_id),
+        SubnetNodeRewardsReceipt {
+          hotkey: hotkey.clone(),
+          reward: account_reward,
+          validator_reward: validator_reward,
+          node_delegate_reward: node_delegate_reward,
+        }
+      );
 
       Self::deposit_event(
-        Event::RewardResult { 
-          subnet_id: *subnet_id, 
-          attestation_percentage: attestation_percentage, 
+        Event::SubnetNodeRewarded {
+          subnet_id: subnet_id,
+          epoch: epoch,
+          subnet_node_id: subnet_node_id,
+          hotkey: hotkey,
+          reward: account_reward,
+          node_delegate_reward: node_delegate_reward,
         }
       );
+    }
 
-      // --- If subnet is past its max penalty count, remove
-      let subnet_penalty_count = SubnetPenaltyCount::<T>::get(subnet_id);
-      if subnet_penalty_count > max_subnet_penalty_count {
-        Self::do_remove_subnet(
-          data.path.clone(),
-          SubnetRemovalReason::MaxPenalties,
+    (true, count)
+  }
+
+  /// Reward the subnets delegate stakers once all of its subnet nodes are rewarded
+  pub fn finish_reward_subnet(epoch: u32, cursor: SubnetRewardsCursor) {
+    let subnet_id: u32 = cursor.subnet_id;
+
+    // --- Portion of rewards to delegate stakers
+    Self::do_increase_delegate_stake(
+      subnet_id,
+      cursor.delegate_stake_reward,
+    );
+
+    SubnetEpochRewards::<T>::mutate(subnet_id, epoch, |receipt| {
+      if let Some(receipt) = receipt {
+        receipt.subnet_node_reward = cursor.rewarded;
+
+        Self::deposit_event(
+          Event::SubnetRewarded {
+            subnet_id: subnet_id,
+            epoch: epoch,
+            subnet_owner_reward: receipt.subnet_owner_reward,
+            delegate_stake_reward: receipt.delegate_stake_reward,
+            subnet_node_reward: receipt.subnet_node_reward,
+          }
         );
       }
+    });
+
+    // --- Increment down subnet penalty score on successful epochs if result were greater than or equal to the min required nodes
+    if cursor.data_len >= MinSubnetNodes::<T>::get() {
+      SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| n.saturating_dec());
     }
 
-    Ok(None.into())
+    if let Ok(data) = SubnetsData::<T>::try_get(subnet_id) {
+      Self::conclude_reward_subnet(subnet_id, data.path, cursor.attestation_percentage);
+    }
+  }
+
+  /// Max epochs of each history pruned per subnet each time the subnet is rewarded
+  ///
+  /// Greater than 1 so the pruning catches up on epochs the subnet wasn't rewarded in
+  const MAX_PRUNE_EPOCHS: u32 = 4;
+
+  /// Remove the rewards receipts of ``subnet_id`` from `RewardsHistoryEpochs` before ``epoch``
+  ///
+  /// Prunes from the `SubnetEpochRewardsPruneEpoch` up to `MAX_PRUNE_EPOCHS` at a time
+  fn prune_epoch_rewards(subnet_id: u32, epoch: u32) {
+    let cutoff_epoch: u32 = match epoch.checked_sub(RewardsHistoryEpochs::<T>::get()) {
+      Some(cutoff_epoch) => cutoff_epoch,
+      None => return,
+    };
+
+    // --- Receipts before the first prune were removed one epoch at a time as they expired
+    let from_epoch: u32 = SubnetEpochRewardsPruneEpoch::<T>::get(subnet_id).unwrap_or(cutoff_epoch);
+    let to_epoch: u32 = cutoff_epoch
+      .saturating_add(1)
+      .min(from_epoch.saturating_add(Self::MAX_PRUNE_EPOCHS));
+
+    for prune_epoch in from_epoch..to_epoch {
+      SubnetEpochRewards::<T>::remove(subnet_id, prune_epoch);
+      let _ = SubnetNodeEpochRewards::<T>::clear_prefix((subnet_id, prune_epoch), u32::MAX, None);
+    }
+
+    SubnetEpochRewardsPruneEpoch::<T>::insert(subnet_id, to_epoch.max(from_epoch));
+  }
+
+  /// Remove the submission and validator of ``subnet_id`` from `RewardsSubmissionHistoryEpochs` before ``epoch``
+  ///
+  /// Prunes from the `SubnetRewardsSubmissionPruneEpoch` up to `MAX_PRUNE_EPOCHS` at a time
+  fn prune_rewards_submissions(subnet_id: u32, epoch: u32) {
+    let cutoff_epoch: u32 = match epoch.checked_sub(RewardsSubmissionHistoryEpochs::<T>::get()) {
+      Some(cutoff_epoch) => cutoff_epoch,
+      None => return,
+    };
+
+    // --- Submissions before the first prune were removed one epoch at a time as they expired
+    let from_epoch: u32 = SubnetRewardsSubmissionPruneEpoch::<T>::get(subnet_id).unwrap_or(cutoff_epoch);
+    let to_epoch: u32 = cutoff_epoch
+      .saturating_add(1)
+      .min(from_epoch.saturating_add(Self::MAX_PRUNE_EPOCHS));
+
+    for prune_epoch in from_epoch..to_epoch {
+      SubnetRewardsSubmission::<T>::remove(subnet_id, prune_epoch);
+      SubnetRewardsDissents::<T>::remove(subnet_id, prune_epoch);
+      SubnetRewardsValidator::<T>::remove(subnet_id, prune_epoch);
+      SubnetValidatorCommittee::<T>::remove(subnet_id, prune_epoch);
+      SubnetBackupValidators::<T>::remove(subnet_id, prune_epoch);
+      SubnetValidatorSelectionProof::<T>::remove(subnet_id, prune_epoch);
+      SubnetCommitteeSubmissions::<T>::remove(subnet_id, prune_epoch);
+    }
+
+    SubnetRewardsSubmissionPruneEpoch::<T>::insert(subnet_id, to_epoch.max(from_epoch));
+  }
+
+  fn conclude_reward_subnet(subnet_id: u32, path: Vec<u8>, attestation_percentage: u128) {
+    // TODO: Get benchmark for removing max subnets in one epoch to ensure does not surpass max weights
+
+    Self::deposit_event(
+      Event::RewardResult { 
+        subnet_id: subnet_id, 
+        attestation_percentage: attestation_percentage, 
+      }
+    );
+
+    // --- If subnet is past its max penalty count, remove
+    let subnet_penalty_count = SubnetPenaltyCount::<T>::get(subnet_id);
+    if subnet_penalty_count > MaxSubnetPenaltyCount::<T>::get() {
+      let _ = Self::do_remove_subnet(
+        path,
+        SubnetRemovalReason::MaxPenalties,
+      );
+    }
   }
-}
\ No newline at end of file
+}
@@ -14,6 +14,8 @@
 // limitations under the License.
 
 use super::*;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use sp_runtime::DispatchError;
 
 impl<T: Config> Pallet<T> {
   pub fn get_subnet_nodes(
@@ -85,9 +87,144 @@ impl<T: Config> Pallet<T> {
   pub fn get_consensus_data(
     subnet_id: u32,
     epoch: u32
-  ) -> Option<RewardsData> {
-    let data = SubnetRewardsSubmission::<T>::get(subnet_id, epoch);
-    Some(data?)
+  ) -> Option<ConsensusData> {
+    let data = SubnetRewardsSubmission::<T>::get(subnet_id, epoch)?;
+    Some(ConsensusData {
+      validator_id: data.validator_id,
+      attests: data.attests,
+      decoded_args: Self::decode_validator_args(subnet_id, &data.args),
+      data: data.data,
+      args: data.args,
+    })
+  }
+
+  pub fn get_subnet_validator_committee(
+    subnet_id: u32,
+    epoch: u32
+  ) -> Vec<SubnetNodeInfo<T::AccountId>> {
+    Self::get_validator_committee(subnet_id, epoch)
+      .into_iter()
+      .filter_map(|subnet_node_id| {
+        let subnet_node = SubnetNodesData::<T>::try_get(subnet_id, subnet_node_id).ok()?;
+        Some(SubnetNodeInfo {
+          subnet_node_id: subnet_node_id,
+          coldkey: HotkeyOwner::<T>::get(&subnet_node.hotkey),
+          hotkey: subnet_node.hotkey,
+          peer_id: subnet_node.peer_id,
+          classification: subnet_node.classification,
+          a: subnet_node.a,
+          b: subnet_node.b,
+          c: subnet_node.c,
+        })
+      })
+      .collect()
+  }
+
+  // Predict the validator committee and ranked backup validators chosen with the ``block`` seed
+  // The first subnet node ID of the committee is the primary validator
+  pub fn get_subnet_validator_selection(
+    subnet_id: u32,
+    block: u32
+  ) -> (Vec<u32>, Vec<u32>) {
+    if !SubnetsData::<T>::contains_key(subnet_id) {
+      return (Vec::new(), Vec::new());
+    }
+    let epoch: u32 = Self::get_current_epoch_as_u32();
+    let subnet_node_ids: Vec<u32> = Self::get_classified_subnet_node_ids(subnet_id, &SubnetNodeClass::Validator, epoch);
+    Self::select_validator_committee(block, subnet_id, subnet_node_ids)
+  }
+
+  pub fn get_subnet_validator_proof(
+    subnet_id: u32,
+    epoch: u32
+  ) -> Option<ValidatorSelectionProof<T::Hash>> {
+    SubnetValidatorSelectionProof::<T>::get(subnet_id, epoch)
+  }
+
+  /// Dry run the rewards of ``subnet_id`` for ``epoch`` against its current `SubnetRewardsSubmission`
+  ///
+  /// Runs the rewards of `reward_subnets_v2` in a storage transaction that is always rolled back
+  pub fn get_subnet_rewards_preview(
+    subnet_id: u32,
+    epoch: u32
+  ) -> Option<SubnetRewardsPreview<T::AccountId>> {
+    match SubnetsData::<T>::try_get(subnet_id) {
+      Ok(subnet) if subnet.state == SubnetState::Active => (),
+      _ => return None,
+    };
+
+    let preview = with_transaction(|| -> TransactionOutcome<Result<_, DispatchError>> {
+      let block: u32 = Self::get_current_block_as_u32();
+      let rewards: u128 = Self::get_epoch_emissions(epoch);
+      let weight: u128 = Self::get_subnet_stake_weights()
+        .into_iter()
+        .find(|(id, _)| *id == subnet_id)
+        .map_or(0, |(_, weight)| weight);
+
+      // --- Keep the subnet to read its receipts, its removal is checked against the penalty count instead
+      let max_subnet_penalty_count: u32 = MaxSubnetPenaltyCount::<T>::get();
+      MaxSubnetPenaltyCount::<T>::set(u32::MAX);
+
+      // --- Only keep receipts of this run
+      SubnetEpochRewards::<T>::remove(subnet_id, epoch);
+      let _ = SubnetNodeEpochRewards::<T>::clear_prefix((subnet_id, epoch), u32::MAX, None);
+
+      // --- Subnet node ID, hotkey, stake balance, and penalties before rewards
+      let subnet_nodes: Vec<(u32, T::AccountId, u128, u32)> = SubnetNodeIdHotkey::<T>::iter_prefix(subnet_id)
+        .map(|(subnet_node_id, hotkey)| {
+          let stake: u128 = AccountSubnetStake::<T>::get(&hotkey, subnet_id);
+          let penalties: u32 = SubnetNodePenalties::<T>::get(subnet_id, subnet_node_id);
+          (subnet_node_id, hotkey, stake, penalties)
+        })
+        .collect();
+
+      let submission: Option<RewardsData> = SubnetRewardsSubmission::<T>::get(subnet_id, epoch);
+      if let Some(mut cursor) = Self::reward_subnet(block, epoch, rewards, subnet_id, weight, submission) {
+        Self::reward_subnet_nodes(block, epoch, &mut cursor, u32::MAX);
+        Self::finish_reward_subnet(epoch, cursor);
+      }
+
+      let subnet_node_rewards: BTreeMap<u32, SubnetNodeRewardsReceipt<T::AccountId>> = 
+        SubnetNodeEpochRewards::<T>::iter_prefix((subnet_id, epoch)).collect();
+
+      let mut slashes: Vec<(u32, u128)> = Vec::new();
+      let mut penalties: Vec<(u32, u32)> = Vec::new();
+      let mut removed_subnet_nodes: Vec<u32> = Vec::new();
+
+      for (subnet_node_id, hotkey, stake, prev_penalties) in subnet_nodes {
+        let reward: u128 = subnet_node_rewards.get(&subnet_node_id).map_or(0, |receipt| receipt.reward);
+        let slashed: u128 = stake
+          .saturating_add(reward)
+          .saturating_sub(AccountSubnetStake::<T>::get(&hotkey, subnet_id));
+        if slashed > 0 {
+          slashes.push((subnet_node_id, slashed));
+        }
+
+        if !SubnetNodeIdHotkey::<T>::contains_key(subnet_id, subnet_node_id) {
+          removed_subnet_nodes.push(subnet_node_id);
+          continue
+        }
+
+        let subnet_node_penalties: u32 = SubnetNodePenalties::<T>::get(subnet_id, subnet_node_id);
+        if subnet_node_penalties != prev_penalties {
+          penalties.push((subnet_node_id, subnet_node_penalties));
+        }
+      }
+
+      let subnet_penalty_count: u32 = SubnetPenaltyCount::<T>::get(subnet_id);
+
+      TransactionOutcome::Rollback(Ok(SubnetRewardsPreview {
+        subnet: SubnetEpochRewards::<T>::get(subnet_id, epoch),
+        subnet_nodes: subnet_node_rewards.into_iter().collect(),
+        slashes: slashes,
+        penalties: penalties,
+        removed_subnet_nodes: removed_subnet_nodes,
+        subnet_penalty_count: subnet_penalty_count,
+        subnet_removed: subnet_penalty_count > max_subnet_penalty_count,
+      }))
+    });
+
+    preview.ok()
   }
 
   // pub fn get_incentives_data(
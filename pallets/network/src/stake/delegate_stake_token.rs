@@ -0,0 +1,265 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Represents the delegate stake shares of subnets with `SubnetDelegateStakeToken` enabled as a
+// transferable token, with the subnet ID as the asset ID
+//
+// The shares ledger is the token ledger, so adding and removing delegate stake mints and burns
+
+use super::*;
+use sp_runtime::Saturating;
+use frame_support::traits::tokens::{
+  fungibles,
+  DepositConsequence,
+  WithdrawConsequence,
+  Fortitude,
+  Preservation,
+  Provenance,
+};
+
+impl<T: Config> Pallet<T> {
+  /// Transfer delegate stake shares between accounts
+  ///
+  /// # Arguments
+  ///
+  /// * `from` - Account sending the shares.
+  /// * `subnet_id` - Subnet ID of the shares.
+  /// * `to` - Account receiving the shares.
+  /// * `shares` - Shares to transfer.
+  ///
+  pub fn do_transfer_delegate_stake_token(
+    from: &T::AccountId,
+    subnet_id: u32,
+    to: &T::AccountId,
+    shares: u128,
+  ) -> DispatchResult {
+    Self::ensure_delegate_stake_token(subnet_id)?;
+
+    ensure!(
+      from != to,
+      Error::<T>::InvalidDelegateStakeTokenTransfer
+    );
+
+    ensure!(
+      shares > 0 && Self::free_delegate_stake_shares(from, subnet_id) >= shares,
+      Error::<T>::NotEnoughFreeDelegateStakeShares
+    );
+
+    ensure!(
+      Self::transferable_delegate_stake_shares(from, subnet_id) >= shares,
+      Error::<T>::InsufficientCooldown
+    );
+
+    Self::ensure_delegate_stake_shares_transfer(from, to, subnet_id, shares)?;
+
+    Self::move_delegate_stake_shares(from, to, subnet_id, shares);
+
+    Ok(())
+  }
+
+  /// Reserve delegate stake shares so they can't be removed or transferred, i.e. for an atomic swap
+  pub fn reserve_delegate_stake_token(
+    account_id: &T::AccountId,
+    subnet_id: u32,
+    shares: u128,
+  ) -> DispatchResult {
+    Self::ensure_delegate_stake_token(subnet_id)?;
+
+    ensure!(
+      Self::free_delegate_stake_shares(account_id, subnet_id) >= shares,
+      Error::<T>::NotEnoughFreeDelegateStakeShares
+    );
+
+    ensure!(
+      Self::transferable_delegate_stake_shares(account_id, subnet_id) >= shares,
+      Error::<T>::InsufficientCooldown
+    );
+
+    ReservedSubnetDelegateStakeShares::<T>::mutate(account_id, subnet_id, |n| n.saturating_accrue(shares));
+
+    Ok(())
+  }
+
+  /// Unreserve up to `shares` reserved delegate stake shares
+  pub fn unreserve_delegate_stake_token(
+    account_id: &T::AccountId,
+    subnet_id: u32,
+    shares: u128,
+  ) {
+    ReservedSubnetDelegateStakeShares::<T>::mutate_exists(account_id, subnet_id, |reserved| {
+      let remaining = reserved.unwrap_or(0).saturating_sub(shares);
+      *reserved = if remaining == 0 { None } else { Some(remaining) };
+    });
+  }
+
+  /// Move reserved delegate stake shares of `from` into the free shares of `to`
+  pub fn repatriate_reserved_delegate_stake_token(
+    from: &T::AccountId,
+    to: &T::AccountId,
+    subnet_id: u32,
+    shares: u128,
+  ) -> DispatchResult {
+    // --- Shares reserved before the owner disabled the token can still be claimed
+    ensure!(
+      SubnetsData::<T>::contains_key(subnet_id),
+      Error::<T>::SubnetNotExist
+    );
+
+    ensure!(
+      ReservedSubnetDelegateStakeShares::<T>::get(from, subnet_id) >= shares,
+      Error::<T>::NotEnoughFreeDelegateStakeShares
+    );
+
+    // --- The receiver gets the same checks as a transfer
+    if from != to {
+      Self::ensure_delegate_stake_shares_transfer(from, to, subnet_id, shares)?;
+    }
+
+    Self::unreserve_delegate_stake_token(from, subnet_id, shares);
+
+    if from != to {
+      Self::move_delegate_stake_shares(from, to, subnet_id, shares);
+    }
+
+    Ok(())
+  }
+
+  /// Delegate stake shares of an account that aren't reserved
+  pub fn free_delegate_stake_shares(account_id: &T::AccountId, subnet_id: u32) -> u128 {
+    AccountSubnetDelegateStakeShares::<T>::get(account_id, subnet_id)
+      .saturating_sub(ReservedSubnetDelegateStakeShares::<T>::get(account_id, subnet_id))
+  }
+
+  /// Emit the mint of delegate stake shares if the subnet has a delegate stake token
+  pub fn mint_delegate_stake_token(account_id: &T::AccountId, subnet_id: u32, shares: u128) {
+    if SubnetDelegateStakeToken::<T>::get(subnet_id) {
+      Self::deposit_event(Event::DelegateStakeTokenMinted { 
+        subnet_id: subnet_id, 
+        account_id: account_id.clone(), 
+        shares: shares 
+      });
+    }
+  }
+
+  /// Emit the burn of delegate stake shares if the subnet has a delegate stake token
+  pub fn burn_delegate_stake_token(account_id: &T::AccountId, subnet_id: u32, shares: u128) {
+    if SubnetDelegateStakeToken::<T>::get(subnet_id) {
+      Self::deposit_event(Event::DelegateStakeTokenBurned { 
+        subnet_id: subnet_id, 
+        account_id: account_id.clone(), 
+        shares: shares 
+      });
+    }
+  }
+
+  fn ensure_delegate_stake_token(subnet_id: u32) -> DispatchResult {
+    // --- Shares can't move into accounts the removal of the subnet already unbonded
+    ensure!(
+      SubnetsData::<T>::contains_key(subnet_id),
+      Error::<T>::SubnetNotExist
+    );
+
+    ensure!(
+      SubnetDelegateStakeToken::<T>::get(subnet_id),
+      Error::<T>::DelegateStakeTokenDisabled
+    );
+
+    Ok(())
+  }
+
+  /// Move shares between accounts, leaving `TotalSubnetDelegateStakeShares` as is
+  ///
+  /// The shares are in the `DelegateStakeCooldown` of `to`
+  fn move_delegate_stake_shares(
+    from: &T::AccountId,
+    to: &T::AccountId,
+    subnet_id: u32,
+    shares: u128,
+  ) {
+    Self::decrease_account_delegate_stake_shares_balance(from, subnet_id, shares);
+    Self::increase_account_delegate_stake_shares_balance(to, subnet_id, shares);
+    Self::set_delegate_stake_cooldown(to, subnet_id, shares);
+
+    Self::deposit_event(Event::DelegateStakeTokenTransferred { 
+      subnet_id: subnet_id, 
+      from: from.clone(), 
+      to: to.clone(), 
+      shares: shares 
+    });
+  }
+}
+
+impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
+  type AssetId = u32;
+  type Balance = u128;
+
+  fn total_issuance(subnet_id: u32) -> u128 {
+    TotalSubnetDelegateStakeShares::<T>::get(subnet_id)
+  }
+
+  fn minimum_balance(_subnet_id: u32) -> u128 {
+    0
+  }
+
+  fn total_balance(subnet_id: u32, who: &T::AccountId) -> u128 {
+    AccountSubnetDelegateStakeShares::<T>::get(who, subnet_id)
+  }
+
+  fn balance(subnet_id: u32, who: &T::AccountId) -> u128 {
+    AccountSubnetDelegateStakeShares::<T>::get(who, subnet_id)
+  }
+
+  fn reducible_balance(
+    subnet_id: u32,
+    who: &T::AccountId,
+    _preservation: Preservation,
+    _force: Fortitude,
+  ) -> u128 {
+    Self::transferable_delegate_stake_shares(who, subnet_id)
+  }
+
+  fn can_deposit(
+    subnet_id: u32,
+    who: &T::AccountId,
+    amount: u128,
+    _provenance: Provenance,
+  ) -> DepositConsequence {
+    if !Self::asset_exists(subnet_id) {
+      return DepositConsequence::UnknownAsset
+    }
+    if AccountSubnetDelegateStakeShares::<T>::get(who, subnet_id).checked_add(amount).is_none() {
+      return DepositConsequence::Overflow
+    }
+    DepositConsequence::Success
+  }
+
+  fn can_withdraw(
+    subnet_id: u32,
+    who: &T::AccountId,
+    amount: u128,
+  ) -> WithdrawConsequence<u128> {
+    if !Self::asset_exists(subnet_id) {
+      return WithdrawConsequence::UnknownAsset
+    }
+    if Self::transferable_delegate_stake_shares(who, subnet_id) < amount {
+      return WithdrawConsequence::BalanceLow
+    }
+    WithdrawConsequence::Success
+  }
+
+  fn asset_exists(subnet_id: u32) -> bool {
+    SubnetsData::<T>::contains_key(subnet_id) && SubnetDelegateStakeToken::<T>::get(subnet_id)
+  }
+}
@@ -45,6 +45,55 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
+  /// Move unbonding balance back into subnet delegate stake shares
+  ///
+  /// Takes from the subnets `DelegateStakeUnbondingLedger` first, and the remainder from the
+  /// accounts `StakeUnbondingLedger` where delegate stake removed from ``subnet_id`` is unbonded
+  pub fn do_rebond_delegate_stake(
+    origin: T::RuntimeOrigin,
+    subnet_id: u32,
+    delegate_stake_to_be_rebonded: u128,
+  ) -> DispatchResult {
+    let account_id: T::AccountId = ensure_signed(origin)?;
+
+    let mut delegate_ledger = DelegateStakeUnbondingLedger::<T>::get(&account_id, subnet_id);
+
+    let delegate_ledger_balance: u128 = delegate_ledger.values().fold(0, |acc, v| acc.saturating_add(*v));
+    let from_delegate_ledger = delegate_ledger_balance.min(delegate_stake_to_be_rebonded);
+    let from_ledger = delegate_stake_to_be_rebonded.saturating_sub(from_delegate_ledger);
+
+    ensure!(
+      delegate_stake_to_be_rebonded > 0 &&
+      Self::take_from_unbonding_ledger(&mut delegate_ledger, from_delegate_ledger) &&
+      Self::take_rebondable_from_unbonding_ledger(&account_id, subnet_id, UnbondingKind::DelegateStake, from_ledger),
+      Error::<T>::NotEnoughUnbondingBalance
+    );
+
+    let (result, _, _) = Self::perform_do_add_delegate_stake(
+      &account_id,
+      subnet_id,
+      delegate_stake_to_be_rebonded,
+      true
+    );
+
+    result?;
+
+    if delegate_ledger.is_empty() {
+      DelegateStakeUnbondingLedger::<T>::remove(&account_id, subnet_id);
+    } else {
+      DelegateStakeUnbondingLedger::<T>::insert(&account_id, subnet_id, delegate_ledger);
+    }
+
+    let block: u32 = Self::get_current_block_as_u32();
+
+    // Set last block for rate limiting
+    Self::set_last_tx_block(&account_id, block);
+
+    Self::deposit_event(Event::SubnetDelegateStakeRebonded(subnet_id, account_id, delegate_stake_to_be_rebonded));
+
+    Ok(())
+  }
+
   /// Add to the subnet delegate stake balance of a user
   ///
   /// # Arguments
@@ -72,16 +121,28 @@ impl<T: Config> Pallet<T> {
       return (Err(Error::<T>::CouldNotConvertToBalance.into()), 0, 0);
     }
 
+    if !SubnetsData::<T>::contains_key(subnet_id) {
+      return (Err(Error::<T>::SubnetNotExist.into()), 0, 0);
+    }
+
     // --- Get accounts current balance
-    // let account_delegate_stake_balance = Self::convert_to_balance(
-    //   account_delegate_stake_shares,
-    //   total_subnet_delegated_stake_shares,
-    //   total_subnet_delegated_stake_balance
-    // );
+    let account_delegate_stake_balance = Self::convert_to_balance(
+      AccountSubnetDelegateStakeShares::<T>::get(&account_id, subnet_id),
+      TotalSubnetDelegateStakeShares::<T>::get(subnet_id),
+      TotalSubnetDelegateStakeBalance::<T>::get(subnet_id)
+    );
 
-    // if account_delegate_stake_balance.saturating_add(delegate_stake_to_be_added) > MaxDelegateStakeBalance::<T>::get() {
-    //   return (Err(Error::<T>::MaxDelegatedStakeReached.into()), 0, 0);
-    // }
+    if account_delegate_stake_balance.saturating_add(delegate_stake_to_be_added) > MaxDelegateStakeBalance::<T>::get() {
+      return (Err(Error::<T>::MaxDelegatedStakeReached.into()), 0, 0);
+    }
+
+    // --- Ensure the subnets delegate stake cap isn't reached
+    if let Some(max_subnet_delegate_stake) = MaxSubnetDelegateStake::<T>::get(subnet_id) {
+      let total_subnet_delegate_stake_balance = TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
+      if total_subnet_delegate_stake_balance.saturating_add(delegate_stake_to_be_added) > max_subnet_delegate_stake {
+        return (Err(Error::<T>::MaxSubnetDelegateStakeReached.into()), 0, 0);
+      }
+    }
 
     // --- Ensure the callers account_id has enough delegate_stake to perform the transaction.
     if !switch {
@@ -133,6 +194,8 @@ impl<T: Config> Pallet<T> {
       delegate_stake_to_be_added_as_shares,
     );
 
+    Self::mint_delegate_stake_token(&account_id, subnet_id, delegate_stake_to_be_added_as_shares);
+
     (Ok(()), delegate_stake_to_be_added, delegate_stake_to_be_added_as_shares)
   }
 
@@ -184,13 +247,19 @@ impl<T: Config> Pallet<T> {
       return (Err(Error::<T>::NotEnoughStakeToWithdraw.into()), 0, 0);
     }
 
-    let account_delegate_stake_shares: u128 = AccountSubnetDelegateStakeShares::<T>::get(&account_id, subnet_id);
+    // --- Reserved shares can't be removed
+    let account_delegate_stake_shares: u128 = Self::free_delegate_stake_shares(&account_id, subnet_id);
 
     // --- Ensure that the account has enough delegate_stake to withdraw.      
     if account_delegate_stake_shares < delegate_stake_shares_to_be_removed {
       return (Err(Error::<T>::NotEnoughStakeToWithdraw.into()), 0, 0);
     }
 
+    // --- Shares received by transfer can't leave until the cooldown is over
+    if Self::transferable_delegate_stake_shares(&account_id, subnet_id) < delegate_stake_shares_to_be_removed {
+      return (Err(Error::<T>::InsufficientCooldown.into()), 0, 0);
+    }
+
     let total_subnet_delegated_stake_shares = TotalSubnetDelegateStakeShares::<T>::get(subnet_id);
     let total_subnet_delegated_stake_balance = TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
 
@@ -215,11 +284,15 @@ impl<T: Config> Pallet<T> {
 
     // --- We remove the shares from the account and balance from the pool
     Self::decrease_account_delegate_stake_shares(&account_id, subnet_id, delegate_stake_to_be_removed, delegate_stake_shares_to_be_removed);
+
+    Self::burn_delegate_stake_token(&account_id, subnet_id, delegate_stake_shares_to_be_removed);
     
     // --- We add the balancer to the account_id.  If the above fails we will not credit this account_id.
     if add_to_ledger {
-      let result = Self::add_balance_to_unbonding_ledger(
+      let result = Self::add_rebondable_balance_to_unbonding_ledger(
         &account_id, 
+        subnet_id,
+        UnbondingKind::DelegateStake,
         delegate_stake_to_be_removed, 
         T::DelegateStakeCooldownEpochs::get(),
         block
@@ -282,7 +355,7 @@ impl<T: Config> Pallet<T> {
     shares: u128,
   ) {
     // -- increase account subnet staking shares balance
-    AccountSubnetDelegateStakeShares::<T>::mutate(account_id, subnet_id, |mut n| n.saturating_accrue(shares));
+    Self::increase_account_delegate_stake_shares_balance(account_id, subnet_id, shares);
 
     // -- increase total subnet delegate stake balance
     TotalSubnetDelegateStakeBalance::<T>::mutate(subnet_id, |mut n| n.saturating_accrue(amount));
@@ -300,7 +373,7 @@ impl<T: Config> Pallet<T> {
     shares: u128,
   ) {
     // -- decrease account subnet staking shares balance
-    AccountSubnetDelegateStakeShares::<T>::mutate(account_id, subnet_id, |mut n| n.saturating_reduce(shares));
+    Self::decrease_account_delegate_stake_shares_balance(account_id, subnet_id, shares);
 
     // -- decrease total subnet delegate stake balance
     TotalSubnetDelegateStakeBalance::<T>::mutate(subnet_id, |mut n| n.saturating_reduce(amount));
@@ -311,6 +384,33 @@ impl<T: Config> Pallet<T> {
     TotalDelegateStake::<T>::mutate(|mut n| n.saturating_reduce(amount));
   }
 
+  /// Increase the `AccountSubnetDelegateStakeShares` of an account, leaving the subnet totals as is
+  pub fn increase_account_delegate_stake_shares_balance(
+    account_id: &T::AccountId,
+    subnet_id: u32,
+    shares: u128,
+  ) {
+    AccountSubnetDelegateStakeShares::<T>::mutate(account_id, subnet_id, |mut n| n.saturating_accrue(shares));
+    if shares > 0 {
+      SubnetDelegateStakeAccounts::<T>::insert(subnet_id, account_id, ());
+    }
+  }
+
+  /// Decrease the `AccountSubnetDelegateStakeShares` of an account, leaving the subnet totals as is
+  pub fn decrease_account_delegate_stake_shares_balance(
+    account_id: &T::AccountId,
+    subnet_id: u32,
+    shares: u128,
+  ) {
+    let account_shares = AccountSubnetDelegateStakeShares::<T>::mutate(account_id, subnet_id, |n| {
+      n.saturating_reduce(shares);
+      *n
+    });
+    if account_shares == 0 {
+      SubnetDelegateStakeAccounts::<T>::remove(subnet_id, account_id);
+    }
+  }
+
   /// Rewards are deposited here from the ``rewards.rs`` or by donations
   pub fn do_increase_delegate_stake(
     subnet_id: u32,
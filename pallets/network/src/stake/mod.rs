@@ -3,4 +3,6 @@ pub mod staking;
 pub mod delegate_staking;
 pub mod node_delegate_staking;
 pub mod staking_utils;
-pub mod transfer_utils;
\ No newline at end of file
+pub mod transfer_utils;
+pub mod subnet_unbonding;
+pub mod delegate_stake_token;
\ No newline at end of file
@@ -81,6 +81,11 @@ impl<T: Config> Pallet<T> {
       return (Err(Error::<T>::CouldNotConvertToBalance.into()), 0, 0);
     }
 
+    // --- Removed subnets keep their subnet nodes until they're cleaned up in `on_idle`
+    if !SubnetsData::<T>::contains_key(subnet_id) {
+      return (Err(Error::<T>::SubnetNotExist.into()), 0, 0);
+    }
+
     // let account_node_delegate_stake_shares = AccountNodeDelegateStakeShares::<T>::get((&account_id, subnet_id, subnet_node_id));
     let total_node_delegated_stake_shares = match TotalNodeDelegateStakeShares::<T>::get(subnet_id, subnet_node_id) {
       0 => {
@@ -217,6 +222,12 @@ impl<T: Config> Pallet<T> {
       return (Err(Error::<T>::NotEnoughStakeToWithdraw.into()), 0, 0);
     }
 
+    // --- Shares received by transfer can't leave until the cooldown is over
+    let cooldown_shares: u128 = Self::node_delegate_stake_cooldown_shares(&account_id, subnet_id, subnet_node_id);
+    if account_node_delegate_stake_shares.saturating_sub(cooldown_shares) < node_delegate_stake_shares_to_be_removed {
+      return (Err(Error::<T>::InsufficientCooldown.into()), 0, 0);
+    }
+
     let total_node_delegated_stake_shares = TotalNodeDelegateStakeShares::<T>::get(subnet_id, subnet_node_id);
     let total_node_delegated_stake_balance = TotalNodeDelegateStakeBalance::<T>::get(subnet_id, subnet_node_id);
 
@@ -332,7 +343,7 @@ impl<T: Config> Pallet<T> {
     shares: u128,
   ) {
     // -- increase account subnet staking shares balance
-    AccountNodeDelegateStakeShares::<T>::mutate((account_id, subnet_id, subnet_node_id), |mut n| n.saturating_accrue(shares));
+    Self::increase_account_node_delegate_stake_shares_balance(account_id, subnet_id, subnet_node_id, shares);
 
     // -- increase total subnet delegate stake balance
     TotalNodeDelegateStakeBalance::<T>::mutate(subnet_id, subnet_node_id, |mut n| n.saturating_accrue(amount));
@@ -351,7 +362,7 @@ impl<T: Config> Pallet<T> {
     shares: u128,
   ) {
     // -- decrease account subnet staking shares balance
-    AccountNodeDelegateStakeShares::<T>::mutate((account_id, subnet_id, subnet_node_id), |mut n| n.saturating_reduce(shares));
+    Self::decrease_account_node_delegate_stake_shares_balance(account_id, subnet_id, subnet_node_id, shares);
 
     // -- decrease total subnet delegate stake balance
     TotalNodeDelegateStakeBalance::<T>::mutate(subnet_id, subnet_node_id, |mut n| n.saturating_reduce(amount));
@@ -362,6 +373,35 @@ impl<T: Config> Pallet<T> {
     TotalNodeDelegateStake::<T>::mutate(|mut n| n.saturating_reduce(amount));
   }
 
+  /// Increase the `AccountNodeDelegateStakeShares` of an account, leaving the node totals as is
+  pub fn increase_account_node_delegate_stake_shares_balance(
+    account_id: &T::AccountId,
+    subnet_id: u32,
+    subnet_node_id: u32,
+    shares: u128,
+  ) {
+    AccountNodeDelegateStakeShares::<T>::mutate((account_id, subnet_id, subnet_node_id), |mut n| n.saturating_accrue(shares));
+    if shares > 0 {
+      SubnetNodeDelegateStakeAccounts::<T>::insert((subnet_id, account_id, subnet_node_id), ());
+    }
+  }
+
+  /// Decrease the `AccountNodeDelegateStakeShares` of an account, leaving the node totals as is
+  pub fn decrease_account_node_delegate_stake_shares_balance(
+    account_id: &T::AccountId,
+    subnet_id: u32,
+    subnet_node_id: u32,
+    shares: u128,
+  ) {
+    let account_shares = AccountNodeDelegateStakeShares::<T>::mutate((account_id, subnet_id, subnet_node_id), |n| {
+      n.saturating_reduce(shares);
+      *n
+    });
+    if account_shares == 0 {
+      SubnetNodeDelegateStakeAccounts::<T>::remove((subnet_id, account_id, subnet_node_id));
+    }
+  }
+
   /// Rewards are deposited here from the ``rewards.rs`` or by donations
   pub fn do_increase_node_delegate_stake(
     subnet_id: u32,
@@ -379,4 +419,16 @@ impl<T: Config> Pallet<T> {
 
     TotalNodeDelegateStake::<T>::mutate(|mut n| n.saturating_accrue(amount));
   }
-}
\ No newline at end of file
+
+  /// Slashes are taken from here in ``slash_validator``
+  pub fn do_decrease_node_delegate_stake(
+    subnet_id: u32,
+    subnet_node_id: u32,
+    amount: u128,
+  ) {
+    // -- decrease total subnet delegate stake without changing shares
+    TotalNodeDelegateStakeBalance::<T>::mutate(subnet_id, subnet_node_id, |mut n| n.saturating_reduce(amount));
+
+    TotalNodeDelegateStake::<T>::mutate(|mut n| n.saturating_reduce(amount));
+  }
+}
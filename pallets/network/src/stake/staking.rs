@@ -79,6 +79,60 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
+  /// Move unbonding balance of the coldkey back into the stake of one of its hotkeys
+  ///
+  /// Only balance unbonding from stake in ``subnet_id`` is rebonded, the latest unbondings first
+  pub fn do_rebond(
+    origin: T::RuntimeOrigin,
+    subnet_id: u32,
+    hotkey: T::AccountId,
+    stake_to_be_rebonded: u128,
+  ) -> DispatchResult {
+    let coldkey: T::AccountId = ensure_signed(origin)?;
+
+    ensure!(
+      stake_to_be_rebonded > 0,
+      Error::<T>::NotEnoughUnbondingBalance
+    );
+
+    let account_stake_balance: u128 = AccountSubnetStake::<T>::get(&hotkey, subnet_id);
+
+    ensure!(
+      account_stake_balance.saturating_add(stake_to_be_rebonded) >= MinStakeBalance::<T>::get(),
+      Error::<T>::MinStakeNotReached
+    );
+
+    ensure!(
+      account_stake_balance.saturating_add(stake_to_be_rebonded) <= MaxStakeBalance::<T>::get(),
+      Error::<T>::MaxStakeReached
+    );
+
+    let block: u32 = Self::get_current_block_as_u32();
+    ensure!(
+      !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(&coldkey), block),
+      Error::<T>::TxRateLimitExceeded
+    );
+
+    // --- Take the balance out of the unbonding ledger
+    ensure!(
+      Self::take_rebondable_from_unbonding_ledger(&coldkey, subnet_id, UnbondingKind::Stake, stake_to_be_rebonded),
+      Error::<T>::NotEnoughUnbondingBalance
+    );
+
+    Self::increase_account_stake(
+      &hotkey,
+      subnet_id,
+      stake_to_be_rebonded,
+    );
+
+    // Set last block for rate limiting
+    Self::set_last_tx_block(&coldkey, block);
+
+    Self::deposit_event(Event::StakeRebonded(subnet_id, coldkey, hotkey, stake_to_be_rebonded));
+
+    Ok(())
+  }
+
   pub fn do_remove_stake(
     origin: T::RuntimeOrigin, 
     subnet_id: u32,
@@ -129,16 +183,20 @@ impl<T: Config> Pallet<T> {
 
     // --- 9. We add the balancer to the coldkey.  If the above fails we will not credit this coldkey.
     if is_active {
-      Self::add_balance_to_unbonding_ledger(
+      Self::add_rebondable_balance_to_unbonding_ledger(
         &coldkey, 
+        subnet_id,
+        UnbondingKind::Stake,
         stake_to_be_removed, 
         T::StakeCooldownEpochs::get(),
         block
       ).map_err(|e| e)?;
     } else {
       // Unstaking cooldown for nodes that never activated
-      Self::add_balance_to_unbonding_ledger(
+      Self::add_rebondable_balance_to_unbonding_ledger(
         &coldkey, 
+        subnet_id,
+        UnbondingKind::Stake,
         stake_to_be_removed, 
         RegisteredStakeCooldownEpochs::<T>::get(),
         block
@@ -172,7 +230,11 @@ impl<T: Config> Pallet<T> {
     amount: u128,
   ) {
     // -- increase account subnet staking balance
-    AccountSubnetStake::<T>::mutate(hotkey, subnet_id, |mut n| n.saturating_accrue(amount));
+    let balance = AccountSubnetStake::<T>::mutate(hotkey, subnet_id, |n| {
+      n.saturating_accrue(amount);
+      *n
+    });
+    Self::index_subnet_stake_account(hotkey, subnet_id, balance);
 
     // -- increase total subnet stake
     TotalSubnetStake::<T>::mutate(subnet_id, |mut n| n.saturating_accrue(amount));
@@ -187,7 +249,11 @@ impl<T: Config> Pallet<T> {
     amount: u128,
   ) {
     // -- decrease account subnet staking balance
-    AccountSubnetStake::<T>::mutate(hotkey, subnet_id, |mut n| n.saturating_reduce(amount));
+    let balance = AccountSubnetStake::<T>::mutate(hotkey, subnet_id, |n| {
+      n.saturating_reduce(amount);
+      *n
+    });
+    Self::index_subnet_stake_account(hotkey, subnet_id, balance);
 
     // -- decrease total subnet stake
     TotalSubnetStake::<T>::mutate(subnet_id, |mut n| n.saturating_reduce(amount));
@@ -211,5 +277,24 @@ impl<T: Config> Pallet<T> {
       subnet_id, 
       old_hotkey_stake_balance.saturating_add(new_hotkey_stake_balance)
     );
+    Self::index_subnet_stake_account(old_hotkey, subnet_id, 0);
+    Self::index_subnet_stake_account(
+      new_hotkey,
+      subnet_id,
+      old_hotkey_stake_balance.saturating_add(new_hotkey_stake_balance)
+    );
+  }
+
+  /// Keep ``hotkey`` in `SubnetStakeAccounts` while its stake ``balance`` in ``subnet_id`` isn't zero
+  fn index_subnet_stake_account(
+    hotkey: &T::AccountId,
+    subnet_id: u32,
+    balance: u128,
+  ) {
+    if balance > 0 {
+      SubnetStakeAccounts::<T>::insert(subnet_id, hotkey, ());
+    } else {
+      SubnetStakeAccounts::<T>::remove(subnet_id, hotkey);
+    }
   }
 }
\ No newline at end of file
@@ -53,6 +53,61 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
+  /// `add_balance_to_unbonding_ledger` for balance removed from ``kind`` of stake in ``subnet_id``
+  ///
+  /// The origin is kept so the balance can only be rebonded into the same subnet and kind of stake
+  pub fn add_rebondable_balance_to_unbonding_ledger(
+    coldkey: &T::AccountId,
+    subnet_id: u32,
+    kind: UnbondingKind,
+    amount: u128,
+    cooldown_epoch_length: u32,
+    block: u32,
+  ) -> DispatchResult {
+    Self::add_balance_to_unbonding_ledger(coldkey, amount, cooldown_epoch_length, block)?;
+
+    let epoch = Self::get_current_epoch_as_u32();
+    let claim_epoch = cooldown_epoch_length.saturating_add(epoch);
+
+    StakeUnbondingOrigins::<T>::mutate(&coldkey, |origins| {
+      // --- Drop the origins of unbondings that may have been claimed
+      origins.retain(|_, unbondings| {
+        unbondings.retain(|unbonding_epoch, _| *unbonding_epoch >= epoch);
+        !unbondings.is_empty()
+      });
+      origins
+        .entry((subnet_id, kind))
+        .or_default()
+        .entry(claim_epoch)
+        .and_modify(|v| v.saturating_accrue(amount))
+        .or_insert(amount);
+    });
+
+    Ok(())
+  }
+
+  /// Infallible `add_balance_to_unbonding_ledger` used when unbonding balances of removed subnets
+  ///
+  /// If the ledger is full the balance is merged into its latest unbonding
+  pub fn force_add_balance_to_unbonding_ledger(
+    coldkey: &T::AccountId,
+    amount: u128,
+    cooldown_epoch_length: u32,
+    block: u32,
+  ) {
+    if Self::add_balance_to_unbonding_ledger(coldkey, amount, cooldown_epoch_length, block).is_ok() {
+      return
+    }
+
+    let epoch = Self::get_current_epoch_as_u32();
+    let claim_epoch = cooldown_epoch_length.saturating_add(epoch);
+
+    StakeUnbondingLedger::<T>::mutate(&coldkey, |ledger| {
+      let (latest_claim_epoch, balance) = ledger.pop_last().unwrap_or((claim_epoch, 0));
+      ledger.insert(latest_claim_epoch.max(claim_epoch), balance.saturating_add(amount));
+    });
+  }
+
   // Infallible
   pub fn do_claim_unbondings(coldkey: &T::AccountId) -> u32 {
     let epoch = Self::get_current_epoch_as_u32();
@@ -85,6 +140,94 @@ impl<T: Config> Pallet<T> {
     successful_unbondings
   }
 
+  /// Take `amount` out of an unbonding ledger, latest claim epochs first
+  ///
+  /// Returns false and leaves the ledger untouched if it holds less than `amount`
+  pub fn take_from_unbonding_ledger(
+    ledger: &mut BTreeMap<u32, u128>,
+    amount: u128,
+  ) -> bool {
+    let total: u128 = ledger.values().fold(0, |acc, v| acc.saturating_add(*v));
+    if total < amount {
+      return false
+    }
+
+    let mut remaining = amount;
+    // --- Rebond the entries furthest from being claimable first
+    let claim_epochs: Vec<u32> = ledger.keys().rev().copied().collect();
+    for claim_epoch in claim_epochs {
+      if remaining == 0 {
+        break
+      }
+      let balance = ledger.get(&claim_epoch).copied().unwrap_or(0);
+      if balance <= remaining {
+        ledger.remove(&claim_epoch);
+        remaining = remaining.saturating_sub(balance);
+      } else {
+        ledger.insert(claim_epoch, balance.saturating_sub(remaining));
+        remaining = 0;
+      }
+    }
+
+    true
+  }
+
+  /// Take `amount` out of the unbonding ledger of ``coldkey`` from the unbondings removed from ``kind`` of stake
+  /// in ``subnet_id``, latest claim epochs first
+  ///
+  /// Returns false and leaves the ledgers untouched if less than `amount` is rebondable
+  pub fn take_rebondable_from_unbonding_ledger(
+    coldkey: &T::AccountId,
+    subnet_id: u32,
+    kind: UnbondingKind,
+    amount: u128,
+  ) -> bool {
+    let epoch = Self::get_current_epoch_as_u32();
+    let mut ledger = StakeUnbondingLedger::<T>::get(&coldkey);
+    let mut origins = StakeUnbondingOrigins::<T>::get(&coldkey);
+
+    // --- Unbondings that may have been claimed, or were merged into a later claim epoch, aren't rebondable
+    let mut rebondable: BTreeMap<u32, u128> = origins
+      .remove(&(subnet_id, kind))
+      .unwrap_or_default()
+      .into_iter()
+      .filter(|(unbonding_epoch, _)| *unbonding_epoch >= epoch)
+      .map(|(unbonding_epoch, balance)| {
+        (unbonding_epoch, balance.min(ledger.get(&unbonding_epoch).copied().unwrap_or(0)))
+      })
+      .filter(|(_, balance)| *balance > 0)
+      .collect();
+
+    let before: BTreeMap<u32, u128> = rebondable.clone();
+    if !Self::take_from_unbonding_ledger(&mut rebondable, amount) {
+      return false
+    }
+
+    // --- Take the same balances out of the ledger
+    for (unbonding_epoch, balance) in before {
+      let taken = balance.saturating_sub(rebondable.get(&unbonding_epoch).copied().unwrap_or(0));
+      let remaining = ledger.get(&unbonding_epoch).copied().unwrap_or(0).saturating_sub(taken);
+      if remaining == 0 {
+        ledger.remove(&unbonding_epoch);
+      } else {
+        ledger.insert(unbonding_epoch, remaining);
+      }
+    }
+
+    if !rebondable.is_empty() {
+      origins.insert((subnet_id, kind), rebondable);
+    }
+
+    StakeUnbondingLedger::<T>::insert(&coldkey, ledger);
+    if origins.is_empty() {
+      StakeUnbondingOrigins::<T>::remove(&coldkey);
+    } else {
+      StakeUnbondingOrigins::<T>::insert(&coldkey, origins);
+    }
+
+    true
+  }
+
   pub fn can_remove_balance_from_coldkey_account(
     coldkey: &T::AccountId,
     amount: <<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance,
@@ -0,0 +1,225 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Unbonds the stake and delegate stake balances left in a subnet when it's removed
+
+//
+// The positions are iterated through the subnet first `SubnetStakeAccounts`, `SubnetDelegateStakeAccounts`
+// and `SubnetNodeDelegateStakeAccounts` indexes, so each step only visits up to `max_items` positions
+// of the subnet and continues from the last raw key in the following blocks
+
+use super::*;
+use sp_runtime::Saturating;
+use frame_support::storage::PrefixIterator;
+
+impl<T: Config> Pallet<T> {
+  /// Move the subnet node stake of a removed subnet into the coldkeys unbonding ledgers
+  ///
+  /// Returns if the step completed, the raw key to continue from, the entries visited,
+  /// and the unbonded balances
+  ///
+  /// # Arguments
+  ///
+  /// * `subnet_id` - Subnet ID being removed.
+  /// * `last_key` - Raw key to continue from.
+  /// * `max_items` - Max entries to visit.
+  ///
+  pub fn unbond_subnet_stake(
+    subnet_id: u32,
+    last_key: Option<Vec<u8>>,
+    max_items: u32,
+  ) -> (bool, Vec<u8>, u32, Vec<(T::AccountId, u128)>) {
+    let mut iter = match last_key {
+      Some(key) => SubnetStakeAccounts::<T>::iter_prefix_from(subnet_id, key),
+      None => SubnetStakeAccounts::<T>::iter_prefix(subnet_id),
+    };
+
+    let mut stakes: Vec<(T::AccountId, u128)> = Vec::new();
+    let (completed, last_key, items) = Self::step_storage_iter(&mut iter, max_items, |(hotkey, _)| {
+      let balance = AccountSubnetStake::<T>::get(&hotkey, subnet_id);
+      if balance > 0 {
+        stakes.push((hotkey, balance));
+      }
+    });
+
+    let block: u32 = Self::get_current_block_as_u32();
+    let mut unbonded: Vec<(T::AccountId, u128)> = Vec::new();
+    for (hotkey, balance) in stakes {
+      Self::decrease_account_stake(&hotkey, subnet_id, balance);
+      AccountSubnetStake::<T>::remove(&hotkey, subnet_id);
+
+      let coldkey = HotkeyOwner::<T>::get(&hotkey);
+      Self::force_add_balance_to_unbonding_ledger(
+        &coldkey,
+        balance,
+        T::StakeCooldownEpochs::get(),
+        block
+      );
+      unbonded.push((coldkey, balance));
+    }
+
+    if completed {
+      TotalSubnetStake::<T>::remove(subnet_id);
+    }
+
+    (completed, last_key, items, unbonded)
+  }
+
+  /// Move the subnet delegate stake of a removed subnet into the accounts unbonding ledgers
+  ///
+  /// See `unbond_subnet_stake`
+  pub fn unbond_subnet_delegate_stake(
+    subnet_id: u32,
+    last_key: Option<Vec<u8>>,
+    max_items: u32,
+  ) -> (bool, Vec<u8>, u32, Vec<(T::AccountId, u128)>) {
+    let mut iter = match last_key {
+      Some(key) => SubnetDelegateStakeAccounts::<T>::iter_prefix_from(subnet_id, key),
+      None => SubnetDelegateStakeAccounts::<T>::iter_prefix(subnet_id),
+    };
+
+    let mut delegate_shares: Vec<(T::AccountId, u128)> = Vec::new();
+    let (completed, last_key, items) = Self::step_storage_iter(&mut iter, max_items, |(account_id, _)| {
+      let shares = AccountSubnetDelegateStakeShares::<T>::get(&account_id, subnet_id);
+      if shares > 0 {
+        delegate_shares.push((account_id, shares));
+      }
+    });
+
+    let block: u32 = Self::get_current_block_as_u32();
+    let mut unbonded: Vec<(T::AccountId, u128)> = Vec::new();
+    for (account_id, shares) in delegate_shares {
+      let balance = Self::convert_to_balance(
+        shares,
+        TotalSubnetDelegateStakeShares::<T>::get(subnet_id),
+        TotalSubnetDelegateStakeBalance::<T>::get(subnet_id)
+      );
+
+      Self::decrease_account_delegate_stake_shares(&account_id, subnet_id, balance, shares);
+      AccountSubnetDelegateStakeShares::<T>::remove(&account_id, subnet_id);
+      ReservedSubnetDelegateStakeShares::<T>::remove(&account_id, subnet_id);
+      DelegateStakeCooldown::<T>::remove(&account_id, subnet_id);
+
+      if balance > 0 {
+        Self::force_add_balance_to_unbonding_ledger(
+          &account_id,
+          balance,
+          T::DelegateStakeCooldownEpochs::get(),
+          block
+        );
+        unbonded.push((account_id, balance));
+      }
+    }
+
+    // --- Remove what's left in the pool from the dead shares and rounding
+    if completed {
+      let delegate_stake_balance = TotalSubnetDelegateStakeBalance::<T>::take(subnet_id);
+      TotalDelegateStake::<T>::mutate(|mut n| n.saturating_reduce(delegate_stake_balance));
+      TotalSubnetDelegateStakeShares::<T>::remove(subnet_id);
+    }
+
+    (completed, last_key, items, unbonded)
+  }
+
+  /// Move the node delegate stake of a removed subnet into the accounts unbonding ledgers
+  ///
+  /// See `unbond_subnet_stake`
+  pub fn unbond_subnet_node_delegate_stake(
+    subnet_id: u32,
+    last_key: Option<Vec<u8>>,
+    max_items: u32,
+  ) -> (bool, Vec<u8>, u32, Vec<(T::AccountId, u32, u128)>) {
+    let mut iter = match last_key {
+      Some(key) => SubnetNodeDelegateStakeAccounts::<T>::iter_prefix_from((subnet_id,), key),
+      None => SubnetNodeDelegateStakeAccounts::<T>::iter_prefix((subnet_id,)),
+    };
+
+    let mut node_delegate_shares: Vec<(T::AccountId, u32, u128)> = Vec::new();
+    let (completed, last_key, items) = Self::step_storage_iter(&mut iter, max_items, |((account_id, subnet_node_id), _)| {
+      let shares = AccountNodeDelegateStakeShares::<T>::get((&account_id, subnet_id, subnet_node_id));
+      if shares > 0 {
+        node_delegate_shares.push((account_id, subnet_node_id, shares));
+      }
+    });
+
+    let block: u32 = Self::get_current_block_as_u32();
+    let mut unbonded: Vec<(T::AccountId, u32, u128)> = Vec::new();
+    for (account_id, subnet_node_id, shares) in node_delegate_shares {
+      let balance = Self::convert_to_balance(
+        shares,
+        TotalNodeDelegateStakeShares::<T>::get(subnet_id, subnet_node_id),
+        TotalNodeDelegateStakeBalance::<T>::get(subnet_id, subnet_node_id)
+      );
+
+      Self::decrease_account_node_delegate_stake_shares(&account_id, subnet_id, subnet_node_id, balance, shares);
+      AccountNodeDelegateStakeShares::<T>::remove((&account_id, subnet_id, subnet_node_id));
+      NodeDelegateStakeCooldown::<T>::remove((&account_id, subnet_id, subnet_node_id));
+
+      if balance > 0 {
+        Self::force_add_balance_to_unbonding_ledger(
+          &account_id,
+          balance,
+          T::NodeDelegateStakeCooldownEpochs::get(),
+          block
+        );
+        unbonded.push((account_id, subnet_node_id, balance));
+      }
+    }
+
+    (completed, last_key, items, unbonded)
+  }
+
+  /// Remove what's left in the node delegate stake pools of a removed subnet from the dead shares and rounding
+  ///
+  /// Returns if the step completed and the pools removed
+  pub fn remove_subnet_node_delegate_pools(
+    subnet_id: u32,
+    max_items: u32,
+  ) -> (bool, u32) {
+    let mut items: u32 = 0;
+    for (subnet_node_id, node_delegate_stake_balance) in TotalNodeDelegateStakeBalance::<T>::drain_prefix(subnet_id) {
+      TotalNodeDelegateStake::<T>::mutate(|mut n| n.saturating_reduce(node_delegate_stake_balance));
+      TotalNodeDelegateStakeShares::<T>::remove(subnet_id, subnet_node_id);
+      items.saturating_inc();
+      if items >= max_items {
+        return (TotalNodeDelegateStakeBalance::<T>::iter_prefix(subnet_id).next().is_none(), items)
+      }
+    }
+
+    (true, items)
+  }
+
+  /// Visit up to `max_items` entries of a storage iterator
+  ///
+  /// Returns if the iterator completed, the raw key to continue from, and the entries visited
+  fn step_storage_iter<Item>(
+    iter: &mut PrefixIterator<Item>,
+    max_items: u32,
+    mut f: impl FnMut(Item),
+  ) -> (bool, Vec<u8>, u32) {
+    let mut items: u32 = 0;
+    while items < max_items {
+      match iter.next() {
+        Some(item) => {
+          items.saturating_inc();
+          f(item);
+        },
+        None => return (true, Vec::new(), items),
+      }
+    }
+
+    (false, iter.last_raw_key().to_vec(), items)
+  }
+}
@@ -116,4 +116,321 @@ impl<T: Config> Pallet<T> {
 
     Ok(())
   }
-}
\ No newline at end of file
+
+  /// Transfer stake between two (hotkey, subnet) pairs of the same coldkey
+  ///
+  /// # Arguments
+  ///
+  /// * `from_subnet_id` - Subnet ID unstaking from.
+  /// * `from_hotkey` - Hotkey unstaking from.
+  /// * `to_subnet_id` - Subnet ID staking to.
+  /// * `to_hotkey` - Hotkey staking to.
+  /// * `stake_to_be_transferred` - Balance to transfer.
+  ///
+  pub fn do_transfer_stake(
+    origin: T::RuntimeOrigin,
+    from_subnet_id: u32,
+    from_hotkey: T::AccountId,
+    to_subnet_id: u32,
+    to_hotkey: T::AccountId,
+    stake_to_be_transferred: u128,
+  ) -> DispatchResult {
+    let coldkey: T::AccountId = ensure_signed(origin)?;
+
+    ensure!(
+      from_subnet_id != to_subnet_id || from_hotkey != to_hotkey,
+      Error::<T>::InvalidStakeTransfer
+    );
+
+    ensure!(
+      stake_to_be_transferred > 0,
+      Error::<T>::NotEnoughStakeToWithdraw
+    );
+
+    let from_stake_balance: u128 = AccountSubnetStake::<T>::get(&from_hotkey, from_subnet_id);
+
+    ensure!(
+      from_stake_balance >= stake_to_be_transferred,
+      Error::<T>::NotEnoughStakeToWithdraw
+    );
+
+    // --- If the source is still a subnet node it must keep the required minimum balance
+    if HotkeySubnetNodeId::<T>::contains_key(from_subnet_id, &from_hotkey) {
+      ensure!(
+        from_stake_balance.saturating_sub(stake_to_be_transferred) >= MinStakeBalance::<T>::get(),
+        Error::<T>::MinStakeNotReached
+      );
+    }
+
+    let to_stake_balance: u128 = AccountSubnetStake::<T>::get(&to_hotkey, to_subnet_id);
+
+    ensure!(
+      to_stake_balance.saturating_add(stake_to_be_transferred) >= MinStakeBalance::<T>::get(),
+      Error::<T>::MinStakeNotReached
+    );
+
+    ensure!(
+      to_stake_balance.saturating_add(stake_to_be_transferred) <= MaxStakeBalance::<T>::get(),
+      Error::<T>::MaxStakeReached
+    );
+
+    // --- Only allow one transfer per `StakeTransferPeriod`
+    let block: u32 = Self::get_current_block_as_u32();
+    let last_transfer_block: u32 = LastStakeTransfer::<T>::get(&coldkey);
+    ensure!(
+      last_transfer_block == 0 ||
+      block.saturating_sub(last_transfer_block) >= StakeTransferPeriod::<T>::get(),
+      Error::<T>::StakeTransferPeriodExceeded
+    );
+
+    Self::decrease_account_stake(&from_hotkey, from_subnet_id, stake_to_be_transferred);
+    Self::increase_account_stake(&to_hotkey, to_subnet_id, stake_to_be_transferred);
+
+    LastStakeTransfer::<T>::insert(&coldkey, block);
+
+    Self::deposit_event(Event::StakeTransferred { 
+      coldkey: coldkey, 
+      from_subnet_id: from_subnet_id, 
+      from_hotkey: from_hotkey, 
+      to_subnet_id: to_subnet_id, 
+      to_hotkey: to_hotkey, 
+      amount: stake_to_be_transferred 
+    });
+
+    Ok(())
+  }
+
+  /// Transfer subnet delegate stake shares to another account without unstaking
+  ///
+  /// The receiver can't remove or transfer the received shares until `DelegateStakeCooldown`
+  ///
+  /// # Arguments
+  ///
+  /// * `from` - Account sending the shares.
+  /// * `to` - Account receiving the shares.
+  /// * `subnet_id` - Subnet ID of the shares.
+  /// * `shares` - Shares to transfer.
+  ///
+  pub fn do_transfer_delegate_stake_shares(
+    from: &T::AccountId,
+    to: &T::AccountId,
+    subnet_id: u32,
+    shares: u128,
+  ) -> DispatchResult {
+    ensure!(
+      SubnetsData::<T>::contains_key(subnet_id),
+      Error::<T>::SubnetNotExist
+    );
+
+    ensure!(
+      from != to,
+      Error::<T>::InvalidDelegateStakeSharesTransfer
+    );
+
+    // --- Reserved shares can't be transferred
+    ensure!(
+      shares > 0 && Self::free_delegate_stake_shares(from, subnet_id) >= shares,
+      Error::<T>::NotEnoughStakeToWithdraw
+    );
+
+    ensure!(
+      Self::transferable_delegate_stake_shares(from, subnet_id) >= shares,
+      Error::<T>::InsufficientCooldown
+    );
+
+    let block: u32 = Self::get_current_block_as_u32();
+    ensure!(
+      !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(from), block),
+      Error::<T>::TxRateLimitExceeded
+    );
+
+    Self::ensure_delegate_stake_shares_transfer(from, to, subnet_id, shares)?;
+
+    Self::decrease_account_delegate_stake_shares_balance(from, subnet_id, shares);
+    Self::increase_account_delegate_stake_shares_balance(to, subnet_id, shares);
+
+    Self::set_delegate_stake_cooldown(to, subnet_id, shares);
+    Self::set_last_tx_block(from, block);
+
+    Self::deposit_event(Event::SubnetDelegateStakeSharesTransferred { 
+      subnet_id: subnet_id, 
+      from: from.clone(), 
+      to: to.clone(), 
+      shares: shares 
+    });
+
+    Ok(())
+  }
+
+  /// Transfer node delegate stake shares to another account without unstaking
+  ///
+  /// See `do_transfer_delegate_stake_shares`
+  pub fn do_transfer_node_delegate_stake_shares(
+    from: &T::AccountId,
+    to: &T::AccountId,
+    subnet_id: u32,
+    subnet_node_id: u32,
+    shares: u128,
+  ) -> DispatchResult {
+    ensure!(
+      SubnetsData::<T>::contains_key(subnet_id),
+      Error::<T>::SubnetNotExist
+    );
+
+    ensure!(
+      from != to,
+      Error::<T>::InvalidDelegateStakeSharesTransfer
+    );
+
+    let from_shares: u128 = AccountNodeDelegateStakeShares::<T>::get((from, subnet_id, subnet_node_id));
+    ensure!(
+      shares > 0 && from_shares >= shares,
+      Error::<T>::NotEnoughStakeToWithdraw
+    );
+
+    ensure!(
+      from_shares.saturating_sub(Self::node_delegate_stake_cooldown_shares(from, subnet_id, subnet_node_id)) >= shares,
+      Error::<T>::InsufficientCooldown
+    );
+
+    let block: u32 = Self::get_current_block_as_u32();
+    ensure!(
+      !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(from), block),
+      Error::<T>::TxRateLimitExceeded
+    );
+
+    let total_shares: u128 = TotalNodeDelegateStakeShares::<T>::get(subnet_id, subnet_node_id);
+    let total_balance: u128 = TotalNodeDelegateStakeBalance::<T>::get(subnet_id, subnet_node_id);
+    let to_shares: u128 = AccountNodeDelegateStakeShares::<T>::get((to, subnet_id, subnet_node_id));
+
+    Self::ensure_delegate_stake_shares_transfer_balances(
+      from_shares.saturating_sub(shares),
+      to_shares.saturating_add(shares),
+      total_shares,
+      total_balance,
+    )?;
+
+    Self::decrease_account_node_delegate_stake_shares_balance(from, subnet_id, subnet_node_id, shares);
+    Self::increase_account_node_delegate_stake_shares_balance(to, subnet_id, subnet_node_id, shares);
+
+    Self::set_node_delegate_stake_cooldown(to, subnet_id, subnet_node_id, shares);
+    Self::set_last_tx_block(from, block);
+
+    Self::deposit_event(Event::NodeDelegateStakeSharesTransferred { 
+      subnet_id: subnet_id, 
+      subnet_node_id: subnet_node_id, 
+      from: from.clone(), 
+      to: to.clone(), 
+      shares: shares 
+    });
+
+    Ok(())
+  }
+
+  /// Checks on the balances of both accounts of a subnet delegate stake shares transfer
+  ///
+  /// * Both accounts must keep `MinDelegateStakeBalance`, or the sender nothing
+  /// * The receiver must stay under `MaxDelegateStakeBalance`
+  ///
+  /// Shared by the shares transfer and the delegate stake token
+  pub fn ensure_delegate_stake_shares_transfer(
+    from: &T::AccountId,
+    to: &T::AccountId,
+    subnet_id: u32,
+    shares: u128,
+  ) -> DispatchResult {
+    let from_shares: u128 = AccountSubnetDelegateStakeShares::<T>::get(from, subnet_id);
+    let to_shares: u128 = AccountSubnetDelegateStakeShares::<T>::get(to, subnet_id);
+    let total_shares: u128 = TotalSubnetDelegateStakeShares::<T>::get(subnet_id);
+    let total_balance: u128 = TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
+
+    Self::ensure_delegate_stake_shares_transfer_balances(
+      from_shares.saturating_sub(shares),
+      to_shares.saturating_add(shares),
+      total_shares,
+      total_balance,
+    )?;
+
+    // --- The receiver can't go over `MaxDelegateStakeBalance`, as when adding delegate stake
+    ensure!(
+      Self::convert_to_balance(to_shares.saturating_add(shares), total_shares, total_balance) <= MaxDelegateStakeBalance::<T>::get(),
+      Error::<T>::MaxDelegatedStakeReached
+    );
+
+    Ok(())
+  }
+
+  /// Both sides of a shares transfer must keep `MinDelegateStakeBalance`, or the sender nothing
+  fn ensure_delegate_stake_shares_transfer_balances(
+    from_shares: u128,
+    to_shares: u128,
+    total_shares: u128,
+    total_balance: u128,
+  ) -> DispatchResult {
+    let min_delegate_stake_balance: u128 = MinDelegateStakeBalance::<T>::get();
+
+    ensure!(
+      from_shares == 0 ||
+      Self::convert_to_balance(from_shares, total_shares, total_balance) >= min_delegate_stake_balance,
+      Error::<T>::MinDelegateStake
+    );
+
+    ensure!(
+      Self::convert_to_balance(to_shares, total_shares, total_balance) >= min_delegate_stake_balance,
+      Error::<T>::MinDelegateStake
+    );
+
+    Ok(())
+  }
+
+  /// Put subnet delegate stake shares received by an account in its `DelegateStakeCooldown`
+  ///
+  /// Only the received shares are locked, the rest of the position can still leave
+  pub fn set_delegate_stake_cooldown(account_id: &T::AccountId, subnet_id: u32, shares: u128) {
+    DelegateStakeCooldown::<T>::mutate(account_id, subnet_id, |ledger| Self::add_cooldown_shares(ledger, shares));
+  }
+
+  /// Put node delegate stake shares received by an account in its `NodeDelegateStakeCooldown`
+  pub fn set_node_delegate_stake_cooldown(
+    account_id: &T::AccountId,
+    subnet_id: u32,
+    subnet_node_id: u32,
+    shares: u128,
+  ) {
+    NodeDelegateStakeCooldown::<T>::mutate((account_id, subnet_id, subnet_node_id), |ledger| Self::add_cooldown_shares(ledger, shares));
+  }
+
+  /// Subnet delegate stake shares of an account still in their cooldown
+  pub fn delegate_stake_cooldown_shares(account_id: &T::AccountId, subnet_id: u32) -> u128 {
+    Self::cooldown_shares(&DelegateStakeCooldown::<T>::get(account_id, subnet_id))
+  }
+
+  /// Node delegate stake shares of an account still in their cooldown
+  pub fn node_delegate_stake_cooldown_shares(account_id: &T::AccountId, subnet_id: u32, subnet_node_id: u32) -> u128 {
+    Self::cooldown_shares(&NodeDelegateStakeCooldown::<T>::get((account_id, subnet_id, subnet_node_id)))
+  }
+
+  /// Subnet delegate stake shares of an account that can be removed or transferred
+  pub fn transferable_delegate_stake_shares(account_id: &T::AccountId, subnet_id: u32) -> u128 {
+    Self::free_delegate_stake_shares(account_id, subnet_id)
+      .saturating_sub(Self::delegate_stake_cooldown_shares(account_id, subnet_id))
+  }
+
+  fn add_cooldown_shares(ledger: &mut BTreeMap<u32, u128>, shares: u128) {
+    let epoch: u32 = Self::get_current_epoch_as_u32();
+
+    // --- Drop the shares whose cooldown is over
+    ledger.retain(|&cooldown_epoch, _| cooldown_epoch > epoch);
+
+    ledger.entry(epoch.saturating_add(T::DelegateStakeCooldownEpochs::get()))
+      .and_modify(|n| n.saturating_accrue(shares))
+      .or_insert(shares);
+  }
+
+  fn cooldown_shares(ledger: &BTreeMap<u32, u128>) -> u128 {
+    let epoch: u32 = Self::get_current_epoch_as_u32();
+    ledger.iter()
+      .filter(|(&cooldown_epoch, _)| cooldown_epoch > epoch)
+      .fold(0, |acc, (_, &shares)| acc.saturating_add(shares))
+  }
+}
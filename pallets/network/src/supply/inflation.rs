@@ -16,30 +16,35 @@
 // Enables accounts to delegate stake to subnets for a portion of emissions
 
 use super::*;
-use libm::{exp, pow};
+use sp_runtime::{FixedPointNumber, FixedU128, traits::{One, Zero}};
 
 pub struct Inflation {
   /// Initial inflation percentage, from time=0
-  pub initial: f64,
+  pub initial: FixedU128,
 
   /// Terminal inflation percentage, to time=INF
-  pub terminal: f64,
+  pub terminal: FixedU128,
 
   /// Rate per year, at which inflation is lowered until reaching terminal
   ///  i.e. inflation(year) == MAX(terminal, initial*((1-taper)^year))
-  pub taper: f64,
+  pub taper: FixedU128,
 
   /// Percentage of total inflation allocated to the foundation
-  pub foundation: f64,
+  pub foundation: FixedU128,
   /// Duration of foundation pool inflation, in years
-  pub foundation_term: f64,
+  pub foundation_term: FixedU128,
 }
 
-const DEFAULT_INITIAL: f64 = 0.1;
-const DEFAULT_TERMINAL: f64 = 0.015;
-const DEFAULT_TAPER: f64 = 0.15;
-const DEFAULT_FOUNDATION: f64 = 0.05;
-const DEFAULT_FOUNDATION_TERM: f64 = 7.0;
+// 0.1
+const DEFAULT_INITIAL: FixedU128 = FixedU128::from_inner(FixedU128::DIV / 10);
+// 0.015
+const DEFAULT_TERMINAL: FixedU128 = FixedU128::from_inner(FixedU128::DIV * 15 / 1000);
+// 0.15
+const DEFAULT_TAPER: FixedU128 = FixedU128::from_inner(FixedU128::DIV * 15 / 100);
+// 0.05
+const DEFAULT_FOUNDATION: FixedU128 = FixedU128::from_inner(FixedU128::DIV * 5 / 100);
+// 7.0
+const DEFAULT_FOUNDATION_TERM: FixedU128 = FixedU128::from_inner(FixedU128::DIV * 7);
 
 impl Default for Inflation {
   fn default() -> Self {
@@ -54,36 +59,29 @@ impl Default for Inflation {
 }
 
 impl Inflation {
-  pub fn epoch(&self, epoch: u32, epochs_per_year: u32, denominator: u128) -> f64 {
-    let years_elapsed = epoch as f64 / epochs_per_year as f64;
-    // let rate = self.initial * pow(1.0 - self.terminal, years_elapsed);
-
-    self.total(years_elapsed)
-
-    // Ensure inflation does not go below the minimum taper rate
-    // let final_rate = rate.max(self.taper);
-
-    // final_rate
-    // final_rate as u128 * denominator
+  pub fn epoch<T: Config>(&self, epoch: u32, epochs_per_year: u32) -> FixedU128 {
+    self.total::<T>(self.year_from_epoch(epoch, epochs_per_year))
   }
 
   /// portion of total that goes to validators
-  pub fn validator(&self, year: f64) -> f64 {
-    self.total(year) - self.foundation(year)
+  pub fn validator<T: Config>(&self, year: FixedU128) -> FixedU128 {
+    self.total::<T>(year).saturating_sub(self.foundation::<T>(year))
   }
 
   /// portion of total that goes to foundation
-  pub fn foundation(&self, year: f64) -> f64 {
+  pub fn foundation<T: Config>(&self, year: FixedU128) -> FixedU128 {
     if year < self.foundation_term {
-      self.total(year) * self.foundation
+      self.total::<T>(year).saturating_mul(self.foundation)
     } else {
-      0.0
+      FixedU128::zero()
     }
   }
 
   /// inflation rate at year
-  pub fn total(&self, year: f64) -> f64 {
-    let tapered = self.initial * pow(1.0 - self.taper, year);
+  pub fn total<T: Config>(&self, year: FixedU128) -> FixedU128 {
+    let tapered = self.initial.saturating_mul(
+      Pallet::<T>::fixed_pow(FixedU128::one().saturating_sub(self.taper), year)
+    );
 
     if tapered > self.terminal {
       tapered
@@ -92,8 +90,8 @@ impl Inflation {
     }
   }
 
-  pub fn year_from_epoch(&self, epoch: u32, epochs_per_year: u32) -> f64 {
-    epoch as f64 / epochs_per_year as f64
+  pub fn year_from_epoch(&self, epoch: u32, epochs_per_year: u32) -> FixedU128 {
+    FixedU128::checked_from_rational(epoch, epochs_per_year).unwrap_or_default()
   }
 }
 
@@ -127,71 +125,12 @@ impl<T: Config> Pallet<T> {
   pub fn get_epoch_emissions(
     epoch: u32, 
   ) -> u128 {
-    let max_subnets: u32 = MaxSubnets::<T>::get();
-    let mut total_activate_subnets: u32 = TotalActiveSubnets::<T>::get();
-    // There can be n+1 subnets at this time before 1 is removed in the epoch steps
-    if total_activate_subnets > max_subnets {
-      total_activate_subnets = max_subnets;
-    }
+    // let total_issuance: u128 = Self::get_total_network_issuance();
+    let total_issuance: u128 = 19536777003861893185536;
 
-    // ==========================
-    // --- Get subnet utilization
-    // ==========================
-    let subnet_utilization_rate: f64 = total_activate_subnets as f64 / max_subnets as f64;
-    let adj_subnet_utilization_rate: f64 = Self::pow(
-      subnet_utilization_rate, 
-      Self::get_percent_as_f64(SubnetInflationAdjFactor::<T>::get())
-    ).min(1.0);
+    log::debug!("inflation total_issuance: {:?}", total_issuance);
 
-    // Max subnet nodes per subnet
-    let max_nodes: u32 = max_subnets.saturating_mul(MaxSubnetNodes::<T>::get());
-    let total_active_nodes: u32 = TotalActiveNodes::<T>::get();
-
-    // ==========================
-    // --- Get subnet node utilization
-    // ==========================
-    let node_utilization_rate: f64 = total_active_nodes as f64 / max_nodes as f64;
-    let adj_node_utilization_rate: f64 = Self::pow(
-      node_utilization_rate, 
-      Self::get_percent_as_f64(SubnetNodeInflationAdjFactor::<T>::get())
-    ).min(1.0);
-
-    // ==========================
-    // --- Get final utilization factors
-    // ==========================
-    // Subnet inflation factor
-    let sif: f64 = Self::get_percent_as_f64(SubnetInflationFactor::<T>::get());
-    // Subnet node inflation factor
-    let snif: f64 = 1.0 - sif;
-
-    let adj_subnet_utilization_rate: f64 = subnet_utilization_rate * sif;
-    let adj_node_utilization_rate: f64 = node_utilization_rate * snif;
-
-    // --- Get percentage of inflation to use in current epoch
-    // This is the network activity factor
-    let inflation_factor: f64 = Self::get_inflation_factor(snif + adj_node_utilization_rate);
-
-    // ==========================
-    // --- Get current epochs total inflation
-    //
-    // * Adjusts the inflation based on network activity using `let inflation_factor`
-    // ==========================
-    // let total_issuance: f64 = Self::get_total_network_issuance() as f64;
-    let total_issuance: f64 = 19536777003861893185536.0;
-    let epochs_per_year: f64 = T::EpochsPerYear::get() as f64;
-
-    log::error!("inflation total_issuance: {:?}", total_issuance as u128);
-
-    let inflation = Inflation::default();
-
-    let year: f64 = epoch as f64 / epochs_per_year;
-    log::error!("inflation year: {:?}", year);
-
-    // --- Get current yearly inflation
-    let apr: f64 = inflation.total(year);
-    log::error!("inflation apr: {:?}", apr);
-
-    (total_issuance * apr * inflation_factor) as u128
+    Self::get_epoch_inflation(epoch).saturating_mul_int(total_issuance)
   }
 
   pub fn get_epoch_emissions_adj(
@@ -199,6 +138,19 @@ impl<T: Config> Pallet<T> {
     increase_issuance: u128,
     decrease_issuance: u128,
   ) -> u128 {
+    let total_issuance: u128 = Self::get_total_network_issuance()
+      .saturating_add(increase_issuance)
+      .saturating_sub(decrease_issuance);
+
+    log::debug!("inflation total_issuance: {:?}", total_issuance);
+
+    Self::get_epoch_inflation(epoch).saturating_mul_int(total_issuance)
+  }
+
+  /// Get the percentage of the total issuance emitted in an epoch
+  ///
+  /// This is the yearly inflation of the epoch adjusted by the network activity
+  pub fn get_epoch_inflation(epoch: u32) -> FixedU128 {
     let max_subnets: u32 = MaxSubnets::<T>::get();
     let mut total_activate_subnets: u32 = TotalActiveSubnets::<T>::get();
     // There can be n+1 subnets at this time before 1 is removed in the epoch steps
@@ -209,11 +161,10 @@ impl<T: Config> Pallet<T> {
     // ==========================
     // --- Get subnet utilization
     // ==========================
-    let subnet_utilization_rate: f64 = total_activate_subnets as f64 / max_subnets as f64;
-    let adj_subnet_utilization_rate: f64 = Self::pow(
-      subnet_utilization_rate, 
-      Self::get_percent_as_f64(SubnetInflationAdjFactor::<T>::get())
-    ).min(1.0);
+    let subnet_utilization_rate: FixedU128 = FixedU128::checked_from_rational(
+      total_activate_subnets, 
+      max_subnets
+    ).unwrap_or_default();
 
     // Max subnet nodes per subnet
     let max_nodes: u32 = max_subnets.saturating_mul(MaxSubnetNodes::<T>::get());
@@ -222,59 +173,53 @@ impl<T: Config> Pallet<T> {
     // ==========================
     // --- Get subnet node utilization
     // ==========================
-    let node_utilization_rate: f64 = total_active_nodes as f64 / max_nodes as f64;
-    let adj_node_utilization_rate: f64 = Self::pow(
-      node_utilization_rate, 
-      Self::get_percent_as_f64(SubnetNodeInflationAdjFactor::<T>::get())
-    ).min(1.0);
+    let node_utilization_rate: FixedU128 = FixedU128::checked_from_rational(
+      total_active_nodes, 
+      max_nodes
+    ).unwrap_or_default();
 
     // ==========================
     // --- Get final utilization factors
     // ==========================
     // Subnet inflation factor
-    let sif: f64 = Self::get_percent_as_f64(SubnetInflationFactor::<T>::get());
+    let sif: FixedU128 = Self::get_percent_as_fixed(SubnetInflationFactor::<T>::get());
     // Subnet node inflation factor
-    let snif: f64 = 1.0 - sif;
+    let snif: FixedU128 = FixedU128::one().saturating_sub(sif);
 
-    let adj_subnet_utilization_rate: f64 = subnet_utilization_rate * sif;
-    let adj_node_utilization_rate: f64 = node_utilization_rate * snif;
+    let _adj_subnet_utilization_rate: FixedU128 = subnet_utilization_rate.saturating_mul(sif);
+    let adj_node_utilization_rate: FixedU128 = node_utilization_rate.saturating_mul(snif);
 
     // --- Get percentage of inflation to use in current epoch
     // This is the network activity factor
-    let inflation_factor: f64 = Self::get_inflation_factor(snif + adj_node_utilization_rate);
+    let inflation_factor: FixedU128 = Self::get_inflation_factor(snif.saturating_add(adj_node_utilization_rate));
 
     // ==========================
     // --- Get current epochs total inflation
     //
     // * Adjusts the inflation based on network activity using `let inflation_factor`
     // ==========================
-    let total_issuance: f64 = Self::get_total_network_issuance() as f64 + increase_issuance as f64 - decrease_issuance as f64;
-    let epochs_per_year: f64 = T::EpochsPerYear::get() as f64;
-
-    log::error!("inflation total_issuance: {:?}", total_issuance);
-
     let inflation = Inflation::default();
 
-    let year: f64 = epoch as f64 / epochs_per_year;
-    log::error!("inflation year: {:?}", year);
+    let year: FixedU128 = inflation.year_from_epoch(epoch, T::EpochsPerYear::get());
+    log::debug!("inflation year: {:?}", year);
 
     // --- Get current yearly inflation
-    let apr: f64 = inflation.total(year);
-    log::error!("inflation apr: {:?}", apr);
+    let apr: FixedU128 = inflation.total::<T>(year);
+    log::debug!("inflation apr: {:?}", apr);
 
-    (total_issuance * apr * inflation_factor) as u128
+    apr.saturating_mul(inflation_factor)
   }
 
-  pub fn get_inflation_factor(x: f64) -> f64 {
-    if x >= 1.0 {
-      return 1.0
+  pub fn get_inflation_factor(x: FixedU128) -> FixedU128 {
+    if x >= FixedU128::one() {
+      return FixedU128::one()
     }
     
-    let k: f64 = Self::get_percent_as_f64(InflationAdjFactor::<T>::get());
-    if k == 0.0 {
-      return 1.0
+    let k: FixedU128 = Self::get_percent_as_fixed(InflationAdjFactor::<T>::get());
+    if k.is_zero() {
+      return FixedU128::one()
     }
 
-    pow(x, k).min(1.0)
+    Self::fixed_pow(x, k).min(FixedU128::one())
   }
 }
\ No newline at end of file
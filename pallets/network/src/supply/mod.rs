@@ -1,2 +1,4 @@
 use super::*;
-pub mod inflation;
\ No newline at end of file
+pub mod inflation;
+pub mod reward_policy;
+pub use reward_policy::*;
\ No newline at end of file
@@ -0,0 +1,112 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Distribution of the epoch emissions between subnets and within each subnet
+
+use super::*;
+use sp_runtime::RuntimeDebug;
+
+/// Parameters of a subnet used to weigh its share of the epoch emissions
+///
+/// # Arguments
+///
+/// * `subnet_id` - Subnet ID.
+/// * `delegate_stake` - Subnet delegate stake balance.
+/// * `active_subnet_nodes` - Activated subnet nodes of the subnet.
+#[derive(Default, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct SubnetWeightParams {
+  pub subnet_id: u32,
+  pub delegate_stake: u128,
+  pub active_subnet_nodes: u32,
+}
+
+/// Rewards of a subnet split between its owner, delegate stakers, and subnet nodes
+#[derive(Default, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct SubnetRewardsSplit {
+  pub subnet_owner_reward: u128,
+  pub delegate_stake_reward: u128,
+  pub subnet_node_reward: u128,
+}
+
+/// Splits the epoch emissions between subnets, and the rewards of a subnet between its owner,
+/// delegate stakers, and subnet nodes
+///
+/// Percentages are out of `PERCENTAGE_FACTOR`
+pub trait RewardPolicy<T: Config> {
+  /// Weight of a subnets share of the epoch emissions
+  ///
+  /// Weights are normalized against the weights of all active subnets
+  fn subnet_weight(params: &SubnetWeightParams) -> u128;
+
+  /// Split the rewards of a subnet between its owner, delegate stakers, and subnet nodes
+  ///
+  /// The owner gets `SubnetOwnerPercentage` and the delegate stakers get `DelegateStakeRewardsPercentage`
+  /// of the rest
+  fn subnet_rewards_split(_subnet_id: u32, overall_subnet_reward: u128) -> SubnetRewardsSplit {
+    let subnet_owner_reward: u128 = Pallet::<T>::percent_mul(overall_subnet_reward, SubnetOwnerPercentage::<T>::get());
+    let subnet_reward: u128 = overall_subnet_reward.saturating_sub(subnet_owner_reward);
+    let delegate_stake_reward: u128 = Pallet::<T>::percent_mul(subnet_reward, DelegateStakeRewardsPercentage::<T>::get());
+
+    SubnetRewardsSplit {
+      subnet_owner_reward: subnet_owner_reward,
+      delegate_stake_reward: delegate_stake_reward,
+      subnet_node_reward: subnet_reward.saturating_sub(delegate_stake_reward),
+    }
+  }
+
+  /// Percentage of the subnet nodes rewards to a subnet node
+  ///
+  /// Subnet nodes are rewarded by their score out of the sum of the scores
+  fn subnet_node_percentage(score: u128, score_sum: u128) -> u128 {
+    Pallet::<T>::percent_div(score, score_sum)
+  }
+}
+
+/// Weighs subnets by the square root of their delegate stake
+pub struct SqrtDelegateStakePolicy;
+
+impl<T: Config> RewardPolicy<T> for SqrtDelegateStakePolicy {
+  // sqrt(a / total) / Σ sqrt(b / total) == sqrt(a) / Σ sqrt(b)
+  fn subnet_weight(params: &SubnetWeightParams) -> u128 {
+    Pallet::<T>::isqrt(params.delegate_stake)
+  }
+}
+
+/// Weighs subnets by their delegate stake
+pub struct LinearDelegateStakePolicy;
+
+impl<T: Config> RewardPolicy<T> for LinearDelegateStakePolicy {
+  fn subnet_weight(params: &SubnetWeightParams) -> u128 {
+    params.delegate_stake
+  }
+}
+
+/// Splits the epoch emissions equally between subnets
+pub struct EqualSplitPolicy;
+
+impl<T: Config> RewardPolicy<T> for EqualSplitPolicy {
+  fn subnet_weight(_params: &SubnetWeightParams) -> u128 {
+    1
+  }
+}
+
+/// Weighs subnets by their activated subnet nodes
+pub struct ActiveSubnetNodesPolicy;
+
+impl<T: Config> RewardPolicy<T> for ActiveSubnetNodesPolicy {
+  fn subnet_weight(params: &SubnetWeightParams) -> u128 {
+    params.active_subnet_nodes as u128
+  }
+}
@@ -0,0 +1,213 @@
+use super::mock::*;
+use super::test_utils::*;
+use frame_support::{
+	assert_ok, assert_err
+};
+use crate::{
+  Error,
+  SubnetRewardsValidator,
+  AccountSubnetStake,
+  SubnetRewardsSubmission,
+  SubnetValidatorCommittee,
+  SubnetBackupValidators,
+  BackupValidatorsCount,
+  BackupValidatorBlockOffset,
+  SubnetPenaltyCount,
+  SubnetNodeIdHotkey,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Backup validators
+//
+//
+//
+//
+//
+//
+//
+
+#[test]
+fn test_set_backup_validator_params() {
+  new_test_ext().execute_with(|| {
+    assert_err!(
+      Network::do_set_backup_validator_block_offset(0),
+      Error::<Test>::InvalidBackupValidatorBlockOffset
+    );
+    assert_err!(
+      Network::do_set_backup_validator_block_offset(EpochLength::get()),
+      Error::<Test>::InvalidBackupValidatorBlockOffset
+    );
+    assert_ok!(Network::do_set_backup_validator_block_offset(3));
+    assert_eq!(BackupValidatorBlockOffset::<Test>::get(), 3);
+
+    assert_err!(
+      Network::do_set_backup_validators_count(u32::MAX),
+      Error::<Test>::InvalidBackupValidatorsCount
+    );
+
+    // --- The fourth backup would take over after the epoch
+    assert_err!(
+      Network::do_set_backup_validators_count(4),
+      Error::<Test>::InvalidBackupValidatorsCount
+    );
+    assert_ok!(Network::do_set_backup_validators_count(3));
+    assert_eq!(BackupValidatorsCount::<Test>::get(), 3);
+
+    assert_err!(
+      Network::do_set_backup_validator_block_offset(4),
+      Error::<Test>::InvalidBackupValidatorBlockOffset
+    );
+  });
+}
+
+#[test]
+fn test_default_backup_validator_block_offset() {
+  new_test_ext().execute_with(|| {
+    let offset = BackupValidatorBlockOffset::<Test>::get();
+    assert!(offset > 0);
+    assert!(offset * BackupValidatorsCount::<Test>::get() < EpochLength::get());
+  });
+}
+
+#[test]
+fn test_choose_backup_validators() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, _, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |_| ());
+
+    let committee = SubnetValidatorCommittee::<Test>::get(subnet_id, epoch).unwrap();
+    let backups = SubnetBackupValidators::<Test>::get(subnet_id, epoch);
+
+    assert_eq!(backups.len() as u32, BackupValidatorsCount::<Test>::get());
+    assert!(backups.iter().all(|x| !committee.contains(x)));
+    assert_ne!(backups[0], backups[1]);
+  });
+}
+
+#[test]
+fn test_backup_validator_not_active() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |_| ());
+
+    let backups = SubnetBackupValidators::<Test>::get(subnet_id, epoch);
+    let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, backups[0]).unwrap();
+
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(hotkey),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      ),
+      Error::<Test>::BackupValidatorNotActive
+    );
+  });
+}
+
+#[test]
+fn test_backup_validator_in_rank_order() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(Network::do_set_backup_validator_block_offset(3));
+
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |_| ());
+
+    let epoch_start_block = epoch * EpochLength::get();
+    let backups = SubnetBackupValidators::<Test>::get(subnet_id, epoch);
+    let first_backup = SubnetNodeIdHotkey::<Test>::get(subnet_id, backups[0]).unwrap();
+    let second_backup = SubnetNodeIdHotkey::<Test>::get(subnet_id, backups[1]).unwrap();
+
+    System::set_block_number(epoch_start_block + 3);
+
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(second_backup.clone()),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      ),
+      Error::<Test>::BackupValidatorNotActive
+    );
+
+    System::set_block_number(epoch_start_block + 6);
+
+    assert_ok!(
+      Network::validate(
+        RuntimeOrigin::signed(second_backup),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      )
+    );
+
+    // --- Only one backup can take over
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(first_backup),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      ),
+      Error::<Test>::SubnetRewardsAlreadySubmitted
+    );
+
+    let submission = SubnetRewardsSubmission::<Test>::get(subnet_id, epoch).unwrap();
+    assert_eq!(submission.validator_id, backups[1]);
+  });
+}
+
+#[test]
+fn test_backup_validator_keeps_subnet_emissions() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |_| ());
+
+    let validator_id = SubnetRewardsValidator::<Test>::get(subnet_id, epoch).unwrap();
+    let validator = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+    let backups = SubnetBackupValidators::<Test>::get(subnet_id, epoch);
+    let backup = SubnetNodeIdHotkey::<Test>::get(subnet_id, backups[0]).unwrap();
+
+    System::set_block_number(epoch * EpochLength::get() + BackupValidatorBlockOffset::<Test>::get());
+
+    assert_ok!(
+      Network::validate(
+        RuntimeOrigin::signed(backup.clone()),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      )
+    );
+
+    for n in 1..total_subnet_nodes+1 {
+      if n == backups[0] {
+        continue
+      }
+      assert_ok!(
+        Network::attest(
+          RuntimeOrigin::signed(account(n)),
+          subnet_id,
+        )
+      );
+    }
+
+    let validator_stake_before = AccountSubnetStake::<Test>::get(&validator, subnet_id);
+    let backup_stake_before = AccountSubnetStake::<Test>::get(&backup, subnet_id);
+    let penalties_before = SubnetPenaltyCount::<Test>::get(subnet_id);
+
+    increase_epochs(1);
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    // --- Primary is slashed, the subnet isn't penalized and is rewarded
+    assert!(AccountSubnetStake::<Test>::get(&validator, subnet_id) < validator_stake_before);
+    assert!(AccountSubnetStake::<Test>::get(&backup, subnet_id) > backup_stake_before);
+    assert!(SubnetPenaltyCount::<Test>::get(subnet_id) <= penalties_before);
+  });
+}
@@ -0,0 +1,314 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_noop, assert_ok, assert_err
+};
+use crate::{
+  Error,
+  SubnetRewardsValidator,
+  SubnetBackupValidators,
+  AccountSubnetStake,
+  SubnetRewardsSubmission,
+  SubnetRewardsCommit,
+  SubnetCommitReveal,
+  SubnetPenaltyCount,
+  SubnetNodeIdHotkey,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Commit-reveal validation
+//
+//
+//
+//
+//
+//
+//
+
+fn enable_commit_reveal(subnet_id: u32) {
+  assert_ok!(
+    Network::owner_update_commit_reveal(
+      RuntimeOrigin::signed(account(0)),
+      subnet_id,
+      true,
+    )
+  );
+  assert!(SubnetCommitReveal::<Test>::get(subnet_id));
+}
+
+#[test]
+fn test_owner_update_commit_reveal_not_owner() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+    let (subnet_id, _, _, _) = build_epoch_subnet(subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+
+    assert_err!(
+      Network::owner_update_commit_reveal(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        true,
+      ),
+      Error::<Test>::NotSubnetOwner
+    );
+  });
+}
+
+#[test]
+fn test_commit_reveal_validate() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, enable_commit_reveal);
+
+    let subnet_node_data_vec = subnet_node_data(0, total_subnet_nodes);
+    let salt: Vec<u8> = "salt".into();
+
+    let validator_id = SubnetRewardsValidator::<Test>::get(subnet_id, epoch).unwrap();
+    let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+    // --- Direct submissions are rejected
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(hotkey.clone()),
+        subnet_id,
+        subnet_node_data_vec.clone(),
+        None,
+      ),
+      Error::<Test>::CommitRevealEnabled
+    );
+
+    let commitment = Network::get_rewards_commitment(&subnet_node_data_vec, &None, &salt);
+
+    assert_ok!(
+      Network::commit_validate(
+        RuntimeOrigin::signed(hotkey.clone()),
+        subnet_id,
+        commitment,
+      )
+    );
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::ValidatorCommit {
+        subnet_id: subnet_id,
+        account_id: hotkey.clone(),
+        epoch: epoch,
+      }
+    );
+
+    assert_err!(
+      Network::commit_validate(
+        RuntimeOrigin::signed(hotkey.clone()),
+        subnet_id,
+        commitment,
+      ),
+      Error::<Test>::SubnetRewardsAlreadyCommitted
+    );
+
+    // --- Nothing to attest until revealed
+    assert_err!(
+      Network::attest(
+        RuntimeOrigin::signed(account(total_subnet_nodes)),
+        subnet_id,
+      ),
+      Error::<Test>::SubnetRewardsNotRevealed
+    );
+
+    assert_err!(
+      Network::reveal_validate(
+        RuntimeOrigin::signed(hotkey.clone()),
+        subnet_id,
+        subnet_node_data_vec.clone(),
+        None,
+        salt.clone(),
+      ),
+      Error::<Test>::RevealTooEarly
+    );
+
+    System::set_block_number(System::block_number() + 1);
+
+    assert_err!(
+      Network::reveal_validate(
+        RuntimeOrigin::signed(hotkey.clone()),
+        subnet_id,
+        subnet_node_data_vec.clone(),
+        None,
+        "wrong".into(),
+      ),
+      Error::<Test>::InvalidRewardsReveal
+    );
+
+    assert_ok!(
+      Network::reveal_validate(
+        RuntimeOrigin::signed(hotkey.clone()),
+        subnet_id,
+        subnet_node_data_vec.clone(),
+        None,
+        salt.clone(),
+      )
+    );
+
+    assert_eq!(SubnetRewardsCommit::<Test>::get((subnet_id, epoch, validator_id)), None);
+
+    let submission = SubnetRewardsSubmission::<Test>::get(subnet_id, epoch).unwrap();
+    assert_eq!(submission.validator_id, validator_id);
+    assert_eq!(submission.data.len(), subnet_node_data_vec.len());
+    assert_eq!(submission.attests.len(), 1);
+
+    let attestor = if validator_id == 1 { account(2) } else { account(1) };
+    assert_ok!(
+      Network::attest(
+        RuntimeOrigin::signed(attestor),
+        subnet_id,
+      )
+    );
+
+    let submission = SubnetRewardsSubmission::<Test>::get(subnet_id, epoch).unwrap();
+    assert_eq!(submission.attests.len(), 2);
+  });
+}
+
+#[test]
+fn test_commit_validate_not_enabled() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, enable_commit_reveal);
+
+    assert_ok!(
+      Network::owner_update_commit_reveal(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        false,
+      )
+    );
+
+    let subnet_node_data_vec = subnet_node_data(0, total_subnet_nodes);
+    let validator_id = SubnetRewardsValidator::<Test>::get(subnet_id, epoch).unwrap();
+    let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+    let commitment = Network::get_rewards_commitment(&subnet_node_data_vec, &None, &"salt".into());
+
+    assert_err!(
+      Network::commit_validate(
+        RuntimeOrigin::signed(hotkey),
+        subnet_id,
+        commitment,
+      ),
+      Error::<Test>::CommitRevealNotEnabled
+    );
+  });
+}
+
+#[test]
+fn test_commit_validate_invalid_validator() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, enable_commit_reveal);
+
+    let subnet_node_data_vec = subnet_node_data(0, total_subnet_nodes);
+    let validator_id = SubnetRewardsValidator::<Test>::get(subnet_id, epoch).unwrap();
+    let backups = SubnetBackupValidators::<Test>::get(subnet_id, epoch);
+    let not_validator = account(
+      (1..total_subnet_nodes+1).find(|x| *x != validator_id && !backups.contains(x)).unwrap()
+    );
+
+    let commitment = Network::get_rewards_commitment(&subnet_node_data_vec, &None, &"salt".into());
+
+    assert_err!(
+      Network::commit_validate(
+        RuntimeOrigin::signed(not_validator),
+        subnet_id,
+        commitment,
+      ),
+      Error::<Test>::InvalidValidator
+    );
+  });
+}
+
+#[test]
+fn test_commit_without_reveal_is_missed_submission() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, enable_commit_reveal);
+
+    let subnet_node_data_vec = subnet_node_data(0, total_subnet_nodes);
+    let validator_id = SubnetRewardsValidator::<Test>::get(subnet_id, epoch).unwrap();
+    let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+    let commitment = Network::get_rewards_commitment(&subnet_node_data_vec, &None, &"salt".into());
+
+    assert_ok!(
+      Network::commit_validate(
+        RuntimeOrigin::signed(hotkey.clone()),
+        subnet_id,
+        commitment,
+      )
+    );
+
+    let stake_before = AccountSubnetStake::<Test>::get(&hotkey, subnet_id);
+    let penalties_before = SubnetPenaltyCount::<Test>::get(subnet_id);
+
+    increase_epochs(1);
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    assert!(SubnetRewardsSubmission::<Test>::get(subnet_id, epoch).is_none());
+    assert_eq!(SubnetRewardsCommit::<Test>::get((subnet_id, epoch, validator_id)), None);
+    assert_eq!(SubnetPenaltyCount::<Test>::get(subnet_id), penalties_before + 1);
+    assert!(AccountSubnetStake::<Test>::get(&hotkey, subnet_id) < stake_before);
+  });
+}
+
+#[test]
+fn test_reveal_validate_after_commit_reveal_disabled() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, enable_commit_reveal);
+
+    let subnet_node_data_vec = subnet_node_data(0, total_subnet_nodes);
+    let salt: Vec<u8> = "salt".into();
+
+    let validator_id = SubnetRewardsValidator::<Test>::get(subnet_id, epoch).unwrap();
+    let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+    let commitment = Network::get_rewards_commitment(&subnet_node_data_vec, &None, &salt);
+
+    assert_ok!(
+      Network::commit_validate(
+        RuntimeOrigin::signed(hotkey.clone()),
+        subnet_id,
+        commitment,
+      )
+    );
+
+    assert_ok!(
+      Network::owner_update_commit_reveal(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        false,
+      )
+    );
+    assert_eq!(SubnetRewardsCommit::<Test>::get((subnet_id, epoch, validator_id)), None);
+
+    System::set_block_number(System::block_number() + 1);
+
+    assert_err!(
+      Network::reveal_validate(
+        RuntimeOrigin::signed(hotkey.clone()),
+        subnet_id,
+        subnet_node_data_vec.clone(),
+        None,
+        salt.clone(),
+      ),
+      Error::<Test>::CommitRevealNotEnabled
+    );
+    assert!(SubnetRewardsSubmission::<Test>::get(subnet_id, epoch).is_none());
+  });
+}
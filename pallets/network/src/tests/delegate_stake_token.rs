@@ -0,0 +1,299 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_ok, assert_err,
+  traits::tokens::{fungibles::Inspect, WithdrawConsequence},
+};
+use crate::{
+  Error,
+  SubnetDelegateStakeToken,
+  AccountSubnetDelegateStakeShares,
+  TotalSubnetDelegateStakeShares,
+  ReservedSubnetDelegateStakeShares,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Delegate stake token
+//
+//
+//
+//
+//
+//
+//
+
+fn build_delegate_stake_token_subnet(amount: u128) -> (u32, AccountIdOf<Test>) {
+  let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+  let (subnet_id, total_subnet_nodes, _, _) = build_epoch_subnet(subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+
+  assert_ok!(
+    Network::owner_update_delegate_stake_token(
+      RuntimeOrigin::signed(account(0)),
+      subnet_id,
+      true,
+    )
+  );
+
+  let delegator = account(total_subnet_nodes+1);
+  let _ = Balances::deposit_creating(&delegator, amount + 500);
+
+  assert_ok!(
+    Network::add_to_delegate_stake(
+      RuntimeOrigin::signed(delegator.clone()),
+      subnet_id,
+      amount,
+    )
+  );
+
+  (subnet_id, delegator)
+}
+
+#[test]
+fn test_owner_update_delegate_stake_token_not_owner() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+    let (subnet_id, _, _, _) = build_epoch_subnet(subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+
+    assert_err!(
+      Network::owner_update_delegate_stake_token(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        true,
+      ),
+      Error::<Test>::NotSubnetOwner
+    );
+    assert!(!SubnetDelegateStakeToken::<Test>::get(subnet_id));
+  });
+}
+
+#[test]
+fn test_add_delegate_stake_mints_token() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, delegator) = build_delegate_stake_token_subnet(amount);
+
+    let shares = AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id);
+
+    assert!(network_events().contains(&Event::DelegateStakeTokenMinted {
+      subnet_id: subnet_id,
+      account_id: delegator.clone(),
+      shares: shares,
+    }));
+    assert_eq!(<Network as Inspect<AccountIdOf<Test>>>::balance(subnet_id, &delegator), shares);
+    assert_eq!(
+      <Network as Inspect<AccountIdOf<Test>>>::total_issuance(subnet_id),
+      TotalSubnetDelegateStakeShares::<Test>::get(subnet_id)
+    );
+
+    assert_ok!(
+      Network::remove_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        shares,
+      )
+    );
+
+    assert!(network_events().contains(&Event::DelegateStakeTokenBurned {
+      subnet_id: subnet_id,
+      account_id: delegator.clone(),
+      shares: shares,
+    }));
+  });
+}
+
+#[test]
+fn test_transfer_delegate_stake_token() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, delegator) = build_delegate_stake_token_subnet(amount);
+
+    let shares = AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id);
+    let total_shares = TotalSubnetDelegateStakeShares::<Test>::get(subnet_id);
+    let receiver = account(1000);
+    let receiver_shares = AccountSubnetDelegateStakeShares::<Test>::get(receiver.clone(), subnet_id);
+
+    assert_ok!(
+      Network::transfer_delegate_stake_token(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        receiver.clone(),
+        shares / 2,
+      )
+    );
+
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id), shares - shares / 2);
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(receiver.clone(), subnet_id), receiver_shares + shares / 2);
+    assert_eq!(TotalSubnetDelegateStakeShares::<Test>::get(subnet_id), total_shares);
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::DelegateStakeTokenTransferred {
+        subnet_id: subnet_id,
+        from: delegator.clone(),
+        to: receiver.clone(),
+        shares: shares / 2,
+      }
+    );
+
+    assert_err!(
+      Network::transfer_delegate_stake_token(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        receiver.clone(),
+        shares,
+      ),
+      Error::<Test>::NotEnoughFreeDelegateStakeShares
+    );
+
+    assert_err!(
+      Network::transfer_delegate_stake_token(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        delegator.clone(),
+        1,
+      ),
+      Error::<Test>::InvalidDelegateStakeTokenTransfer
+    );
+
+    // --- The receiver would be under the minimum balance
+    assert_err!(
+      Network::transfer_delegate_stake_token(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        account(1001),
+        1,
+      ),
+      Error::<Test>::MinDelegateStake
+    );
+
+    // --- The received shares are in the cooldown of the receiver
+    assert_eq!(Network::delegate_stake_cooldown_shares(&receiver, subnet_id), shares / 2);
+    assert_err!(
+      Network::transfer_delegate_stake_token(
+        RuntimeOrigin::signed(receiver.clone()),
+        subnet_id,
+        delegator.clone(),
+        shares / 2,
+      ),
+      Error::<Test>::InsufficientCooldown
+    );
+  });
+}
+
+#[test]
+fn test_transfer_delegate_stake_token_disabled() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, delegator) = build_delegate_stake_token_subnet(amount);
+
+    assert_ok!(
+      Network::owner_update_delegate_stake_token(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        false,
+      )
+    );
+
+    assert_err!(
+      Network::transfer_delegate_stake_token(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        account(1000),
+        1,
+      ),
+      Error::<Test>::DelegateStakeTokenDisabled
+    );
+    assert!(!<Network as Inspect<AccountIdOf<Test>>>::asset_exists(subnet_id));
+  });
+}
+
+#[test]
+fn test_reserve_and_repatriate_delegate_stake_token() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, delegator) = build_delegate_stake_token_subnet(amount);
+
+    let shares = AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id);
+    let receiver = account(1000);
+    let receiver_shares = AccountSubnetDelegateStakeShares::<Test>::get(receiver.clone(), subnet_id);
+
+    assert_err!(
+      Network::reserve_delegate_stake_token(&delegator, subnet_id, shares + 1),
+      Error::<Test>::NotEnoughFreeDelegateStakeShares
+    );
+    assert_ok!(Network::reserve_delegate_stake_token(&delegator, subnet_id, shares));
+
+    // --- Reserved shares can't be removed or transferred
+    assert_eq!(
+      <Network as Inspect<AccountIdOf<Test>>>::can_withdraw(subnet_id, &delegator, 1),
+      WithdrawConsequence::BalanceLow
+    );
+    assert_err!(
+      Network::remove_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        shares,
+      ),
+      Error::<Test>::NotEnoughStakeToWithdraw
+    );
+    assert_err!(
+      Network::transfer_delegate_stake_token(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        receiver.clone(),
+        1,
+      ),
+      Error::<Test>::NotEnoughFreeDelegateStakeShares
+    );
+
+    Network::unreserve_delegate_stake_token(&delegator, subnet_id, shares / 2);
+    assert_eq!(ReservedSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id), shares - shares / 2);
+
+    assert_ok!(Network::repatriate_reserved_delegate_stake_token(&delegator, &receiver, subnet_id, shares - shares / 2));
+
+    assert!(!ReservedSubnetDelegateStakeShares::<Test>::contains_key(delegator.clone(), subnet_id));
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id), shares / 2);
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(receiver.clone(), subnet_id), receiver_shares + shares - shares / 2);
+    assert_eq!(Network::delegate_stake_cooldown_shares(&receiver, subnet_id), shares - shares / 2);
+  });
+}
+
+#[test]
+fn test_repatriate_delegate_stake_token_after_disabled() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, delegator) = build_delegate_stake_token_subnet(amount);
+
+    let shares = AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id);
+    let receiver = account(1000);
+
+    assert_ok!(Network::reserve_delegate_stake_token(&delegator, subnet_id, shares));
+
+    assert_ok!(
+      Network::owner_update_delegate_stake_token(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        false,
+      )
+    );
+
+    // --- New reservations fail but existing ones can still be claimed
+    assert_err!(
+      Network::reserve_delegate_stake_token(&delegator, subnet_id, 0),
+      Error::<Test>::DelegateStakeTokenDisabled
+    );
+    assert_ok!(Network::repatriate_reserved_delegate_stake_token(&delegator, &receiver, subnet_id, shares));
+
+    assert!(!ReservedSubnetDelegateStakeShares::<Test>::contains_key(delegator.clone(), subnet_id));
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id), 0);
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(receiver.clone(), subnet_id), shares);
+  });
+}
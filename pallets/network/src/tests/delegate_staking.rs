@@ -16,12 +16,15 @@ use crate::{
   AccountSubnetDelegateStakeShares, 
   TotalSubnetDelegateStakeShares, 
   TotalSubnetDelegateStakeBalance,
+  TotalDelegateStake,
   SubnetRemovalReason,
   StakeUnbondingLedger,
   AccountNodeDelegateStakeShares,
   TotalNodeDelegateStakeBalance,
   TotalNodeDelegateStakeShares,
   MinStakeBalance,
+  MaxDelegateStakeBalance,
+  MaxSubnetDelegateStake,
 };
 use codec::Decode;
 use sp_runtime::traits::TrailingZeroInput;
@@ -168,7 +171,8 @@ fn check_balances() {
       // reset everything
       let _ = AccountSubnetDelegateStakeShares::<Test>::remove(user.clone(), subnet_id);			
       let _ = TotalSubnetDelegateStakeShares::<Test>::remove(subnet_id);
-      let _ = TotalSubnetDelegateStakeBalance::<Test>::remove(subnet_id);
+      let total_subnet_delegated_stake_balance = TotalSubnetDelegateStakeBalance::<Test>::take(subnet_id);
+      TotalDelegateStake::<Test>::mutate(|n| *n -= total_subnet_delegated_stake_balance);
 
       let USER_INITIAL_TOKENS: u128 = 10_u128.pow(n);
       let USER_INITIAL_BALANCE: u128 = USER_INITIAL_TOKENS + 500;
@@ -343,15 +347,12 @@ fn test_remove_claim_delegate_stake_after_remove_subnet() {
       )
     );
 
+    remove_subnet_storage();
+
     let epoch = System::block_number() / EpochLength::get();
 
-    assert_ok!(
-      Network::remove_delegate_stake(
-        RuntimeOrigin::signed(account(total_subnet_nodes+1)),
-        subnet_id,
-        delegate_shares,
-      )
-    );
+    // --- Removing the subnet unbonds the delegate stake
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(account(total_subnet_nodes+1), subnet_id), 0);
 
     let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(account(total_subnet_nodes+1));
     assert_eq!(unbondings.len(), 1);
@@ -1086,18 +1087,15 @@ fn test_remove_delegate_stake_after_subnet_remove() {
       )
     );
 
+    remove_subnet_storage();
+
     // System::set_block_number(System::block_number() + epoch_length * cooldown_epochs);
 
     let balance = Balances::free_balance(&account(n_account));
     let epoch = System::block_number() / epoch_length;
 
-    assert_ok!(
-      Network::remove_delegate_stake(
-        RuntimeOrigin::signed(account(n_account)),
-        subnet_id,
-        delegate_shares,
-      )
-    );
+    // --- Removing the subnet unbonds the delegate stake
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), subnet_id), 0);
     let post_balance = Balances::free_balance(&account(n_account));
     assert_eq!(post_balance, balance);
 
@@ -1613,3 +1611,221 @@ fn test_donation_attack_simulation() {
     // assert!(false);
   });
 }
+
+#[test]
+fn test_add_to_delegate_stake_max_delegate_stake_balance() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let deposit_amount: u128 = 10000000000000000000000;
+    let amount: u128 = 1000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+
+    build_activated_subnet(subnet_path.clone(), 0, 0, deposit_amount, stake_amount);
+
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+    let n_account = total_subnet_nodes + 1;
+
+    let _ = Balances::deposit_creating(&account(n_account), amount*2+500);
+
+    MaxDelegateStakeBalance::<Test>::set(amount);
+
+    assert_ok!(
+      Network::add_to_delegate_stake(
+        RuntimeOrigin::signed(account(n_account)),
+        subnet_id,
+        amount,
+      ) 
+    );
+
+    let delegate_shares = AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), subnet_id);
+
+    assert_err!(
+      Network::add_to_delegate_stake(
+        RuntimeOrigin::signed(account(n_account)),
+        subnet_id,
+        amount,
+      ),
+      Error::<Test>::MaxDelegatedStakeReached
+    );
+
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), subnet_id), delegate_shares);
+  });
+}
+
+#[test]
+fn test_transfer_delegate_stake_shares_max_delegate_stake_balance() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let deposit_amount: u128 = 10000000000000000000000;
+    let amount: u128 = 1000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+
+    build_activated_subnet(subnet_path.clone(), 0, 0, deposit_amount, stake_amount);
+
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+    let sender = account(total_subnet_nodes + 1);
+    let receiver = account(total_subnet_nodes + 2);
+
+    MaxDelegateStakeBalance::<Test>::set(amount);
+
+    for delegator in [sender.clone(), receiver.clone()] {
+      let _ = Balances::deposit_creating(&delegator, amount+500);
+      assert_ok!(
+        Network::add_to_delegate_stake(
+          RuntimeOrigin::signed(delegator),
+          subnet_id,
+          amount,
+        ) 
+      );
+    }
+
+    let sender_shares = AccountSubnetDelegateStakeShares::<Test>::get(sender.clone(), subnet_id);
+    let receiver_shares = AccountSubnetDelegateStakeShares::<Test>::get(receiver.clone(), subnet_id);
+
+    // --- The receiver would go over the max balance by transfer
+    assert_err!(
+      Network::transfer_delegate_stake_shares(
+        RuntimeOrigin::signed(sender.clone()),
+        receiver.clone(),
+        subnet_id,
+        sender_shares / 2,
+      ),
+      Error::<Test>::MaxDelegatedStakeReached
+    );
+
+    // --- And by the delegate stake token
+    assert_ok!(
+      Network::owner_update_delegate_stake_token(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        true,
+      )
+    );
+
+    assert_err!(
+      Network::transfer_delegate_stake_token(
+        RuntimeOrigin::signed(sender.clone()),
+        subnet_id,
+        receiver.clone(),
+        sender_shares / 2,
+      ),
+      Error::<Test>::MaxDelegatedStakeReached
+    );
+
+    assert_ok!(Network::reserve_delegate_stake_token(&sender, subnet_id, sender_shares / 2));
+    assert_err!(
+      Network::repatriate_reserved_delegate_stake_token(&sender, &receiver, subnet_id, sender_shares / 2),
+      Error::<Test>::MaxDelegatedStakeReached
+    );
+    Network::unreserve_delegate_stake_token(&sender, subnet_id, sender_shares / 2);
+
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(sender.clone(), subnet_id), sender_shares);
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(receiver.clone(), subnet_id), receiver_shares);
+
+    // --- Accounts under the max balance can receive
+    assert_ok!(
+      Network::transfer_delegate_stake_shares(
+        RuntimeOrigin::signed(sender.clone()),
+        account(total_subnet_nodes + 3),
+        subnet_id,
+        sender_shares / 2,
+      )
+    );
+  });
+}
+
+#[test]
+fn test_add_to_delegate_stake_max_subnet_delegate_stake() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let deposit_amount: u128 = 10000000000000000000000;
+    let amount: u128 = 1000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+
+    build_activated_subnet(subnet_path.clone(), 0, 0, deposit_amount, stake_amount);
+
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+    let n_account = total_subnet_nodes + 1;
+
+    let _ = Balances::deposit_creating(&account(n_account), amount*2+500);
+
+    let max_subnet_delegate_stake = TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id) + amount;
+
+    assert_err!(
+      Network::owner_update_max_subnet_delegate_stake(
+        RuntimeOrigin::signed(account(n_account)),
+        subnet_id,
+        Some(max_subnet_delegate_stake),
+      ),
+      Error::<Test>::NotSubnetOwner
+    );
+
+    assert_err!(
+      Network::owner_update_max_subnet_delegate_stake(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        Some(0),
+      ),
+      Error::<Test>::InvalidMaxSubnetDelegateStake
+    );
+
+    assert_ok!(
+      Network::owner_update_max_subnet_delegate_stake(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        Some(max_subnet_delegate_stake),
+      )
+    );
+    assert_eq!(MaxSubnetDelegateStake::<Test>::get(subnet_id), Some(max_subnet_delegate_stake));
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::SubnetMaxDelegateStakeUpdate {
+        subnet_id: subnet_id,
+        owner: account(0),
+        value: Some(max_subnet_delegate_stake),
+      }
+    );
+
+    assert_ok!(
+      Network::add_to_delegate_stake(
+        RuntimeOrigin::signed(account(n_account)),
+        subnet_id,
+        amount,
+      ) 
+    );
+
+    // --- The subnet is full
+    assert_err!(
+      Network::add_to_delegate_stake(
+        RuntimeOrigin::signed(account(n_account)),
+        subnet_id,
+        1000,
+      ),
+      Error::<Test>::MaxSubnetDelegateStakeReached
+    );
+
+    assert_ok!(
+      Network::owner_update_max_subnet_delegate_stake(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        None,
+      )
+    );
+    assert_eq!(MaxSubnetDelegateStake::<Test>::get(subnet_id), None);
+
+    assert_ok!(
+      Network::add_to_delegate_stake(
+        RuntimeOrigin::signed(account(n_account)),
+        subnet_id,
+        1000,
+      ) 
+    );
+  });
+}
@@ -0,0 +1,229 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_ok, assert_err,
+};
+use sp_core::H256;
+use crate::{
+  Error,
+  SubnetRewardsDissents,
+  SubnetPaths,
+  SubnetNodePenalties,
+  SubnetPenaltyCount,
+  MinStakeBalance,
+  DissentSlashPercentage,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Dissent
+//
+//
+//
+//
+//
+//
+//
+
+// Subnet node IDs that aren't the validator
+fn non_validators(total_subnet_nodes: u32, validator_id: u32) -> Vec<u32> {
+  (1..total_subnet_nodes+1).filter(|n| *n != validator_id).collect()
+}
+
+#[test]
+fn test_dissent() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, validator_id) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Submitted, |_| ());
+
+    let n = non_validators(total_subnet_nodes, validator_id)[0];
+    let reason = H256::repeat_byte(1);
+
+    assert_ok!(Network::dissent(RuntimeOrigin::signed(account(n)), subnet_id, Some(reason)));
+
+    assert_eq!(SubnetRewardsDissents::<Test>::get(subnet_id, epoch).get(&n), Some(&Some(reason)));
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::Dissent {
+        subnet_id: subnet_id,
+        account_id: account(n),
+        epoch: epoch,
+        reason: Some(reason),
+      }
+    );
+
+    assert_err!(
+      Network::dissent(RuntimeOrigin::signed(account(n)), subnet_id, None),
+      Error::<Test>::AlreadyDissented
+    );
+
+    assert_err!(
+      Network::attest(RuntimeOrigin::signed(account(n)), subnet_id),
+      Error::<Test>::AlreadyDissented
+    );
+
+    // --- The validator auto-attests its own data
+    assert_err!(
+      Network::dissent(RuntimeOrigin::signed(account(validator_id)), subnet_id, None),
+      Error::<Test>::AlreadyAttested
+    );
+  });
+}
+
+#[test]
+fn test_dissent_no_submission() {
+  new_test_ext().execute_with(|| {
+    let deposit_amount: u128 = 10000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+    build_activated_subnet(subnet_path.clone(), 0, 12, deposit_amount, stake_amount);
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+
+    assert_err!(
+      Network::dissent(RuntimeOrigin::signed(account(1)), subnet_id, None),
+      Error::<Test>::InvalidSubnetRewardsSubmission
+    );
+
+    assert_err!(
+      Network::dissent(RuntimeOrigin::signed(account(100)), subnet_id, None),
+      Error::<Test>::SubnetNodeNotExist
+    );
+  });
+}
+
+#[test]
+fn test_set_dissent_slash_percentage() {
+  new_test_ext().execute_with(|| {
+    assert_err!(
+      Network::do_set_dissent_slash_percentage(0),
+      Error::<Test>::InvalidDissentSlashPercentage
+    );
+
+    assert_err!(
+      Network::do_set_dissent_slash_percentage(Network::PERCENTAGE_FACTOR + 1),
+      Error::<Test>::InvalidDissentSlashPercentage
+    );
+
+    assert_ok!(Network::do_set_dissent_slash_percentage(500000000));
+    assert_eq!(DissentSlashPercentage::<Test>::get(), 500000000);
+  });
+}
+
+#[test]
+fn test_dissent_slashes_validator() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, validator_id) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Submitted, |_| ());
+
+    // --- 5 of 12 dissent, over 1/3
+    for n in non_validators(total_subnet_nodes, validator_id).into_iter().take(5) {
+      assert_ok!(Network::dissent(RuntimeOrigin::signed(account(n)), subnet_id, None));
+    }
+
+    let subnet_penalty_count = SubnetPenaltyCount::<Test>::get(subnet_id);
+
+    increase_epochs(1);
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    // --- Validator was wrong
+    assert_eq!(SubnetNodePenalties::<Test>::get(subnet_id, validator_id), 1);
+    assert_eq!(SubnetPenaltyCount::<Test>::get(subnet_id), subnet_penalty_count);
+    assert!(network_events().iter().any(|event| matches!(
+      event,
+      Event::Slashing { subnet_id: id, .. } if *id == subnet_id
+    )));
+  });
+}
+
+#[test]
+fn test_offline_subnet_nodes_dont_slash_validator() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, validator_id) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Submitted, |_| ());
+
+    // --- 1 of 12 dissent, the rest are offline
+    let n = non_validators(total_subnet_nodes, validator_id)[0];
+    assert_ok!(Network::dissent(RuntimeOrigin::signed(account(n)), subnet_id, None));
+
+    let subnet_penalty_count = SubnetPenaltyCount::<Test>::get(subnet_id);
+
+    increase_epochs(1);
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    // --- Neither the validator nor the subnet are penalized
+    assert_eq!(SubnetNodePenalties::<Test>::get(subnet_id, validator_id), 0);
+    assert_eq!(SubnetPenaltyCount::<Test>::get(subnet_id), subnet_penalty_count);
+    assert!(!network_events().iter().any(|event| matches!(
+      event,
+      Event::Slashing { subnet_id: id, .. } if *id == subnet_id
+    )));
+  });
+}
+
+#[test]
+fn test_some_dissents_slash_validator() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, validator_id) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Submitted, |_| ());
+
+    // --- 3 of 12 dissent, under 1/3 but not under half of it
+    for n in non_validators(total_subnet_nodes, validator_id).into_iter().take(3) {
+      assert_ok!(Network::dissent(RuntimeOrigin::signed(account(n)), subnet_id, None));
+    }
+
+    let subnet_penalty_count = SubnetPenaltyCount::<Test>::get(subnet_id);
+
+    increase_epochs(1);
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    // --- Only the validator is penalized
+    assert_eq!(SubnetNodePenalties::<Test>::get(subnet_id, validator_id), 1);
+    assert_eq!(SubnetPenaltyCount::<Test>::get(subnet_id), subnet_penalty_count);
+    assert!(network_events().iter().any(|event| matches!(
+      event,
+      Event::Slashing { subnet_id: id, .. } if *id == subnet_id
+    )));
+  });
+}
+
+#[test]
+fn test_dissenting_subnet_node_not_penalized() {
+  let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+  // --- Silent subnet node is penalized on a vast majority attestation
+  // --- Dissenting subnet node isn't
+  for dissent in [false, true] {
+    new_test_ext().execute_with(|| {
+      let (subnet_id, total_subnet_nodes, epoch, validator_id) = build_epoch_subnet(subnet_path.clone(), 12, EpochSubnetStage::Submitted, |_| ());
+
+      let subnet_node_ids = non_validators(total_subnet_nodes, validator_id);
+      let (dissenter, attestors) = subnet_node_ids.split_first().unwrap();
+
+      for n in attestors {
+        assert_ok!(Network::attest(RuntimeOrigin::signed(account(*n)), subnet_id));
+      }
+
+      if dissent {
+        assert_ok!(Network::dissent(RuntimeOrigin::signed(account(*dissenter)), subnet_id, None));
+      }
+
+      increase_epochs(1);
+
+      assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+      let penalties = SubnetNodePenalties::<Test>::get(subnet_id, *dissenter);
+      assert_eq!(penalties, if dissent { 0 } else { 1 });
+    });
+  }
+}
@@ -0,0 +1,444 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_ok, assert_err,
+  traits::{Get, Hooks},
+  weights::Weight,
+};
+use crate::{
+  Error,
+  SubnetRewardsValidator,
+  SubnetPaths,
+  AccountSubnetStake,
+  EpochRewardsCursor,
+  MaxRewardsItemsPerBlock,
+  MaxSubnets,
+  MinStakeBalance,
+  WeightInfo,
+  RewardsHistoryEpochs,
+  SubnetEpochRewards,
+  SubnetNodeEpochRewards,
+  SubnetRewardsReceipt,
+  SubnetRemovalReason,
+  SubnetNodeRewardsReceipt,
+  SubnetPenaltyCount,
+  PendingRewardsEpochs,
+  SubnetEpochRewardsPruneEpoch,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Epoch rewards across blocks
+//
+//
+//
+//
+//
+//
+//
+
+fn subnet_node_stakes(subnet_id: u32, total_subnet_nodes: u32) -> Vec<u128> {
+  (1..total_subnet_nodes+1)
+    .map(|n| AccountSubnetStake::<Test>::get(&account(n), subnet_id))
+    .collect()
+}
+
+#[test]
+fn test_set_max_rewards_items_per_block() {
+  new_test_ext().execute_with(|| {
+    assert_err!(
+      Network::do_set_max_rewards_items_per_block(0),
+      Error::<Test>::InvalidMaxRewardsItemsPerBlock
+    );
+
+    assert_ok!(Network::do_set_max_rewards_items_per_block(5));
+    assert_eq!(MaxRewardsItemsPerBlock::<Test>::get(), 5);
+  });
+}
+
+#[test]
+fn test_rewards_across_blocks_match_single_block() {
+  let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+  // --- Rewarded in one call
+  let expected: Vec<u128> = new_test_ext().execute_with(|| {
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path.clone(), 12, EpochSubnetStage::Attested, |_| ());
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    subnet_node_stakes(subnet_id, total_subnet_nodes)
+  });
+
+  // --- Rewarded 5 items at a time
+  new_test_ext().execute_with(|| {
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path.clone(), 12, EpochSubnetStage::Attested, |_| ());
+    let stakes_before = subnet_node_stakes(subnet_id, total_subnet_nodes);
+
+    Network::begin_reward_subnets(System::block_number(), epoch);
+
+    let cursor = EpochRewardsCursor::<Test>::get().unwrap();
+    assert_eq!(cursor.epoch, epoch);
+    assert_eq!(cursor.subnets.len(), 1);
+    assert_eq!(cursor.subnet, None);
+
+    Network::do_reward_subnets_step(5, Weight::MAX);
+
+    // --- The subnet and the first 4 subnet nodes
+    let subnet_cursor = EpochRewardsCursor::<Test>::get().unwrap().subnet.unwrap();
+    assert_eq!(subnet_cursor.subnet_id, subnet_id);
+    assert!(subnet_cursor.subnet_node_id.is_some());
+
+    let mut steps: u32 = 1;
+    while EpochRewardsCursor::<Test>::exists() {
+      Network::do_reward_subnets_step(5, Weight::MAX);
+      steps += 1;
+    }
+
+    // --- 1 subnet and 12 subnet nodes
+    assert_eq!(steps, 3);
+
+    let stakes = subnet_node_stakes(subnet_id, total_subnet_nodes);
+    assert_ne!(stakes, stakes_before);
+    assert_eq!(stakes, expected);
+
+    assert!(network_events().iter().any(|event| matches!(
+      event,
+      Event::RewardResult { subnet_id: id, .. } if *id == subnet_id
+    )));
+  });
+}
+
+#[test]
+fn test_begin_rewards_queues_pending_epoch() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Attested, |_| ());
+    let stakes_before = subnet_node_stakes(subnet_id, total_subnet_nodes);
+    let block = System::block_number();
+
+    Network::begin_reward_subnets(block, epoch);
+    Network::do_reward_subnets_step(1, Weight::MAX);
+
+    Network::begin_reward_subnets(block + 1, epoch + 1);
+
+    // --- The previous epoch isn't drained, the next waits for it
+    assert_eq!(EpochRewardsCursor::<Test>::get().unwrap().epoch, epoch);
+    assert_eq!(PendingRewardsEpochs::<Test>::get(), vec![(block + 1, epoch + 1)]);
+    assert_eq!(subnet_node_stakes(subnet_id, total_subnet_nodes), stakes_before);
+
+    while EpochRewardsCursor::<Test>::exists() {
+      Network::do_reward_subnets_step(5, Weight::MAX);
+    }
+    assert_ne!(subnet_node_stakes(subnet_id, total_subnet_nodes), stakes_before);
+
+    // --- The pending epoch begins on the next step
+    Network::do_reward_subnets_step(5, Weight::MAX);
+    let cursor = EpochRewardsCursor::<Test>::get().unwrap();
+    assert_eq!(cursor.epoch, epoch + 1);
+    assert_eq!(cursor.block, block + 1);
+    assert!(!PendingRewardsEpochs::<Test>::exists());
+  });
+}
+
+#[test]
+fn test_rewards_step_within_max_weight() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (_, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Attested, |_| ());
+
+    Network::begin_reward_subnets(System::block_number(), epoch);
+
+    let node_weight = <Test as crate::Config>::WeightInfo::reward_subnet_node(total_subnet_nodes);
+    let max_weight = <Test as crate::Config>::WeightInfo::reward_subnet(total_subnet_nodes, total_subnet_nodes)
+      .saturating_add(node_weight.saturating_mul(2))
+      .saturating_add(<Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1));
+
+    // --- The subnet and 2 subnet nodes fit
+    let weight = Network::do_reward_subnets_step(u32::MAX, max_weight);
+    assert!(weight.all_lte(max_weight));
+
+    // --- Subnet nodes are iterated in storage order, the rest are rewarded on later steps
+    let subnet_cursor = EpochRewardsCursor::<Test>::get().unwrap().subnet.unwrap();
+    assert!(subnet_cursor.subnet_node_id.is_some());
+    assert!(weight.all_gte(node_weight.saturating_mul(2)));
+  });
+}
+
+#[test]
+fn test_on_idle_continues_rewards() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Attested, |_| ());
+    let stakes_before = subnet_node_stakes(subnet_id, total_subnet_nodes);
+
+    Network::begin_reward_subnets(System::block_number(), epoch);
+
+    // --- Not enough weight for a single item
+    Network::on_idle(System::block_number(), Weight::from_parts(1, 1));
+    assert!(EpochRewardsCursor::<Test>::exists());
+
+    let used_weight = Network::on_idle(System::block_number(), Weight::MAX);
+    assert!(used_weight.all_gt(Weight::zero()));
+    assert!(!EpochRewardsCursor::<Test>::exists());
+    assert_ne!(subnet_node_stakes(subnet_id, total_subnet_nodes), stakes_before);
+  });
+}
+
+#[test]
+fn test_removed_subnet_not_rewarded() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path.clone(), 12, EpochSubnetStage::Attested, |_| ());
+    let stakes_before = subnet_node_stakes(subnet_id, total_subnet_nodes);
+
+    Network::begin_reward_subnets(System::block_number(), epoch);
+
+    assert_ok!(Network::do_remove_subnet(subnet_path, SubnetRemovalReason::Council));
+
+    Network::do_reward_subnets_step(u32::MAX, Weight::MAX);
+
+    assert!(!EpochRewardsCursor::<Test>::exists());
+    assert!(!SubnetEpochRewards::<Test>::contains_key(subnet_id, epoch));
+    assert_eq!(subnet_node_stakes(subnet_id, total_subnet_nodes), stakes_before);
+  });
+}
+
+#[test]
+fn test_on_initialize_begins_rewards() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, _, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Attested, |_| ());
+    let stakes_before = subnet_node_stakes(subnet_id, total_subnet_nodes);
+
+    assert_ok!(Network::do_set_max_rewards_items_per_block(1));
+
+    let epoch_length = EpochLength::get();
+    let block = System::block_number() / epoch_length * epoch_length;
+    System::set_block_number(block);
+
+    // --- Only the subnet is rewarded on the first block
+    Network::on_initialize(block);
+    assert!(EpochRewardsCursor::<Test>::get().unwrap().subnet.is_some());
+    assert_eq!(subnet_node_stakes(subnet_id, total_subnet_nodes), stakes_before);
+
+    // --- Subnet nodes are rewarded on the following blocks
+    assert_ok!(Network::do_set_max_rewards_items_per_block(4));
+    for n in 1..4 {
+      System::set_block_number(block + n);
+      Network::on_initialize(block + n);
+    }
+
+    assert!(!EpochRewardsCursor::<Test>::exists());
+    assert_ne!(subnet_node_stakes(subnet_id, total_subnet_nodes), stakes_before);
+  });
+}
+
+#[test]
+fn test_on_initialize_epoch_phase_weights() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (_, total_subnet_nodes, _, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Attested, |_| ());
+
+    let epoch_length = EpochLength::get();
+    let block = System::block_number() / epoch_length * epoch_length;
+    let queue_weight = <Test as crate::Config>::WeightInfo::do_queue(MaxSubnets::<Test>::get() + 1);
+
+    let mut weights: Vec<Weight> = Vec::new();
+    for n in 0..3 {
+      System::set_block_number(block + n);
+      weights.push(Network::on_initialize(block + n));
+    }
+
+    // --- ``do_queue`` in ``on_finalize`` is weighed on every block
+    assert!(weights.iter().all(|weight| weight.all_gte(queue_weight)));
+
+    // --- Epoch preliminaries are weighed by the subnets and their validators
+    assert!(weights[2].all_gte(
+      <Test as crate::Config>::WeightInfo::do_epoch_preliminaries(1, total_subnet_nodes)
+        .saturating_add(queue_weight)
+    ));
+  });
+}
+
+#[test]
+fn test_set_rewards_history_epochs() {
+  new_test_ext().execute_with(|| {
+    assert_err!(
+      Network::do_set_rewards_history_epochs(0),
+      Error::<Test>::InvalidRewardsHistoryEpochs
+    );
+
+    assert_ok!(Network::do_set_rewards_history_epochs(5));
+    assert_eq!(RewardsHistoryEpochs::<Test>::get(), 5);
+  });
+}
+
+#[test]
+fn test_epoch_rewards_receipts() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Attested, |_| ());
+    let stakes_before = subnet_node_stakes(subnet_id, total_subnet_nodes);
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    let stakes = subnet_node_stakes(subnet_id, total_subnet_nodes);
+
+    let mut subnet_node_reward: u128 = 0;
+    for n in 1..total_subnet_nodes+1 {
+      let receipt = SubnetNodeEpochRewards::<Test>::get((subnet_id, epoch, n)).unwrap();
+      assert_eq!(receipt.hotkey, account(n));
+      assert_eq!(receipt.reward, stakes[n as usize - 1] - stakes_before[n as usize - 1]);
+      subnet_node_reward += receipt.reward + receipt.node_delegate_reward;
+
+      assert!(network_events().iter().any(|event| matches!(
+        event,
+        Event::SubnetNodeRewarded { subnet_id: id, subnet_node_id, reward, .. }
+          if *id == subnet_id && *subnet_node_id == n && *reward == receipt.reward
+      )));
+    }
+
+    let receipt = SubnetEpochRewards::<Test>::get(subnet_id, epoch).unwrap();
+    assert_eq!(receipt.subnet_owner, Some(account(0)));
+    assert!(receipt.subnet_owner_reward > 0);
+    assert!(receipt.delegate_stake_reward > 0);
+    assert_eq!(receipt.subnet_node_reward, subnet_node_reward);
+
+    assert_eq!(
+      network_events().iter().find(|event| matches!(event, Event::SubnetRewarded { .. })).unwrap(),
+      &Event::SubnetRewarded {
+        subnet_id: subnet_id,
+        epoch: epoch,
+        subnet_owner_reward: receipt.subnet_owner_reward,
+        delegate_stake_reward: receipt.delegate_stake_reward,
+        subnet_node_reward: subnet_node_reward,
+      }
+    );
+  });
+}
+
+#[test]
+fn test_prune_epoch_rewards() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, _, epoch, _) = build_epoch_subnet(subnet_path.clone(), 12, EpochSubnetStage::Attested, |_| ());
+
+    assert_ok!(Network::do_set_rewards_history_epochs(1));
+    SubnetEpochRewards::<Test>::insert(subnet_id, epoch - 1, SubnetRewardsReceipt::default());
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    // --- Receipts older than the history are pruned when the subnet is rewarded
+    assert!(!SubnetEpochRewards::<Test>::contains_key(subnet_id, epoch - 1));
+    assert!(SubnetEpochRewards::<Test>::contains_key(subnet_id, epoch));
+    assert!(SubnetNodeEpochRewards::<Test>::contains_key((subnet_id, epoch, 1)));
+
+    assert_ok!(Network::do_remove_subnet(subnet_path, SubnetRemovalReason::Council));
+    remove_subnet_storage();
+
+    assert!(!SubnetEpochRewards::<Test>::contains_key(subnet_id, epoch));
+    assert!(!SubnetNodeEpochRewards::<Test>::contains_key((subnet_id, epoch, 1)));
+    assert!(!SubnetEpochRewardsPruneEpoch::<Test>::contains_key(subnet_id));
+  });
+}
+
+#[test]
+fn test_prune_epoch_rewards_catches_up() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, _, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Attested, |_| ());
+
+    // --- Receipts of 6 epochs past the history, e.g. after lowering `RewardsHistoryEpochs`
+    assert_ok!(Network::do_set_rewards_history_epochs(1));
+    SubnetEpochRewardsPruneEpoch::<Test>::insert(subnet_id, epoch - 6);
+    for prune_epoch in epoch-6..epoch {
+      SubnetEpochRewards::<Test>::insert(subnet_id, prune_epoch, SubnetRewardsReceipt::default());
+    }
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    // --- Up to 4 epochs are pruned at a time
+    for prune_epoch in epoch-6..epoch-2 {
+      assert!(!SubnetEpochRewards::<Test>::contains_key(subnet_id, prune_epoch));
+    }
+    assert!(SubnetEpochRewards::<Test>::contains_key(subnet_id, epoch - 2));
+    assert!(SubnetEpochRewards::<Test>::contains_key(subnet_id, epoch - 1));
+    assert_eq!(SubnetEpochRewardsPruneEpoch::<Test>::get(subnet_id), Some(epoch - 2));
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch + 1));
+
+    // --- The rest are pruned the next time the subnet is rewarded, up to the new cutoff
+    assert!(!SubnetEpochRewards::<Test>::contains_key(subnet_id, epoch - 2));
+    assert!(!SubnetEpochRewards::<Test>::contains_key(subnet_id, epoch - 1));
+    assert!(!SubnetEpochRewards::<Test>::contains_key(subnet_id, epoch));
+    assert_eq!(SubnetEpochRewardsPruneEpoch::<Test>::get(subnet_id), Some(epoch + 1));
+  });
+}
+
+#[test]
+fn test_subnet_rewards_preview() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Attested, |_| ());
+    let stakes_before = subnet_node_stakes(subnet_id, total_subnet_nodes);
+
+    let preview = Network::get_subnet_rewards_preview(subnet_id, epoch).unwrap();
+
+    // --- Nothing is rewarded by the preview
+    assert_eq!(subnet_node_stakes(subnet_id, total_subnet_nodes), stakes_before);
+    assert!(!SubnetEpochRewards::<Test>::contains_key(subnet_id, epoch));
+
+    assert_eq!(preview.subnet_nodes.len() as u32, total_subnet_nodes);
+    assert!(preview.slashes.is_empty());
+    assert!(preview.removed_subnet_nodes.is_empty());
+    assert!(!preview.subnet_removed);
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    let subnet_node_rewards: Vec<(u32, SubnetNodeRewardsReceipt<AccountIdOf<Test>>)> = 
+      SubnetNodeEpochRewards::<Test>::iter_prefix((subnet_id, epoch)).collect();
+
+    assert_eq!(preview.subnet, SubnetEpochRewards::<Test>::get(subnet_id, epoch));
+    assert_eq!(preview.subnet_nodes, subnet_node_rewards);
+    assert_eq!(preview.subnet_penalty_count, SubnetPenaltyCount::<Test>::get(subnet_id));
+  });
+}
+
+#[test]
+fn test_subnet_rewards_preview_no_submission() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let deposit_amount: u128 = 10000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+
+    build_activated_subnet(subnet_path.clone(), 0, 12, deposit_amount, stake_amount);
+
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+
+    let epoch_length = EpochLength::get();
+    let epoch = System::block_number() / epoch_length;
+
+    Network::do_epoch_preliminaries(System::block_number(), epoch, epoch_length);
+
+    let validator_id = SubnetRewardsValidator::<Test>::get(subnet_id, epoch).unwrap();
+
+    increase_epochs(1);
+
+    let preview = Network::get_subnet_rewards_preview(subnet_id, epoch).unwrap();
+
+    // --- Validator is slashed and penalized with the subnet
+    assert_eq!(preview.subnet, None);
+    assert!(preview.subnet_nodes.is_empty());
+    assert_eq!(preview.slashes.len(), 1);
+    assert_eq!(preview.slashes[0].0, validator_id);
+    assert!(preview.slashes[0].1 > 0);
+    assert_eq!(preview.penalties, vec![(validator_id, 1)]);
+    assert_eq!(preview.subnet_penalty_count, SubnetPenaltyCount::<Test>::get(subnet_id) + 1);
+  });
+}
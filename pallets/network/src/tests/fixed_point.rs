@@ -0,0 +1,221 @@
+use super::mock::*;
+use crate::inflation::Inflation;
+use crate::{
+  MaxSubnets,
+  MaxSubnetNodes,
+  TotalActiveSubnets,
+  TotalActiveNodes,
+  SubnetInflationFactor,
+  InflationAdjFactor,
+};
+use sp_runtime::{FixedPointNumber, FixedU128, traits::{One, Zero}};
+
+//
+//
+//
+//
+//
+//
+//
+// Fixed-point math
+//
+// Each fixed-point function is checked against the `f64` version it replaced.
+// Results must match within a relative tolerance of `Network::FIXED_POW_TOLERANCE` (0.0001%),
+// or an absolute tolerance of `ABS_TOLERANCE` (1e-12) for results close to zero.
+// Stake weights are integers in `PERCENTAGE_FACTOR` and match within `STAKE_WEIGHT_TOLERANCE`
+// units, from rounding down `isqrt` and `percent_div`.
+//
+//
+//
+//
+//
+//
+//
+
+const ABS_TOLERANCE: f64 = 1e-12;
+
+const STAKE_WEIGHT_TOLERANCE: f64 = 3.0;
+
+const SAMPLES: u32 = 1000;
+
+// --- Deterministic xorshift so samples are reproducible
+struct Rng(u64);
+
+impl Rng {
+  fn next(&mut self) -> u64 {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    self.0
+  }
+
+  fn next_f64(&mut self) -> f64 {
+    (self.next() >> 11) as f64 / (1u64 << 53) as f64
+  }
+}
+
+fn to_f64(x: FixedU128) -> f64 {
+  x.into_inner() as f64 / FixedU128::DIV as f64
+}
+
+fn from_f64(x: f64) -> FixedU128 {
+  FixedU128::from_inner((x * FixedU128::DIV as f64) as u128)
+}
+
+fn assert_within_tolerance(fixed: f64, float: f64) {
+  let tolerance: f64 = Network::FIXED_POW_TOLERANCE as f64 / Network::PERCENTAGE_FACTOR as f64;
+  let diff: f64 = (fixed - float).abs();
+  assert!(
+    diff <= float.abs() * tolerance || diff <= ABS_TOLERANCE,
+    "fixed {:?} float {:?} diff {:?}", fixed, float, diff
+  );
+}
+
+#[test]
+fn test_isqrt() {
+  new_test_ext().execute_with(|| {
+    assert_eq!(Network::isqrt(0), 0);
+    assert_eq!(Network::isqrt(1), 1);
+    assert_eq!(Network::isqrt(3), 1);
+    assert_eq!(Network::isqrt(4), 2);
+    assert_eq!(Network::isqrt(u128::MAX), u64::MAX as u128);
+
+    let mut rng = Rng(0x2545F4914F6CDD1D);
+    for _ in 0..SAMPLES {
+      let x: u128 = ((rng.next() as u128) << 64 | rng.next() as u128) >> (rng.next() % 128);
+      let root: u128 = Network::isqrt(x);
+
+      // --- Rounded down
+      assert!(root * root <= x);
+      assert!((root + 1).checked_mul(root + 1).map_or(true, |v| v > x));
+    }
+  });
+}
+
+#[test]
+fn test_fixed_sqrt_matches_float() {
+  new_test_ext().execute_with(|| {
+    let mut rng = Rng(0x9E3779B97F4A7C15);
+    for _ in 0..SAMPLES {
+      let x: f64 = rng.next_f64() * 1000.0;
+      let fixed: FixedU128 = Network::fixed_sqrt(from_f64(x));
+
+      assert_within_tolerance(to_f64(fixed), libm::sqrt(x));
+    }
+  });
+}
+
+#[test]
+fn test_fixed_pow_matches_float() {
+  new_test_ext().execute_with(|| {
+    assert_eq!(Network::fixed_pow(FixedU128::zero(), FixedU128::zero()), FixedU128::one());
+    assert_eq!(Network::fixed_pow(FixedU128::zero(), from_f64(0.5)), FixedU128::zero());
+    assert_eq!(Network::fixed_pow(from_f64(0.5), FixedU128::one()), from_f64(0.5));
+
+    let mut rng = Rng(0xD1B54A32D192ED03);
+    for _ in 0..SAMPLES {
+      let base: f64 = rng.next_f64();
+      let exp: f64 = rng.next_f64() * 10.0;
+      let fixed: FixedU128 = Network::fixed_pow(from_f64(base), from_f64(exp));
+
+      assert_within_tolerance(to_f64(fixed), libm::pow(base, exp));
+    }
+  });
+}
+
+#[test]
+fn test_inflation_total_matches_float() {
+  new_test_ext().execute_with(|| {
+    let inflation = Inflation::default();
+
+    let mut rng = Rng(0xA0761D6478BD642F);
+    for _ in 0..SAMPLES {
+      let year: f64 = rng.next_f64() * 100.0;
+      let fixed: FixedU128 = inflation.total::<Test>(from_f64(year));
+
+      let tapered: f64 = 0.1 * libm::pow(1.0 - 0.15, year);
+      let float: f64 = if tapered > 0.015 { tapered } else { 0.015 };
+
+      assert_within_tolerance(to_f64(fixed), float);
+    }
+  });
+}
+
+#[test]
+fn test_get_inflation_factor_matches_float() {
+  new_test_ext().execute_with(|| {
+    let k: f64 = InflationAdjFactor::<Test>::get() as f64 / Network::PERCENTAGE_FACTOR as f64;
+
+    assert_eq!(Network::get_inflation_factor(FixedU128::one()), FixedU128::one());
+
+    let mut rng = Rng(0xE7037ED1A0B428DB);
+    for _ in 0..SAMPLES {
+      let x: f64 = rng.next_f64();
+      let fixed: FixedU128 = Network::get_inflation_factor(from_f64(x));
+
+      assert_within_tolerance(to_f64(fixed), libm::pow(x, k).min(1.0));
+    }
+  });
+}
+
+#[test]
+fn test_get_epoch_emissions_matches_float() {
+  new_test_ext().execute_with(|| {
+    let max_subnets: u32 = MaxSubnets::<Test>::get();
+    let max_nodes: u32 = max_subnets * MaxSubnetNodes::<Test>::get();
+    let total_issuance: f64 = 19536777003861893185536.0;
+
+    let mut rng = Rng(0x8EBC6AF09C88C6E3);
+    for _ in 0..100 {
+      let epoch: u32 = (rng.next() % (EpochsPerYear::get() as u64 * 20)) as u32;
+      let total_active_nodes: u32 = (rng.next() % max_nodes as u64) as u32;
+      TotalActiveSubnets::<Test>::set((rng.next() % max_subnets as u64) as u32);
+      TotalActiveNodes::<Test>::set(total_active_nodes);
+
+      let emissions: u128 = Network::get_epoch_emissions(epoch);
+
+      // --- Previous `f64` implementation
+      let sif: f64 = SubnetInflationFactor::<Test>::get() as f64 / Network::PERCENTAGE_FACTOR as f64;
+      let snif: f64 = 1.0 - sif;
+      let node_utilization_rate: f64 = total_active_nodes as f64 / max_nodes as f64;
+      let x: f64 = snif + node_utilization_rate * snif;
+      let k: f64 = InflationAdjFactor::<Test>::get() as f64 / Network::PERCENTAGE_FACTOR as f64;
+      let inflation_factor: f64 = if x >= 1.0 { 1.0 } else { libm::pow(x, k).min(1.0) };
+
+      let year: f64 = epoch as f64 / EpochsPerYear::get() as f64;
+      let tapered: f64 = 0.1 * libm::pow(1.0 - 0.15, year);
+      let apr: f64 = if tapered > 0.015 { tapered } else { 0.015 };
+
+      let float: f64 = total_issuance * apr * inflation_factor;
+
+      assert_within_tolerance(emissions as f64, float);
+    }
+
+    // --- No subnet nodes were activated
+    TotalActiveSubnets::<Test>::set(0);
+    TotalActiveNodes::<Test>::set(0);
+  });
+}
+
+#[test]
+fn test_stake_weights_match_float() {
+  new_test_ext().execute_with(|| {
+    let mut rng = Rng(0x589965CC75374CC3);
+    for _ in 0..SAMPLES {
+      let stakes: Vec<u128> = (0..(rng.next() % 16 + 1))
+        .map(|_| (rng.next() as u128 % 1_000_000 + 1) * 1e18 as u128)
+        .collect();
+      let total_stake: u128 = stakes.iter().sum();
+
+      let sqrt_sum: u128 = stakes.iter().map(|x| Network::isqrt(*x)).sum();
+      let float_sqrt_sum: f64 = stakes.iter().map(|x| libm::sqrt(*x as f64 / total_stake as f64)).sum();
+
+      for stake in stakes.iter() {
+        let fixed: u128 = Network::percent_div(Network::isqrt(*stake), sqrt_sum);
+        let float: f64 = libm::sqrt(*stake as f64 / total_stake as f64) / float_sqrt_sum * Network::PERCENTAGE_FACTOR as f64;
+
+        assert!((fixed as f64 - float).abs() <= STAKE_WEIGHT_TOLERANCE, "fixed {:?} float {:?}", fixed, float);
+      }
+    }
+  });
+}
@@ -1288,6 +1288,7 @@ fn test_reward_subnets_v2_account_penalty_count() {
 //       node_registration_interval: 0,
 //       node_queue_period: 1,
       // coldkey_whitelist: Some(BTreeSet::new()),
+      // validator_args_type: None,
       // coldkey_whitelist: None,
 //     };
   
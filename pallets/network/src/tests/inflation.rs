@@ -3,6 +3,7 @@ use crate::tests::test_utils::*;
 use crate::Event;
 use log::info;
 use crate::inflation::Inflation;
+use sp_runtime::{FixedPointNumber, FixedU128, traits::Zero};
 // use crate::{
 //   EpochsPerYear,
 // };
@@ -29,11 +30,12 @@ fn test_inflation_total() {
 
     let inflation = Inflation::default();
 
-    let mut last = inflation.total(0.0);
+    let mut last = inflation.total::<Test>(FixedU128::zero());
 
-    for year in &[0.1, 0.5, 1.0, 2.0, 3.0, 4.0, 5.0, 100.0] {
+    for year in &[1, 5, 10, 20, 30, 40, 50, 1000] {
+      let year = FixedU128::checked_from_rational(*year, 10).unwrap();
       log::error!("test_inflation_total year {:?}", year);
-      let total = inflation.total(*year);
+      let total = inflation.total::<Test>(year);
       log::error!("test_inflation_total total {:?}", total);
       assert!(total < last);
       assert!(total >= inflation.terminal);
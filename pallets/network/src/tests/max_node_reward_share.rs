@@ -0,0 +1,270 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_ok, assert_err,
+};
+use crate::{
+  Error,
+  SubnetNodeData,
+  SubnetRewardsValidator,
+  SubnetPaths,
+  TotalSubnetNodes,
+  SubnetNodeIdHotkey,
+  MinStakeBalance,
+  MaxNodeRewardShare,
+  SubnetNodeEpochRewards,
+  SubnetEpochRewards,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Max node reward share
+//
+//
+//
+//
+//
+//
+//
+
+fn build_scored_subnet(subnet_path: Vec<u8>, max_node_reward_share: u128) -> (u32, u32, u32) {
+  let (subnet_id, total_subnet_nodes, epoch, validator_id) = build_epoch_subnet(
+    subnet_path,
+    12,
+    EpochSubnetStage::ValidatorChosen,
+    |subnet_id| {
+      assert_ok!(
+        Network::owner_update_max_node_reward_share(
+          RuntimeOrigin::signed(account(0)),
+          subnet_id,
+          max_node_reward_share,
+        )
+      );
+    },
+  );
+
+  let validator = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+  // --- The first subnet node is given an outsized score
+  let mut data: Vec<SubnetNodeData> = subnet_node_data(0, total_subnet_nodes);
+  data[0].score = DEFAULT_SCORE * 1000;
+
+  assert_ok!(
+    Network::validate(
+      RuntimeOrigin::signed(validator),
+      subnet_id,
+      data,
+      None,
+    )
+  );
+
+  attest_subnet(subnet_id, total_subnet_nodes, validator_id);
+
+  increase_epochs(1);
+
+  assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+  (subnet_id, total_subnet_nodes, epoch)
+}
+
+// Rewards to a subnet node from its score
+fn score_reward(subnet_id: u32, epoch: u32, subnet_node_id: u32) -> u128 {
+  let receipt = SubnetNodeEpochRewards::<Test>::get((subnet_id, epoch, subnet_node_id)).unwrap();
+  receipt.reward + receipt.node_delegate_reward - receipt.validator_reward
+}
+
+#[test]
+fn test_owner_update_max_node_reward_share() {
+  new_test_ext().execute_with(|| {
+    let deposit_amount: u128 = 10000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+    build_activated_subnet(subnet_path.clone(), 0, 12, deposit_amount, stake_amount);
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+
+    assert_eq!(MaxNodeRewardShare::<Test>::get(subnet_id), Network::PERCENTAGE_FACTOR);
+
+    assert_err!(
+      Network::owner_update_max_node_reward_share(RuntimeOrigin::signed(account(1)), subnet_id, 100000000),
+      Error::<Test>::NotSubnetOwner
+    );
+
+    assert_err!(
+      Network::owner_update_max_node_reward_share(RuntimeOrigin::signed(account(0)), subnet_id, 0),
+      Error::<Test>::InvalidMaxNodeRewardShare
+    );
+
+    assert_err!(
+      Network::owner_update_max_node_reward_share(
+        RuntimeOrigin::signed(account(0)), 
+        subnet_id, 
+        Network::PERCENTAGE_FACTOR + 1
+      ),
+      Error::<Test>::InvalidMaxNodeRewardShare
+    );
+
+    assert_ok!(
+      Network::owner_update_max_node_reward_share(RuntimeOrigin::signed(account(0)), subnet_id, 100000000)
+    );
+    assert_eq!(MaxNodeRewardShare::<Test>::get(subnet_id), 100000000);
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::SubnetMaxNodeRewardShareUpdate {
+        subnet_id: subnet_id,
+        owner: account(0),
+        value: 100000000,
+      }
+    );
+  });
+}
+
+#[test]
+fn test_get_max_node_score() {
+  new_test_ext().execute_with(|| {
+    // --- No cap
+    assert_eq!(
+      Network::get_max_node_score(&vec![10, 1, 1], Network::PERCENTAGE_FACTOR),
+      (u128::MAX, 12, 0)
+    );
+
+    // --- Under the cap
+    assert_eq!(
+      Network::get_max_node_score(&vec![1, 1, 1, 1], 500000000),
+      (2, 4, 0)
+    );
+
+    // --- Highest score is capped at 50% and the rest is redistributed
+    let (max_score, sum, excess_share) = Network::get_max_node_score(&vec![10, 1, 1], 500000000);
+    assert_eq!((max_score, sum, excess_share), (2, 4, 0));
+    assert_eq!(Network::percent_div(10u128.min(max_score), sum), 500000000);
+    assert_eq!(Network::percent_div(1u128.min(max_score), sum), 250000000);
+
+    // --- Scores of 0 are ignored
+    assert_eq!(
+      Network::get_max_node_score(&vec![10, 1, 1, 0], 500000000),
+      (2, 4, 0)
+    );
+
+    // --- Every subnet node is capped and scores the same, the excess can't be redistributed
+    let (max_score, sum, excess_share) = Network::get_max_node_score(&vec![10, 1], 400000000);
+    assert_eq!((max_score, sum), (1, 2));
+    assert_eq!(excess_share, 200000000);
+    assert_eq!(Network::percent_div(1u128.min(max_score), sum), 500000000);
+
+    assert_eq!(Network::get_max_node_score(&vec![], 400000000), (u128::MAX, 0, 0));
+  });
+}
+
+#[test]
+fn test_max_node_reward_share_caps_rewards() {
+  let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+  // --- Uncapped, the outsized score receives nearly all of the subnet node rewards
+  new_test_ext().execute_with(|| {
+    let (subnet_id, total_subnet_nodes, epoch) = build_scored_subnet(subnet_path.clone(), Network::PERCENTAGE_FACTOR);
+
+    let reward = score_reward(subnet_id, epoch, 1);
+    for n in 2..total_subnet_nodes+1 {
+      assert!(reward > score_reward(subnet_id, epoch, n) * 900);
+    }
+  });
+
+  // --- Capped at 20%, the other 80% is redistributed to the other 11 subnet nodes by score
+  new_test_ext().execute_with(|| {
+    let (subnet_id, total_subnet_nodes, epoch) = build_scored_subnet(subnet_path.clone(), 200000000);
+
+    let reward = score_reward(subnet_id, epoch, 1);
+    for n in 2..total_subnet_nodes+1 {
+      let other_reward = score_reward(subnet_id, epoch, n);
+      // 20% / (80% / 11)
+      let expected: u128 = other_reward * 11 / 4;
+      assert!(reward.abs_diff(expected) <= expected / 1_000_000);
+    }
+  });
+}
+
+#[test]
+fn test_max_node_reward_share_every_node_capped() {
+  let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+  // --- Rewards to the subnet nodes and the subnet delegate stakers when uncapped
+  let (uncapped_node_rewards, uncapped_total) = new_test_ext().execute_with(|| {
+    let (subnet_id, total_subnet_nodes, epoch) = build_scored_subnet(subnet_path.clone(), Network::PERCENTAGE_FACTOR);
+    let node_rewards: u128 = (1..total_subnet_nodes+1).map(|n| score_reward(subnet_id, epoch, n)).sum();
+    let receipt = SubnetEpochRewards::<Test>::get(subnet_id, epoch).unwrap();
+    (node_rewards, node_rewards + receipt.delegate_stake_reward)
+  });
+
+  // --- Capped at 5% each, 12 subnet nodes can only receive 60% and the other 40% goes to the delegate stakers
+  new_test_ext().execute_with(|| {
+    let (subnet_id, total_subnet_nodes, epoch) = build_scored_subnet(subnet_path.clone(), 50000000);
+
+    let expected: u128 = Network::percent_mul(uncapped_node_rewards, 50000000);
+    let mut node_rewards: u128 = 0;
+    for n in 1..total_subnet_nodes+1 {
+      let reward = score_reward(subnet_id, epoch, n);
+      assert!(reward.abs_diff(expected) <= expected / 1_000_000);
+      node_rewards += reward;
+    }
+
+    // --- Nothing is lost
+    let receipt = SubnetEpochRewards::<Test>::get(subnet_id, epoch).unwrap();
+    let total: u128 = node_rewards + receipt.delegate_stake_reward;
+    assert!(total.abs_diff(uncapped_total) <= uncapped_total / 1_000_000);
+  });
+}
+
+#[test]
+fn test_validate_score_sum_overflow() {
+  new_test_ext().execute_with(|| {
+    let deposit_amount: u128 = 10000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+    build_activated_subnet(subnet_path.clone(), 0, 12, deposit_amount, stake_amount);
+
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+    let epoch_length = EpochLength::get();
+    let epoch = System::block_number() / epoch_length;
+
+    Network::do_epoch_preliminaries(System::block_number(), epoch, epoch_length);
+
+    let validator_id = SubnetRewardsValidator::<Test>::get(subnet_id, epoch).unwrap();
+    let validator = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+    let mut data: Vec<SubnetNodeData> = subnet_node_data(0, total_subnet_nodes);
+    data[0].score = u128::MAX;
+
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(validator.clone()),
+        subnet_id,
+        data.clone(),
+        None,
+      ),
+      Error::<Test>::InvalidScoreSum
+    );
+
+    data[0].score = Network::MAX_SCORE_SUM;
+
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(validator),
+        subnet_id,
+        data,
+        None,
+      ),
+      Error::<Test>::InvalidScoreSum
+    );
+  });
+}
@@ -0,0 +1,209 @@
+use super::mock::*;
+use super::test_utils::*;
+use codec::Encode;
+use frame_support::{
+  assert_ok,
+  storage::unhashed,
+  traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_std::collections::btree_map::BTreeMap;
+use crate::migrations::v1::{v0, MigrateV0ToV1};
+use crate::migrations::v2::MigrateV1ToV2;
+use crate::{
+  SubnetsData,
+  SubnetData,
+  SubnetState,
+  SubnetRegistrationEpoch,
+  SubnetRewardsSubmission,
+  RewardsData,
+  SubnetNodeData,
+  HotkeySubnetNodeId,
+  SubnetNodeIdHotkey,
+  AccountSubnetStake,
+  AccountSubnetDelegateStakeShares,
+  AccountNodeDelegateStakeShares,
+  SubnetStakeAccounts,
+  SubnetDelegateStakeAccounts,
+  SubnetNodeDelegateStakeAccounts,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Storage migrations
+//
+//
+//
+//
+//
+//
+//
+
+fn v0_subnet_data(subnet_id: u32, state: SubnetState, registered: u32) -> v0::SubnetData {
+  v0::SubnetData {
+    id: subnet_id,
+    path: format!("petals-team/StableBeluga{subnet_id}").into(),
+    state: state,
+    registered: registered,
+  }
+}
+
+fn v0_rewards_data(validator: u32, attestors: Vec<u32>) -> v0::RewardsData<AccountIdOf<Test>> {
+  let mut attests: BTreeMap<AccountIdOf<Test>, u64> = BTreeMap::new();
+  for n in attestors {
+    attests.insert(account(n), n as u64 + 100);
+  }
+
+  v0::RewardsData {
+    validator: account(validator),
+    attests: attests,
+    data: subnet_node_data(0, 2),
+    args: None,
+  }
+}
+
+#[test]
+fn test_storage_version() {
+  new_test_ext().execute_with(|| {
+    assert_eq!(Network::in_code_storage_version(), 2);
+  });
+}
+
+#[test]
+fn test_migrate_v0_subnets_data() {
+  new_test_ext().execute_with(|| {
+    StorageVersion::new(0).put::<Network>();
+
+    unhashed::put(&SubnetsData::<Test>::hashed_key_for(1), &v0_subnet_data(1, SubnetState::Registered, 5));
+    unhashed::put(&SubnetsData::<Test>::hashed_key_for(2), &v0_subnet_data(2, SubnetState::Active, 3));
+
+    // --- Already in the current layout
+    let subnet_data = SubnetData {
+      id: 3,
+      path: "petals-team/StableBeluga3".into(),
+      state: SubnetState::Active,
+    };
+    SubnetsData::<Test>::insert(3, &subnet_data);
+
+    MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+    assert_eq!(Network::on_chain_storage_version(), 1);
+
+    assert_eq!(
+      SubnetsData::<Test>::get(1),
+      Some(SubnetData {
+        id: 1,
+        path: "petals-team/StableBeluga1".into(),
+        state: SubnetState::Registered,
+      })
+    );
+    assert_eq!(SubnetRegistrationEpoch::<Test>::get(1), Some(5));
+
+    // --- Active subnets no longer need the registration epoch
+    assert_eq!(SubnetsData::<Test>::get(2).unwrap().state, SubnetState::Active);
+    assert_eq!(SubnetRegistrationEpoch::<Test>::get(2), None);
+
+    assert_eq!(SubnetsData::<Test>::get(3), Some(subnet_data));
+  });
+}
+
+#[test]
+fn test_migrate_v0_rewards_submissions() {
+  new_test_ext().execute_with(|| {
+    StorageVersion::new(0).put::<Network>();
+
+    let subnet_id: u32 = 1;
+    for n in 1..3 {
+      HotkeySubnetNodeId::<Test>::insert(subnet_id, account(n), n);
+      SubnetNodeIdHotkey::<Test>::insert(subnet_id, n, account(n));
+    }
+
+    // --- Attestor 3 is no longer a subnet node
+    unhashed::put(
+      &SubnetRewardsSubmission::<Test>::hashed_key_for(subnet_id, 0),
+      &v0_rewards_data(1, vec![1, 2, 3]),
+    );
+    // --- Validator is no longer a subnet node
+    unhashed::put(
+      &SubnetRewardsSubmission::<Test>::hashed_key_for(subnet_id, 1),
+      &v0_rewards_data(3, vec![1, 2]),
+    );
+
+    MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+    let mut attests: BTreeMap<u32, u32> = BTreeMap::new();
+    attests.insert(1, 101);
+    attests.insert(2, 102);
+
+    assert_eq!(
+      SubnetRewardsSubmission::<Test>::get(subnet_id, 0),
+      Some(RewardsData {
+        validator_id: 1,
+        attests: attests,
+        data: subnet_node_data(0, 2),
+        args: None,
+      })
+    );
+    assert!(!SubnetRewardsSubmission::<Test>::contains_key(subnet_id, 1));
+  });
+}
+
+#[test]
+fn test_migrate_v0_to_v1_runs_once() {
+  new_test_ext().execute_with(|| {
+    StorageVersion::new(1).put::<Network>();
+
+    let key = SubnetsData::<Test>::hashed_key_for(1);
+    let subnet_data = v0_subnet_data(1, SubnetState::Registered, 5);
+    unhashed::put(&key, &subnet_data);
+
+    MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+    assert_eq!(unhashed::get_raw(&key), Some(subnet_data.encode()));
+    assert_eq!(SubnetRegistrationEpoch::<Test>::get(1), None);
+  });
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn test_migrate_v0_to_v1_try_runtime() {
+  new_test_ext().execute_with(|| {
+    StorageVersion::new(0).put::<Network>();
+
+    unhashed::put(&SubnetsData::<Test>::hashed_key_for(1), &v0_subnet_data(1, SubnetState::Registered, 5));
+    HotkeySubnetNodeId::<Test>::insert(1, account(1), 1);
+    SubnetNodeIdHotkey::<Test>::insert(1, 1, account(1));
+    unhashed::put(
+      &SubnetRewardsSubmission::<Test>::hashed_key_for(1, 0),
+      &v0_rewards_data(1, vec![1]),
+    );
+
+    assert_ok!(MigrateV0ToV1::<Test>::try_on_runtime_upgrade(true));
+    assert_eq!(Network::on_chain_storage_version(), 1);
+  });
+}
+
+#[test]
+fn test_migrate_v1_to_v2_indexes_positions() {
+  new_test_ext().execute_with(|| {
+    StorageVersion::new(1).put::<Network>();
+
+    AccountSubnetStake::<Test>::insert(account(1), 1, 100);
+    AccountSubnetStake::<Test>::insert(account(2), 2, 0);
+    AccountSubnetDelegateStakeShares::<Test>::insert(account(3), 1, 100);
+    AccountNodeDelegateStakeShares::<Test>::insert((account(4), 1, 2), 100);
+
+    MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+    assert_eq!(Network::on_chain_storage_version(), 2);
+
+    assert!(SubnetStakeAccounts::<Test>::contains_key(1, account(1)));
+    assert!(!SubnetStakeAccounts::<Test>::contains_key(2, account(2)));
+    assert!(SubnetDelegateStakeAccounts::<Test>::contains_key(1, account(3)));
+    assert!(SubnetNodeDelegateStakeAccounts::<Test>::contains_key((1, account(4), 2)));
+  });
+}
@@ -238,15 +238,33 @@ impl Config for Test {
   type MaxStakeUnlockings = MaxStakeUnlockings;
   type MinProposalStake = MinProposalStake;
   type TreasuryAccount = TreasuryAccount;
+  type RewardPolicy = pallet_network::SqrtDelegateStakePolicy;
 }
 
-pub fn new_test_ext() -> sp_io::TestExternalities {
+pub fn build_test_ext() -> sp_io::TestExternalities {
 	frame_system::GenesisConfig::<Test>::default()
 		.build_storage()
 		.unwrap()
 		.into()
 }
 
+/// Test externalities that check the pallet's `try_state` invariants after each test
+pub struct TestExternalities(sp_io::TestExternalities);
+
+impl TestExternalities {
+	pub fn execute_with<R>(&mut self, execute: impl FnOnce() -> R) -> R {
+		self.0.execute_with(|| {
+			let result = execute();
+			Network::do_try_state().unwrap();
+			result
+		})
+	}
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	TestExternalities(build_test_ext())
+}
+
 pub(crate) fn network_events() -> Vec<crate::Event<Test>> {
 	System::events()
 		.into_iter()
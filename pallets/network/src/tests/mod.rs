@@ -1,9 +1,29 @@
-mod mock;
+pub mod mock;
 mod test_utils;
 // mod subnet;
 // mod subnet_node;
 // mod staking;
 mod delegate_staking;
+mod commit_reveal;
+mod validator_committee;
+mod validator_selection;
+mod backup_validator;
+mod fixed_point;
+mod epoch_rewards;
+mod migrations;
+mod try_state;
+mod reward_policy;
+mod max_node_reward_share;
+mod rewards_submission;
+mod dissent;
+mod validator_args;
+mod rebond;
+mod transfer_stake;
+mod node_delegate_slash;
+mod subnet_unbonding;
+mod subnet_removal;
+mod delegate_stake_token;
+mod transfer_delegate_stake_shares;
 // mod node_delegate_staking;
 // mod incentives_protocol;
 // mod proposals;
@@ -0,0 +1,133 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_ok, assert_err,
+};
+use sp_runtime::DispatchError;
+use crate::{
+  Error,
+  HotkeySubnetNodeId,
+  SlashPercentage,
+  NodeDelegateSlashShare,
+  TotalNodeDelegateStakeBalance,
+  TotalNodeDelegateStakeShares,
+  AccountSubnetStake,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Node delegate slash share
+//
+//
+//
+//
+//
+//
+//
+
+fn build_node_delegated_subnet(amount: u128) -> (u32, u32) {
+  let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+  let (subnet_id, total_subnet_nodes, _, _) = build_epoch_subnet(subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+  let subnet_node_id = HotkeySubnetNodeId::<Test>::get(subnet_id, account(1)).unwrap();
+
+  let delegator = account(total_subnet_nodes+1);
+  let _ = Balances::deposit_creating(&delegator, amount + 500);
+
+  assert_ok!(
+    Network::add_to_node_delegate_stake(
+      RuntimeOrigin::signed(delegator),
+      subnet_id,
+      subnet_node_id,
+      amount,
+    )
+  );
+
+  (subnet_id, subnet_node_id)
+}
+
+#[test]
+fn test_slash_validator_without_node_delegate_slash_share() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, subnet_node_id) = build_node_delegated_subnet(amount);
+
+    let node_delegate_stake_balance = TotalNodeDelegateStakeBalance::<Test>::get(subnet_id, subnet_node_id);
+
+    Network::slash_validator(subnet_id, subnet_node_id, 0, System::block_number());
+
+    assert_eq!(TotalNodeDelegateStakeBalance::<Test>::get(subnet_id, subnet_node_id), node_delegate_stake_balance);
+
+    assert!(network_events().iter().any(|event| matches!(
+      event,
+      Event::Slashing { subnet_id: id, node_delegate_amount: 0, .. } if *id == subnet_id
+    )));
+  });
+}
+
+#[test]
+fn test_slash_validator_with_node_delegate_slash_share() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, subnet_node_id) = build_node_delegated_subnet(amount);
+
+    assert_ok!(Network::do_set_node_delegate_slash_share(500000000));
+
+    let stake_balance = AccountSubnetStake::<Test>::get(account(1), subnet_id);
+    let node_delegate_stake_balance = TotalNodeDelegateStakeBalance::<Test>::get(subnet_id, subnet_node_id);
+    let node_delegate_stake_shares = TotalNodeDelegateStakeShares::<Test>::get(subnet_id, subnet_node_id);
+
+    Network::slash_validator(subnet_id, subnet_node_id, 0, System::block_number());
+
+    let slash_amount = Network::percent_mul(stake_balance, SlashPercentage::<Test>::get());
+    let node_delegate_slash_amount = Network::percent_mul(
+      Network::percent_mul(node_delegate_stake_balance, SlashPercentage::<Test>::get()),
+      NodeDelegateSlashShare::<Test>::get()
+    );
+    assert!(node_delegate_slash_amount > 0);
+
+    assert_eq!(AccountSubnetStake::<Test>::get(account(1), subnet_id), stake_balance - slash_amount);
+    assert_eq!(
+      TotalNodeDelegateStakeBalance::<Test>::get(subnet_id, subnet_node_id),
+      node_delegate_stake_balance - node_delegate_slash_amount
+    );
+    // --- Shares are untouched so the share price drops
+    assert_eq!(TotalNodeDelegateStakeShares::<Test>::get(subnet_id, subnet_node_id), node_delegate_stake_shares);
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::Slashing {
+        subnet_id: subnet_id,
+        account_id: account(1),
+        amount: slash_amount,
+        node_delegate_amount: node_delegate_slash_amount,
+      }
+    );
+  });
+}
+
+#[test]
+fn test_set_node_delegate_slash_share() {
+  new_test_ext().execute_with(|| {
+    assert_err!(
+      Network::set_node_delegate_slash_share(
+        RuntimeOrigin::signed(account(1)),
+        500000000,
+      ),
+      DispatchError::BadOrigin
+    );
+
+    assert_err!(
+      Network::do_set_node_delegate_slash_share(Network::PERCENTAGE_FACTOR + 1),
+      Error::<Test>::InvalidPercent
+    );
+
+    assert_ok!(Network::do_set_node_delegate_slash_share(Network::PERCENTAGE_FACTOR));
+    assert_eq!(NodeDelegateSlashShare::<Test>::get(), Network::PERCENTAGE_FACTOR);
+  });
+}
@@ -0,0 +1,368 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_ok, assert_err,
+};
+use sp_std::collections::btree_map::BTreeMap;
+use crate::{
+  Error,
+  HotkeySubnetNodeId,
+  MinStakeBalance,
+  MaxStakeBalance,
+  StakeUnbondingLedger,
+  StakeUnbondingOrigins,
+  UnbondingKind,
+  DelegateStakeUnbondingLedger,
+  AccountSubnetDelegateStakeShares,
+  TotalSubnetDelegateStakeShares,
+  TotalSubnetDelegateStakeBalance,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Rebond
+//
+//
+//
+//
+//
+//
+//
+
+fn build_unbonding_stake(subnet_path: Vec<u8>, amount: u128) -> u32 {
+  let (subnet_id, _, _, _) = build_epoch_subnet(subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+  let _ = Balances::deposit_creating(&account(1), amount + 500);
+
+  let subnet_node_id = HotkeySubnetNodeId::<Test>::get(subnet_id, account(1)).unwrap();
+
+  assert_ok!(
+    Network::add_to_stake(
+      RuntimeOrigin::signed(account(1)),
+      subnet_id,
+      subnet_node_id,
+      account(1),
+      amount,
+    )
+  );
+
+  assert_ok!(
+    Network::remove_stake(
+      RuntimeOrigin::signed(account(1)),
+      subnet_id,
+      account(1),
+      amount,
+    )
+  );
+
+  subnet_id
+}
+
+#[test]
+fn test_rebond() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+    let subnet_id = build_unbonding_stake(subnet_path.clone(), amount);
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(account(1));
+    assert_eq!(unbondings.values().sum::<u128>(), amount);
+
+    assert_ok!(
+      Network::rebond(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        account(1),
+        amount / 2,
+      )
+    );
+
+    assert_eq!(Network::account_subnet_stake(account(1), subnet_id), stake_amount + amount / 2);
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(account(1));
+    assert_eq!(unbondings.values().sum::<u128>(), amount - amount / 2);
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::StakeRebonded(subnet_id, account(1), account(1), amount / 2)
+    );
+
+    assert_ok!(
+      Network::rebond(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        account(1),
+        amount - amount / 2,
+      )
+    );
+
+    assert_eq!(Network::account_subnet_stake(account(1), subnet_id), stake_amount + amount);
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(account(1));
+    assert_eq!(unbondings.len(), 0);
+  });
+}
+
+#[test]
+fn test_rebond_not_enough_unbonding_balance() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+    let subnet_id = build_unbonding_stake(subnet_path.clone(), amount);
+
+    assert_err!(
+      Network::rebond(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        account(1),
+        amount + 1,
+      ),
+      Error::<Test>::NotEnoughUnbondingBalance
+    );
+
+    assert_err!(
+      Network::rebond(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        account(1),
+        0,
+      ),
+      Error::<Test>::NotEnoughUnbondingBalance
+    );
+
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(account(1));
+    assert_eq!(unbondings.values().sum::<u128>(), amount);
+  });
+}
+
+#[test]
+fn test_rebond_max_stake_reached() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+    let subnet_id = build_unbonding_stake(subnet_path.clone(), amount);
+
+    MaxStakeBalance::<Test>::set(Network::account_subnet_stake(account(1), subnet_id) + amount / 2);
+
+    assert_err!(
+      Network::rebond(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        account(1),
+        amount,
+      ),
+      Error::<Test>::MaxStakeReached
+    );
+
+    assert_ok!(
+      Network::rebond(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        account(1),
+        amount / 2,
+      )
+    );
+  });
+}
+
+#[test]
+fn test_rebond_not_key_owner() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+    let subnet_id = build_unbonding_stake(subnet_path.clone(), amount);
+
+    assert_err!(
+      Network::rebond(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        account(2),
+        amount,
+      ),
+      Error::<Test>::NotKeyOwner
+    );
+  });
+}
+
+#[test]
+fn test_take_from_unbonding_ledger_latest_first() {
+  new_test_ext().execute_with(|| {
+    let mut ledger: BTreeMap<u32, u128> = BTreeMap::new();
+    ledger.insert(1, 100);
+    ledger.insert(2, 200);
+    ledger.insert(3, 300);
+
+    assert!(!Network::take_from_unbonding_ledger(&mut ledger, 601));
+    assert_eq!(ledger.len(), 3);
+
+    assert!(Network::take_from_unbonding_ledger(&mut ledger, 400));
+    assert_eq!(ledger.get(&3), None);
+    assert_eq!(ledger.get(&2), Some(&100));
+    assert_eq!(ledger.get(&1), Some(&100));
+  });
+}
+
+#[test]
+fn test_rebond_delegate_stake() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+
+    let (subnet_id, total_subnet_nodes, _, _) = build_epoch_subnet(subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+    let delegator = account(total_subnet_nodes+1);
+
+    let _ = Balances::deposit_creating(&delegator, amount + 500);
+
+    assert_ok!(
+      Network::add_to_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        amount,
+      )
+    );
+
+    let delegate_shares = AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id);
+
+    assert_ok!(
+      Network::remove_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        delegate_shares,
+      )
+    );
+
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id), 0);
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(delegator.clone());
+    let unbonding_balance: u128 = unbondings.values().sum();
+
+    assert_ok!(
+      Network::rebond_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        unbonding_balance,
+      )
+    );
+
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(delegator.clone());
+    assert_eq!(unbondings.len(), 0);
+
+    let delegate_shares = AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id);
+    let delegate_balance = Network::convert_to_balance(
+      delegate_shares,
+      TotalSubnetDelegateStakeShares::<Test>::get(subnet_id),
+      TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id)
+    );
+    assert!(
+      (delegate_balance >= Network::percent_mul(unbonding_balance, 990000000)) &&
+      (delegate_balance <= unbonding_balance)
+    );
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::SubnetDelegateStakeRebonded(subnet_id, delegator.clone(), unbonding_balance)
+    );
+  });
+}
+
+#[test]
+fn test_rebond_delegate_stake_takes_subnet_ledger_first() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+
+    let (subnet_id, total_subnet_nodes, _, _) = build_epoch_subnet(subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+    let delegator = account(total_subnet_nodes+1);
+
+    let claim_epoch = get_epoch() + 10;
+    DelegateStakeUnbondingLedger::<Test>::insert(delegator.clone(), subnet_id, BTreeMap::from([(claim_epoch, amount)]));
+    StakeUnbondingLedger::<Test>::insert(delegator.clone(), BTreeMap::from([(claim_epoch, amount)]));
+    StakeUnbondingOrigins::<Test>::insert(
+      delegator.clone(),
+      BTreeMap::from([((subnet_id, UnbondingKind::DelegateStake), BTreeMap::from([(claim_epoch, amount)]))])
+    );
+
+    assert_err!(
+      Network::rebond_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        amount * 2 + 1,
+      ),
+      Error::<Test>::NotEnoughUnbondingBalance
+    );
+
+    assert_ok!(
+      Network::rebond_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        amount + amount / 2,
+      )
+    );
+
+    assert!(!DelegateStakeUnbondingLedger::<Test>::contains_key(delegator.clone(), subnet_id));
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(delegator.clone());
+    assert_eq!(unbondings.get(&claim_epoch), Some(&(amount - amount / 2)));
+  });
+}
+
+#[test]
+fn test_rebond_other_subnet() {
+  new_test_ext().execute_with(|| {
+    let other_subnet_path: Vec<u8> = "petals-team/StableBeluga".into();
+    let (other_subnet_id, _, _, _) = build_epoch_subnet(other_subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+    let subnet_id = build_unbonding_stake(subnet_path.clone(), amount);
+
+    // --- Unbonding stake of ``subnet_id`` can't skip the cooldown into another subnet
+    assert_err!(
+      Network::rebond(
+        RuntimeOrigin::signed(account(1)),
+        other_subnet_id,
+        account(1),
+        amount,
+      ),
+      Error::<Test>::NotEnoughUnbondingBalance
+    );
+
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(account(1));
+    assert_eq!(unbondings.values().sum::<u128>(), amount);
+
+    assert_ok!(
+      Network::rebond(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        account(1),
+        amount,
+      )
+    );
+
+    assert!(!StakeUnbondingOrigins::<Test>::contains_key(account(1)));
+  });
+}
+
+#[test]
+fn test_rebond_delegate_stake_from_stake() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+    let subnet_id = build_unbonding_stake(subnet_path.clone(), amount);
+
+    // --- Unbonding stake can't be rebonded as delegate stake
+    assert_err!(
+      Network::rebond_delegate_stake(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        amount,
+      ),
+      Error::<Test>::NotEnoughUnbondingBalance
+    );
+
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(account(1));
+    assert_eq!(unbondings.values().sum::<u128>(), amount);
+  });
+}
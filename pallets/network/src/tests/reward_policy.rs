@@ -0,0 +1,176 @@
+use super::mock::*;
+use super::test_utils::*;
+use frame_support::{
+	assert_ok,
+};
+use crate::{
+  SubnetPaths,
+  MinStakeBalance,
+  TotalSubnetDelegateStakeBalance,
+  TotalActiveSubnetNodes,
+  SubnetOwnerPercentage,
+  DelegateStakeRewardsPercentage,
+  RewardPolicy,
+  SubnetRewardsSplit,
+  SqrtDelegateStakePolicy,
+  LinearDelegateStakePolicy,
+  EqualSplitPolicy,
+  ActiveSubnetNodesPolicy,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Reward policies
+//
+//
+//
+//
+//
+//
+//
+
+// Build two subnets with different delegate stake balances and subnet node counts
+fn build_reward_policy_subnets() -> (u32, u32) {
+  let deposit_amount: u128 = 10000000000000000000000;
+  let stake_amount: u128 = MinStakeBalance::<Test>::get();
+
+  let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+  build_activated_subnet(subnet_path.clone(), 0, 12, deposit_amount, stake_amount);
+  let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+
+  let subnet_path_2: Vec<u8> = "petals-team/StableBeluga3".into();
+  build_activated_subnet(subnet_path_2.clone(), 12, 30, deposit_amount, stake_amount);
+  let subnet_id_2 = SubnetPaths::<Test>::get(subnet_path_2.clone()).unwrap();
+
+  let delegate_stake_amount: u128 = 1000000000000000000000;
+  let _ = Balances::deposit_creating(&account(2000), delegate_stake_amount+500);
+  assert_ok!(
+    Network::add_to_delegate_stake(
+      RuntimeOrigin::signed(account(2000)),
+      subnet_id_2,
+      delegate_stake_amount,
+    ) 
+  );
+
+  (subnet_id, subnet_id_2)
+}
+
+fn get_weight(weights: &Vec<(u32, u128)>, subnet_id: u32) -> u128 {
+  weights.iter().find(|(id, _)| *id == subnet_id).unwrap().1
+}
+
+#[test]
+fn test_sqrt_delegate_stake_policy_weights() {
+  new_test_ext().execute_with(|| {
+    let (subnet_id, subnet_id_2) = build_reward_policy_subnets();
+
+    let delegate_stake = TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id);
+    let delegate_stake_2 = TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id_2);
+    assert!(delegate_stake < delegate_stake_2);
+
+    let weight_sqrt = Network::isqrt(delegate_stake);
+    let weight_sqrt_2 = Network::isqrt(delegate_stake_2);
+
+    let weights = Network::get_subnet_weights::<SqrtDelegateStakePolicy>();
+    assert_eq!(weights.len(), 2);
+    assert_eq!(get_weight(&weights, subnet_id), Network::percent_div(weight_sqrt, weight_sqrt + weight_sqrt_2));
+    assert_eq!(get_weight(&weights, subnet_id_2), Network::percent_div(weight_sqrt_2, weight_sqrt + weight_sqrt_2));
+
+    // --- The mock runtime uses the square root policy
+    assert_eq!(Network::get_subnet_stake_weights(), weights);
+  });
+}
+
+#[test]
+fn test_linear_delegate_stake_policy_weights() {
+  new_test_ext().execute_with(|| {
+    let (subnet_id, subnet_id_2) = build_reward_policy_subnets();
+
+    let delegate_stake = TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id);
+    let delegate_stake_2 = TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id_2);
+
+    let weights = Network::get_subnet_weights::<LinearDelegateStakePolicy>();
+    assert_eq!(get_weight(&weights, subnet_id), Network::percent_div(delegate_stake, delegate_stake + delegate_stake_2));
+    assert_eq!(get_weight(&weights, subnet_id_2), Network::percent_div(delegate_stake_2, delegate_stake + delegate_stake_2));
+
+    // --- Linear weights favor the larger subnet more than square root weights
+    let sqrt_weights = Network::get_subnet_weights::<SqrtDelegateStakePolicy>();
+    assert!(get_weight(&weights, subnet_id_2) > get_weight(&sqrt_weights, subnet_id_2));
+  });
+}
+
+#[test]
+fn test_equal_split_policy_weights() {
+  new_test_ext().execute_with(|| {
+    let (subnet_id, subnet_id_2) = build_reward_policy_subnets();
+
+    let weights = Network::get_subnet_weights::<EqualSplitPolicy>();
+    assert_eq!(get_weight(&weights, subnet_id), Network::PERCENTAGE_FACTOR / 2);
+    assert_eq!(get_weight(&weights, subnet_id_2), Network::PERCENTAGE_FACTOR / 2);
+  });
+}
+
+#[test]
+fn test_active_subnet_nodes_policy_weights() {
+  new_test_ext().execute_with(|| {
+    let (subnet_id, subnet_id_2) = build_reward_policy_subnets();
+
+    let active_subnet_nodes = TotalActiveSubnetNodes::<Test>::get(subnet_id) as u128;
+    let active_subnet_nodes_2 = TotalActiveSubnetNodes::<Test>::get(subnet_id_2) as u128;
+    assert!(active_subnet_nodes < active_subnet_nodes_2);
+
+    let weights = Network::get_subnet_weights::<ActiveSubnetNodesPolicy>();
+    assert_eq!(
+      get_weight(&weights, subnet_id), 
+      Network::percent_div(active_subnet_nodes, active_subnet_nodes + active_subnet_nodes_2)
+    );
+    assert_eq!(
+      get_weight(&weights, subnet_id_2), 
+      Network::percent_div(active_subnet_nodes_2, active_subnet_nodes + active_subnet_nodes_2)
+    );
+  });
+}
+
+#[test]
+fn test_no_active_subnets_weights() {
+  new_test_ext().execute_with(|| {
+    assert!(Network::get_subnet_weights::<EqualSplitPolicy>().is_empty());
+    assert!(Network::get_subnet_stake_weights().is_empty());
+  });
+}
+
+#[test]
+fn test_default_subnet_rewards_split() {
+  new_test_ext().execute_with(|| {
+    let overall_subnet_reward: u128 = 1000000000000000000000;
+
+    let subnet_owner_reward = Network::percent_mul(overall_subnet_reward, SubnetOwnerPercentage::<Test>::get());
+    let subnet_reward = overall_subnet_reward - subnet_owner_reward;
+    let delegate_stake_reward = Network::percent_mul(subnet_reward, DelegateStakeRewardsPercentage::<Test>::get());
+
+    let split: SubnetRewardsSplit = <SqrtDelegateStakePolicy as RewardPolicy<Test>>::subnet_rewards_split(1, overall_subnet_reward);
+    assert_eq!(
+      split, 
+      SubnetRewardsSplit {
+        subnet_owner_reward: subnet_owner_reward,
+        delegate_stake_reward: delegate_stake_reward,
+        subnet_node_reward: subnet_reward - delegate_stake_reward,
+      }
+    );
+    assert_eq!(split.subnet_owner_reward + split.delegate_stake_reward + split.subnet_node_reward, overall_subnet_reward);
+
+    // --- Policies only differ in subnet weights
+    assert_eq!(<EqualSplitPolicy as RewardPolicy<Test>>::subnet_rewards_split(1, overall_subnet_reward), split);
+
+    assert_eq!(
+      <LinearDelegateStakePolicy as RewardPolicy<Test>>::subnet_node_percentage(1, 4), 
+      Network::PERCENTAGE_FACTOR / 4
+    );
+    assert_eq!(<LinearDelegateStakePolicy as RewardPolicy<Test>>::subnet_node_percentage(1, 0), 0);
+  });
+}
@@ -0,0 +1,190 @@
+use super::mock::*;
+use super::test_utils::*;
+use frame_support::{
+	assert_ok, assert_err,
+};
+use sp_std::collections::btree_map::BTreeMap;
+use crate::{
+  Error,
+  SubnetNodeData,
+  SubnetRewardsValidator,
+  SubnetRewardsSubmission,
+  MaxSubnetNodes,
+  RewardsData,
+  RewardsSubmissionHistoryEpochs,
+  SubnetRewardsDissents,
+  SubnetRewardsSubmissionPruneEpoch,
+  SubnetValidatorCommittee,
+  SubnetBackupValidators,
+  SubnetValidatorSelectionProof,
+  SubnetCommitteeSubmissions,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Rewards submissions
+//
+//
+//
+//
+//
+//
+//
+
+#[test]
+fn test_validate_duplicate_rewards_data() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, validator_id) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |_| ());
+    let validator = account(validator_id);
+
+    // --- Duplicates that aren't adjacent
+    let mut data: Vec<SubnetNodeData> = subnet_node_data(0, total_subnet_nodes);
+    data.push(data[0].clone());
+
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(validator.clone()),
+        subnet_id,
+        data,
+        None,
+      ),
+      Error::<Test>::DuplicateRewardsData
+    );
+
+    assert_ok!(
+      Network::validate(
+        RuntimeOrigin::signed(validator),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      )
+    );
+
+    let submission = SubnetRewardsSubmission::<Test>::get(subnet_id, epoch).unwrap();
+    assert_eq!(submission.data.len() as u32, total_subnet_nodes);
+  });
+}
+
+#[test]
+fn test_validate_rewards_data_over_max_subnet_nodes() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, _, _, validator_id) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |_| ());
+    let validator = account(validator_id);
+
+    let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(validator),
+        subnet_id,
+        subnet_node_data(0, max_subnet_nodes + 1),
+        None,
+      ),
+      Error::<Test>::InvalidRewardsDataLength
+    );
+  });
+}
+
+#[test]
+fn test_set_rewards_submission_history_epochs() {
+  new_test_ext().execute_with(|| {
+    assert_err!(
+      Network::do_set_rewards_submission_history_epochs(1),
+      Error::<Test>::InvalidRewardsSubmissionHistoryEpochs
+    );
+
+    assert_ok!(Network::do_set_rewards_submission_history_epochs(2));
+    assert_eq!(RewardsSubmissionHistoryEpochs::<Test>::get(), 2);
+  });
+}
+
+#[test]
+fn test_prune_rewards_submissions() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, validator_id) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |_| ());
+    let validator = account(validator_id);
+
+    assert_ok!(
+      Network::validate(
+        RuntimeOrigin::signed(validator),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      )
+    );
+
+    assert_ok!(Network::do_set_rewards_submission_history_epochs(2));
+    SubnetRewardsSubmission::<Test>::insert(subnet_id, epoch - 2, RewardsData::default());
+    SubnetRewardsValidator::<Test>::insert(subnet_id, epoch - 2, 1);
+
+    increase_epochs(1);
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    // --- Submissions older than the history are pruned when the subnet is rewarded
+    assert!(!SubnetRewardsSubmission::<Test>::contains_key(subnet_id, epoch - 2));
+    assert!(!SubnetRewardsValidator::<Test>::contains_key(subnet_id, epoch - 2));
+    assert!(SubnetRewardsSubmission::<Test>::contains_key(subnet_id, epoch));
+    assert!(SubnetRewardsValidator::<Test>::contains_key(subnet_id, epoch));
+  });
+}
+
+#[test]
+fn test_prune_rewards_submissions_catches_up() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, validator_id) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |_| ());
+    let validator = account(validator_id);
+
+    assert_ok!(
+      Network::validate(
+        RuntimeOrigin::signed(validator),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      )
+    );
+
+    // --- Submissions of 6 epochs past the history, e.g. after lowering `RewardsSubmissionHistoryEpochs`
+    assert_ok!(Network::do_set_rewards_submission_history_epochs(1));
+    SubnetRewardsSubmissionPruneEpoch::<Test>::insert(subnet_id, epoch - 6);
+    for prune_epoch in epoch-6..epoch {
+      SubnetRewardsSubmission::<Test>::insert(subnet_id, prune_epoch, RewardsData::default());
+      SubnetRewardsValidator::<Test>::insert(subnet_id, prune_epoch, 1);
+      SubnetRewardsDissents::<Test>::insert(subnet_id, prune_epoch, BTreeMap::from([(1, None)]));
+      SubnetValidatorCommittee::<Test>::insert(subnet_id, prune_epoch, vec![1]);
+      SubnetBackupValidators::<Test>::insert(subnet_id, prune_epoch, vec![2]);
+      SubnetValidatorSelectionProof::<Test>::insert(subnet_id, prune_epoch, SubnetValidatorSelectionProof::<Test>::get(subnet_id, epoch).unwrap());
+      SubnetCommitteeSubmissions::<Test>::insert(subnet_id, prune_epoch, BTreeMap::from([(1, Vec::new())]));
+    }
+
+    increase_epochs(1);
+
+    assert_ok!(Network::reward_subnets_v2(System::block_number(), epoch));
+
+    // --- Up to 4 epochs are pruned at a time
+    for prune_epoch in epoch-6..epoch-2 {
+      assert!(!SubnetRewardsSubmission::<Test>::contains_key(subnet_id, prune_epoch));
+      assert!(!SubnetRewardsValidator::<Test>::contains_key(subnet_id, prune_epoch));
+      assert!(!SubnetRewardsDissents::<Test>::contains_key(subnet_id, prune_epoch));
+      assert!(!SubnetValidatorCommittee::<Test>::contains_key(subnet_id, prune_epoch));
+      assert!(!SubnetBackupValidators::<Test>::contains_key(subnet_id, prune_epoch));
+      assert!(!SubnetValidatorSelectionProof::<Test>::contains_key(subnet_id, prune_epoch));
+      assert!(!SubnetCommitteeSubmissions::<Test>::contains_key(subnet_id, prune_epoch));
+    }
+    for prune_epoch in epoch-2..epoch+1 {
+      assert!(SubnetRewardsSubmission::<Test>::contains_key(subnet_id, prune_epoch));
+      assert!(SubnetRewardsValidator::<Test>::contains_key(subnet_id, prune_epoch));
+      assert!(SubnetValidatorCommittee::<Test>::contains_key(subnet_id, prune_epoch));
+    }
+    assert_eq!(SubnetRewardsSubmissionPruneEpoch::<Test>::get(subnet_id), Some(epoch - 2));
+  });
+}
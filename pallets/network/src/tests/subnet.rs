@@ -5,7 +5,7 @@ use frame_support::{
 	assert_noop, assert_ok, assert_err
 };
 use log::info;
-use frame_support::traits::{OnInitialize, Currency};
+use frame_support::traits::{OnInitialize, Currency, BuildGenesisConfig};
 use sp_std::collections::btree_set::BTreeSet;
 use crate::{
   Error,
@@ -22,6 +22,10 @@ use crate::{
   SubnetRegistrationEpochs,
   SubnetState,
   MinStakeBalance,
+  TotalActiveSubnetNodes,
+  TotalActiveNodes,
+  TotalDelegateStake,
+  TotalSubnetDelegateStakeBalance,
 };
 
 //
@@ -65,6 +69,7 @@ fn test_register_subnet() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -119,6 +124,7 @@ fn test_register_subnet_subnet_registration_cooldown() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist.clone(),
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -149,6 +155,7 @@ fn test_register_subnet_subnet_registration_cooldown() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist.clone(),
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
 
@@ -193,6 +200,7 @@ fn test_register_subnet_subnet_registration_cooldown() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist.clone(),
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
 
@@ -231,6 +239,7 @@ fn test_register_subnet_exists_error() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -278,6 +287,7 @@ fn test_register_subnet_exists_error() {
 //       node_registration_interval: 0,
 //       // coldkey_whitelist: Some(BTreeSet::new()),
 //       coldkey_whitelist: None,
+//       validator_args_type: None,
 //     };
     
 //     let epoch_length = EpochLength::get();
@@ -300,6 +310,7 @@ fn test_register_subnet_exists_error() {
 //       node_registration_interval: 0,
 //       // coldkey_whitelist: Some(BTreeSet::new()),
 //       coldkey_whitelist: None,
+//       validator_args_type: None,
 //     };
 
 //     assert_err!(
@@ -345,6 +356,7 @@ fn test_register_subnet_exists_error() {
 //         max_node_registration_epochs: 16,
 //         node_registration_interval: 0,
       // coldkey_whitelist: Some(BTreeSet::new()),
+      // validator_args_type: None,
       // coldkey_whitelist: None,
 //       };
 
@@ -388,6 +400,7 @@ fn test_register_subnet_not_enough_balance_err() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
 
@@ -432,6 +445,7 @@ fn test_activate_subnet() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -535,6 +549,7 @@ fn test_activate_subnet_invalid_subnet_id_error() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -615,6 +630,7 @@ fn test_activate_subnet_already_activated_err() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -717,6 +733,7 @@ fn test_activate_subnet_enactment_period_remove_subnet() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -830,6 +847,7 @@ fn test_activate_subnet_initializing_error() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -982,6 +1000,7 @@ fn test_activate_subnet_min_subnet_nodes_remove_subnet() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -1057,6 +1076,7 @@ fn test_activate_subnet_min_delegate_balance_remove_subnet() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -1126,4 +1146,46 @@ fn test_activate_subnet_min_delegate_balance_remove_subnet() {
     let subnet = SubnetsData::<Test>::try_get(subnet_id);
     assert_eq!(subnet, Err(()));
   })
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_remove_subnet_uncounts_active_subnet_nodes() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let deposit_amount: u128 = 10000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+
+    build_activated_subnet(subnet_path.clone(), 0, 0, deposit_amount, stake_amount);
+
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+    assert_eq!(TotalActiveSubnetNodes::<Test>::get(subnet_id), total_subnet_nodes);
+    assert_eq!(TotalActiveNodes::<Test>::get(), total_subnet_nodes);
+
+    assert_ok!(Network::do_remove_subnet(subnet_path, SubnetRemovalReason::Council));
+
+    assert!(!TotalActiveSubnetNodes::<Test>::contains_key(subnet_id));
+    assert_eq!(TotalActiveNodes::<Test>::get(), 0);
+  })
+}
+
+#[test]
+fn test_genesis_subnet_delegate_stake_counted() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let _ = Balances::deposit_creating(&account(1), 10000000000000000000000);
+
+    crate::GenesisConfig::<Test> {
+      subnet_path: subnet_path.clone(),
+      memory_mb: 0,
+      subnet_nodes: Vec::new(),
+    }.build();
+
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path).unwrap();
+    let total_subnet_delegate_stake_balance = TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id);
+
+    assert!(total_subnet_delegate_stake_balance > 0);
+    assert_eq!(TotalDelegateStake::<Test>::get(), total_subnet_delegate_stake_balance);
+  })
+}
@@ -36,6 +36,8 @@ use crate::{
   SubnetRegistrationEpochs,
   MinStakeBalance,
   RegisteredStakeCooldownEpochs,
+  TotalActiveSubnetNodes,
+  TotalActiveNodes,
 };
 
 ///
@@ -411,6 +413,7 @@ fn test_register_subnet_node_subnet_registering_or_activated_error() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -480,6 +483,7 @@ fn test_register_subnet_node_then_activate() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
     };
   
     let epoch_length = EpochLength::get();
@@ -598,6 +602,7 @@ fn test_activate_subnet_node_subnet_registering_or_activated_error() {
       node_queue_period: 1,
       max_node_penalties: 3,
       coldkey_whitelist: whitelist,
+      validator_args_type: None,
       // coldkey_whitelist: None,
     };
   
@@ -2569,3 +2574,50 @@ fn test_update_delegate_reward_rate() {
 
   });
 }
+
+#[test]
+fn test_active_subnet_nodes_accounting() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let deposit_amount: u128 = 10000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+
+    build_activated_subnet(subnet_path.clone(), 0, 12, deposit_amount, stake_amount);
+
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+    // --- Counted on activation
+    assert_eq!(TotalActiveSubnetNodes::<Test>::get(subnet_id), total_subnet_nodes);
+    assert_eq!(TotalActiveNodes::<Test>::get(), total_subnet_nodes);
+
+    assert_ok!(
+      Network::deactivate_subnet_node(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        1,
+      )
+    );
+    assert_eq!(TotalActiveSubnetNodes::<Test>::get(subnet_id), total_subnet_nodes - 1);
+
+    assert_ok!(
+      Network::remove_subnet_node(
+        RuntimeOrigin::signed(account(2)),
+        subnet_id,
+        2,
+      )
+    );
+    assert_eq!(TotalActiveSubnetNodes::<Test>::get(subnet_id), total_subnet_nodes - 2);
+
+    // --- Deactivated subnet nodes are only uncounted once
+    assert_ok!(
+      Network::remove_subnet_node(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        1,
+      )
+    );
+    assert_eq!(TotalActiveSubnetNodes::<Test>::get(subnet_id), total_subnet_nodes - 2);
+    assert_eq!(TotalActiveNodes::<Test>::get(), total_subnet_nodes - 2);
+  });
+}
@@ -0,0 +1,191 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_ok, assert_err,
+  traits::Hooks,
+  weights::Weight,
+};
+use sp_runtime::DispatchError;
+use crate::{
+  Error,
+  SubnetsData,
+  HotkeySubnetNodeId,
+  SubnetNodesData,
+  SubnetRemovalReason,
+  AccountSubnetStake,
+  TotalSubnetStake,
+  RemovingSubnets,
+  MaxSubnetRemovalItemsPerBlock,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Subnet removal across blocks
+//
+//
+//
+//
+//
+//
+//
+
+fn build_removed_subnet(subnet_path: Vec<u8>) -> (u32, u32) {
+  let (subnet_id, total_subnet_nodes, _, _) = build_epoch_subnet(subnet_path.clone(), 0, EpochSubnetStage::Activated, |_| ());
+
+  assert_ok!(Network::do_remove_subnet(subnet_path, SubnetRemovalReason::Council));
+
+  (subnet_id, total_subnet_nodes)
+}
+
+fn subnet_stakes_left(subnet_id: u32, total_subnet_nodes: u32) -> usize {
+  (1..total_subnet_nodes+1)
+    .filter(|n| AccountSubnetStake::<Test>::get(account(*n), subnet_id) > 0)
+    .count()
+}
+
+#[test]
+fn test_remove_subnet_marks_subnet_removing() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes) = build_removed_subnet(subnet_path);
+
+    // --- The subnet is gone but its storage is cleaned up later
+    assert!(!SubnetsData::<Test>::contains_key(subnet_id));
+    assert_eq!(RemovingSubnets::<Test>::get(subnet_id), Some(Default::default()));
+    assert_eq!(SubnetNodesData::<Test>::iter_prefix(subnet_id).count() as u32, total_subnet_nodes);
+    assert_eq!(subnet_stakes_left(subnet_id, total_subnet_nodes), total_subnet_nodes as usize);
+
+    remove_subnet_storage();
+
+    assert!(!RemovingSubnets::<Test>::contains_key(subnet_id));
+    assert_eq!(SubnetNodesData::<Test>::iter_prefix(subnet_id).count(), 0);
+    assert_eq!(HotkeySubnetNodeId::<Test>::iter_prefix(subnet_id).count(), 0);
+    assert_eq!(subnet_stakes_left(subnet_id, total_subnet_nodes), 0);
+    assert!(!TotalSubnetStake::<Test>::contains_key(subnet_id));
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::SubnetRemoved { subnet_id: subnet_id }
+    );
+  });
+}
+
+#[test]
+fn test_remove_subnets_step_resumes() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes) = build_removed_subnet(subnet_path);
+
+    Network::do_remove_subnets_step(1);
+
+    // --- Only one stake was unbonded and the step continues from its key
+    let cursor = RemovingSubnets::<Test>::get(subnet_id).unwrap();
+    assert_eq!(cursor.step, 0);
+    assert!(cursor.cursor.is_some());
+    assert_eq!(subnet_stakes_left(subnet_id, total_subnet_nodes), (total_subnet_nodes - 1) as usize);
+
+    Network::do_remove_subnets_step(1);
+    assert_eq!(subnet_stakes_left(subnet_id, total_subnet_nodes), (total_subnet_nodes - 2) as usize);
+
+    let mut steps: u32 = 2;
+    while RemovingSubnets::<Test>::contains_key(subnet_id) {
+      Network::do_remove_subnets_step(1);
+      steps += 1;
+    }
+
+    assert!(steps > total_subnet_nodes);
+    assert_eq!(subnet_stakes_left(subnet_id, total_subnet_nodes), 0);
+    assert_eq!(SubnetNodesData::<Test>::iter_prefix(subnet_id).count(), 0);
+    assert!(network_events().iter().any(|event| matches!(
+      event,
+      Event::SubnetRemoved { subnet_id: id } if *id == subnet_id
+    )));
+  });
+}
+
+#[test]
+fn test_on_idle_removes_subnet() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes) = build_removed_subnet(subnet_path);
+
+    assert_ok!(Network::do_set_max_subnet_removal_items_per_block(1));
+
+    Network::on_idle(System::block_number(), Weight::MAX);
+    assert!(RemovingSubnets::<Test>::contains_key(subnet_id));
+    assert_eq!(subnet_stakes_left(subnet_id, total_subnet_nodes), (total_subnet_nodes - 1) as usize);
+
+    assert_ok!(Network::do_set_max_subnet_removal_items_per_block(u32::MAX));
+
+    let used_weight = Network::on_idle(System::block_number(), Weight::MAX);
+    assert!(used_weight.all_gt(Weight::zero()));
+    assert!(!RemovingSubnets::<Test>::contains_key(subnet_id));
+    assert_eq!(SubnetNodesData::<Test>::iter_prefix(subnet_id).count(), 0);
+  });
+}
+
+#[test]
+fn test_add_node_delegate_stake_to_removing_subnet() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes) = build_removed_subnet(subnet_path);
+    let amount: u128 = 1000000000000000000000;
+
+    let delegator = account(total_subnet_nodes+1);
+    let _ = Balances::deposit_creating(&delegator, amount + 500);
+
+    // --- The subnet nodes are still stored until the subnet is cleaned up
+    assert!(SubnetNodesData::<Test>::contains_key(subnet_id, 1));
+
+    assert_err!(
+      Network::add_to_node_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        1,
+        amount,
+      ),
+      Error::<Test>::SubnetNotExist
+    );
+
+    assert_err!(
+      Network::increase_node_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        1,
+        amount,
+      ),
+      Error::<Test>::SubnetNotExist
+    );
+  });
+}
+
+#[test]
+fn test_set_max_subnet_removal_items_per_block() {
+  new_test_ext().execute_with(|| {
+    assert_err!(
+      Network::set_max_subnet_removal_items_per_block(
+        RuntimeOrigin::signed(account(1)),
+        1,
+      ),
+      DispatchError::BadOrigin
+    );
+
+    assert_err!(
+      Network::do_set_max_subnet_removal_items_per_block(0),
+      Error::<Test>::InvalidMaxSubnetRemovalItemsPerBlock
+    );
+
+    assert_ok!(Network::do_set_max_subnet_removal_items_per_block(1));
+    assert_eq!(MaxSubnetRemovalItemsPerBlock::<Test>::get(), 1);
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::SetMaxSubnetRemovalItemsPerBlock(1)
+    );
+  });
+}
@@ -0,0 +1,235 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_ok,
+};
+use sp_std::collections::btree_map::BTreeMap;
+use crate::{
+  HotkeySubnetNodeId,
+  SubnetRemovalReason,
+  AccountSubnetStake,
+  TotalSubnetStake,
+  TotalStake,
+  StakeUnbondingLedger,
+  AccountSubnetDelegateStakeShares,
+  TotalSubnetDelegateStakeBalance,
+  TotalSubnetDelegateStakeShares,
+  TotalDelegateStake,
+  AccountNodeDelegateStakeShares,
+  TotalNodeDelegateStakeBalance,
+  TotalNodeDelegateStakeShares,
+  TotalNodeDelegateStake,
+  SubnetStakeAccounts,
+  SubnetDelegateStakeAccounts,
+  SubnetNodeDelegateStakeAccounts,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Subnet removal unbonding
+//
+//
+//
+//
+//
+//
+//
+
+fn build_staked_subnet(subnet_path: Vec<u8>, amount: u128) -> (u32, u32, u32) {
+  let (subnet_id, total_subnet_nodes, _, _) = build_epoch_subnet(subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+  let subnet_node_id = HotkeySubnetNodeId::<Test>::get(subnet_id, account(1)).unwrap();
+
+  let delegator = account(total_subnet_nodes+1);
+  let _ = Balances::deposit_creating(&delegator, amount * 2 + 500);
+
+  assert_ok!(
+    Network::add_to_delegate_stake(
+      RuntimeOrigin::signed(delegator.clone()),
+      subnet_id,
+      amount,
+    )
+  );
+
+  assert_ok!(
+    Network::add_to_node_delegate_stake(
+      RuntimeOrigin::signed(delegator.clone()),
+      subnet_id,
+      subnet_node_id,
+      amount,
+    )
+  );
+
+  (subnet_id, subnet_node_id, total_subnet_nodes)
+}
+
+#[test]
+fn test_remove_subnet_unbonds_stake() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, _, total_subnet_nodes) = build_staked_subnet(subnet_path.clone(), amount);
+
+    let stake_balance = AccountSubnetStake::<Test>::get(account(1), subnet_id);
+    let total_stake = TotalStake::<Test>::get();
+    let subnet_stake = TotalSubnetStake::<Test>::get(subnet_id);
+
+    assert_ok!(Network::do_remove_subnet(subnet_path, SubnetRemovalReason::Council));
+    remove_subnet_storage();
+
+    let epoch = System::block_number() / EpochLength::get();
+
+    for n in 1..total_subnet_nodes+1 {
+      assert_eq!(AccountSubnetStake::<Test>::get(account(n), subnet_id), 0);
+    }
+    assert!(!TotalSubnetStake::<Test>::contains_key(subnet_id));
+    assert_eq!(TotalStake::<Test>::get(), total_stake - subnet_stake);
+
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(account(1));
+    assert_eq!(unbondings.get(&(epoch + StakeCooldownEpochs::get())), Some(&stake_balance));
+
+    assert!(network_events().iter().any(|event| matches!(
+      event,
+      Event::SubnetBalancesUnbonded { subnet_id: id, stake, .. }
+        if *id == subnet_id && stake.contains(&(account(1), stake_balance)) && stake.len() == total_subnet_nodes as usize
+    )));
+  });
+}
+
+#[test]
+fn test_remove_subnet_unbonds_delegate_stake() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, subnet_node_id, total_subnet_nodes) = build_staked_subnet(subnet_path.clone(), amount);
+    let delegator = account(total_subnet_nodes+1);
+
+    let delegate_balance = Network::convert_to_balance(
+      AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id),
+      TotalSubnetDelegateStakeShares::<Test>::get(subnet_id),
+      TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id)
+    );
+    let node_delegate_balance = Network::convert_to_balance(
+      AccountNodeDelegateStakeShares::<Test>::get((delegator.clone(), subnet_id, subnet_node_id)),
+      TotalNodeDelegateStakeShares::<Test>::get(subnet_id, subnet_node_id),
+      TotalNodeDelegateStakeBalance::<Test>::get(subnet_id, subnet_node_id)
+    );
+
+    assert_ok!(Network::do_remove_subnet(subnet_path, SubnetRemovalReason::Council));
+    remove_subnet_storage();
+
+    let epoch = System::block_number() / EpochLength::get();
+
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id), 0);
+    assert_eq!(AccountNodeDelegateStakeShares::<Test>::get((delegator.clone(), subnet_id, subnet_node_id)), 0);
+    assert!(!TotalSubnetDelegateStakeBalance::<Test>::contains_key(subnet_id));
+    assert!(!TotalSubnetDelegateStakeShares::<Test>::contains_key(subnet_id));
+    assert!(!TotalNodeDelegateStakeBalance::<Test>::contains_key(subnet_id, subnet_node_id));
+    assert!(!TotalNodeDelegateStakeShares::<Test>::contains_key(subnet_id, subnet_node_id));
+    assert_eq!(TotalDelegateStake::<Test>::get(), 0);
+    assert_eq!(TotalNodeDelegateStake::<Test>::get(), 0);
+
+    // --- Each teardown step emits the balances it unbonded
+    let delegate_stake = network_events().iter().find_map(|event| match event {
+      Event::SubnetBalancesUnbonded { subnet_id: id, delegate_stake, .. } if *id == subnet_id && !delegate_stake.is_empty() => 
+        Some(delegate_stake.clone()),
+      _ => None,
+    }).unwrap();
+    let node_delegate_stake = network_events().iter().find_map(|event| match event {
+      Event::SubnetBalancesUnbonded { subnet_id: id, node_delegate_stake, .. } if *id == subnet_id && !node_delegate_stake.is_empty() => 
+        Some(node_delegate_stake.clone()),
+      _ => None,
+    }).unwrap();
+
+    // --- The pools other delegators leave first, so allow for rounding
+    let (_, unbonded_delegate_balance) = delegate_stake.iter().find(|(account_id, _)| *account_id == delegator).unwrap().clone();
+    assert!(
+      (unbonded_delegate_balance >= Network::percent_mul(delegate_balance, 999999000)) &&
+      (unbonded_delegate_balance <= delegate_balance + 1000)
+    );
+    assert_eq!(node_delegate_stake, vec![(delegator.clone(), subnet_node_id, node_delegate_balance)]);
+
+    // --- Both cooldowns are equal in the mock so they share a ledger entry
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(delegator.clone());
+    assert_eq!(
+      unbondings.get(&(epoch + DelegateStakeCooldownEpochs::get())),
+      Some(&(unbonded_delegate_balance + node_delegate_balance))
+    );
+  });
+}
+
+#[test]
+fn test_remove_subnet_unbonds_into_full_ledger() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, _, _) = build_staked_subnet(subnet_path.clone(), amount);
+
+    let stake_balance = AccountSubnetStake::<Test>::get(account(1), subnet_id);
+    let epoch = System::block_number() / EpochLength::get();
+
+    // --- Fill the ledger with unbondings that can't be claimed yet
+    let mut ledger: BTreeMap<u32, u128> = BTreeMap::new();
+    let last_claim_epoch = epoch + StakeCooldownEpochs::get() + MaxStakeUnlockings::get();
+    for n in 0..MaxStakeUnlockings::get() {
+      ledger.insert(last_claim_epoch - n, 1);
+    }
+    StakeUnbondingLedger::<Test>::insert(account(1), ledger);
+
+    assert_ok!(Network::do_remove_subnet(subnet_path, SubnetRemovalReason::Council));
+    remove_subnet_storage();
+
+    // --- The stake is merged into the latest unbonding
+    let unbondings: BTreeMap<u32, u128> = StakeUnbondingLedger::<Test>::get(account(1));
+    assert_eq!(unbondings.len(), MaxStakeUnlockings::get() as usize);
+    assert_eq!(unbondings.get(&last_claim_epoch), Some(&(stake_balance + 1)));
+  });
+}
+
+#[test]
+fn test_unbond_subnet_only_visits_subnet_positions() {
+  new_test_ext().execute_with(|| {
+    let other_subnet_path: Vec<u8> = "petals-team/StableBeluga".into();
+    let (other_subnet_id, _, _, _) = build_epoch_subnet(other_subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, subnet_node_id, total_subnet_nodes) = build_staked_subnet(subnet_path.clone(), amount);
+    let delegator = account(total_subnet_nodes+1);
+
+    assert!(SubnetStakeAccounts::<Test>::contains_key(subnet_id, account(1)));
+    assert!(SubnetDelegateStakeAccounts::<Test>::contains_key(subnet_id, delegator.clone()));
+    assert!(SubnetNodeDelegateStakeAccounts::<Test>::contains_key((subnet_id, delegator.clone(), subnet_node_id)));
+
+    assert_ok!(Network::do_remove_subnet(subnet_path, SubnetRemovalReason::Council));
+
+    // --- Only the subnet nodes stake is visited
+    let (completed, _, items, _) = Network::unbond_subnet_stake(subnet_id, None, u32::MAX);
+    assert!(completed);
+    assert_eq!(items, total_subnet_nodes);
+
+    // --- The delegator and the delegate staker activating the subnet
+    let (completed, _, items, _) = Network::unbond_subnet_delegate_stake(subnet_id, None, u32::MAX);
+    assert!(completed);
+    assert_eq!(items, 2);
+
+    let (completed, _, items, _) = Network::unbond_subnet_node_delegate_stake(subnet_id, None, u32::MAX);
+    assert!(completed);
+    assert_eq!(items, 1);
+
+    assert_eq!(SubnetStakeAccounts::<Test>::iter_prefix(subnet_id).count(), 0);
+    assert_eq!(SubnetDelegateStakeAccounts::<Test>::iter_prefix(subnet_id).count(), 0);
+    assert_eq!(SubnetNodeDelegateStakeAccounts::<Test>::iter_prefix((subnet_id,)).count(), 0);
+
+    // --- Other subnets positions are kept
+    assert!(SubnetStakeAccounts::<Test>::contains_key(other_subnet_id, account(1)));
+    assert_eq!(SubnetDelegateStakeAccounts::<Test>::iter_prefix(other_subnet_id).count(), 1);
+
+    remove_subnet_storage();
+  });
+}
@@ -38,6 +38,7 @@ use crate::{
   SubnetNodeRegistrationInterval,
   SubnetRegistrationEpochs,
   SubnetOwner,
+  RemovingSubnets,
   SubnetRegistrationEpoch,
 };
 use frame_support::traits::{OnInitialize, Currency};
@@ -102,6 +103,7 @@ pub fn build_activated_subnet(subnet_path: Vec<u8>, start: u32, mut end: u32, de
     node_queue_period: 1,
     max_node_penalties: 3,
     coldkey_whitelist: whitelist,
+    validator_args_type: None,
   };
 
   // --- Register subnet for activation
@@ -224,6 +226,87 @@ pub fn build_activated_subnet(subnet_path: Vec<u8>, start: u32, mut end: u32, de
   // assert!(validator != None, "Validator is None");
 }
 
+/// Stage of the current epoch `build_epoch_subnet` takes a subnet to
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub enum EpochSubnetStage {
+  /// Activated, the epoch preliminaries aren't run
+  Activated,
+  /// The validator of the epoch is chosen
+  ValidatorChosen,
+  /// The validator submitted `subnet_node_data`
+  Submitted,
+  /// Every other subnet node attested and the next epoch started
+  Attested,
+}
+
+/// Build an activated subnet of ``end`` subnet nodes staking `MinStakeBalance` up to ``stage`` of the current epoch
+///
+/// * ``configure`` is called with the subnet ID before the epoch preliminaries, e.g. for owner updates.
+/// * ``end`` of 0 is `MinSubnetNodes`, as in `build_activated_subnet`.
+///
+/// Returns the subnet ID, total subnet nodes, epoch and validator subnet node ID (0 if not chosen)
+pub fn build_epoch_subnet(
+  subnet_path: Vec<u8>, 
+  end: u32, 
+  stage: EpochSubnetStage, 
+  configure: impl FnOnce(u32),
+) -> (u32, u32, u32, u32) {
+  let deposit_amount: u128 = 10000000000000000000000;
+  let stake_amount: u128 = MinStakeBalance::<Test>::get();
+
+  build_activated_subnet(subnet_path.clone(), 0, end, deposit_amount, stake_amount);
+
+  let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+  let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+  configure(subnet_id);
+
+  let epoch_length = EpochLength::get();
+  let epoch = System::block_number() / epoch_length;
+
+  if stage == EpochSubnetStage::Activated {
+    return (subnet_id, total_subnet_nodes, epoch, 0)
+  }
+
+  Network::do_epoch_preliminaries(System::block_number(), epoch, epoch_length);
+
+  let validator_id = SubnetRewardsValidator::<Test>::get(subnet_id, epoch).unwrap();
+
+  if stage >= EpochSubnetStage::Submitted {
+    let validator = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+    assert_ok!(
+      Network::validate(
+        RuntimeOrigin::signed(validator),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      )
+    );
+  }
+
+  if stage == EpochSubnetStage::Attested {
+    attest_subnet(subnet_id, total_subnet_nodes, validator_id);
+    increase_epochs(1);
+  }
+
+  (subnet_id, total_subnet_nodes, epoch, validator_id)
+}
+
+/// Attest the submission of the current epoch from every subnet node other than the validator
+pub fn attest_subnet(subnet_id: u32, total_subnet_nodes: u32, validator_id: u32) {
+  for n in 1..total_subnet_nodes+1 {
+    if n == validator_id {
+      continue
+    }
+    assert_ok!(
+      Network::attest(
+        RuntimeOrigin::signed(account(n)),
+        subnet_id,
+      )
+    );
+  }
+}
+
 pub fn build_activated_subnet_with_delegator_rewards(
   subnet_path: Vec<u8>, 
   start: u32, 
@@ -257,6 +340,7 @@ pub fn build_activated_subnet_with_delegator_rewards(
     node_queue_period: 1,
     max_node_penalties: 3,
     coldkey_whitelist: whitelist,
+    validator_args_type: None,
   };
 
   // --- Register subnet for activation
@@ -400,7 +484,17 @@ pub fn build_subnet_nodes(subnet_id: u32, start: u32, end: u32, deposit_amount:
   amount_staked
 }
 
+/// Clean up the storage of all removed subnets as `on_idle` would
+pub fn remove_subnet_storage() {
+  while RemovingSubnets::<Test>::iter().next().is_some() {
+    Network::do_remove_subnets_step(u32::MAX);
+  }
+}
+
 pub fn post_subnet_removal_ensures(subnet_id: u32, path: Vec<u8>, start: u32, end: u32) {
+  remove_subnet_storage();
+
+  assert_eq!(RemovingSubnets::<Test>::contains_key(subnet_id), false);
   assert_eq!(SubnetsData::<Test>::try_get(subnet_id), Err(()));
   assert_eq!(SubnetPaths::<Test>::try_get(path), Err(()));
   assert_eq!(LastSubnetRegistration::<Test>::try_get(subnet_id), Err(()));
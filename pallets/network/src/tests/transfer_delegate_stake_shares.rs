@@ -0,0 +1,325 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_ok, assert_err,
+};
+use crate::{
+  Error,
+  HotkeySubnetNodeId,
+  AccountSubnetDelegateStakeShares,
+  TotalSubnetDelegateStakeShares,
+  AccountNodeDelegateStakeShares,
+  TotalNodeDelegateStakeShares,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Transfer delegate stake shares
+//
+//
+//
+//
+//
+//
+//
+
+fn build_delegated_subnet(amount: u128) -> (u32, u32, AccountIdOf<Test>) {
+  let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+  let (subnet_id, total_subnet_nodes, _, _) = build_epoch_subnet(subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+  let subnet_node_id = HotkeySubnetNodeId::<Test>::get(subnet_id, account(1)).unwrap();
+
+  let delegator = account(total_subnet_nodes+1);
+  let _ = Balances::deposit_creating(&delegator, amount * 2 + 500);
+
+  assert_ok!(
+    Network::add_to_delegate_stake(
+      RuntimeOrigin::signed(delegator.clone()),
+      subnet_id,
+      amount,
+    )
+  );
+
+  assert_ok!(
+    Network::add_to_node_delegate_stake(
+      RuntimeOrigin::signed(delegator.clone()),
+      subnet_id,
+      subnet_node_id,
+      amount,
+    )
+  );
+
+  (subnet_id, subnet_node_id, delegator)
+}
+
+#[test]
+fn test_transfer_delegate_stake_shares() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, _, delegator) = build_delegated_subnet(amount);
+
+    let shares = AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id);
+    let total_shares = TotalSubnetDelegateStakeShares::<Test>::get(subnet_id);
+    let receiver = account(2000);
+
+    assert_ok!(
+      Network::transfer_delegate_stake_shares(
+        RuntimeOrigin::signed(delegator.clone()),
+        receiver.clone(),
+        subnet_id,
+        shares / 2,
+      )
+    );
+
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id), shares - shares / 2);
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(receiver.clone(), subnet_id), shares / 2);
+    assert_eq!(TotalSubnetDelegateStakeShares::<Test>::get(subnet_id), total_shares);
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::SubnetDelegateStakeSharesTransferred {
+        subnet_id: subnet_id,
+        from: delegator.clone(),
+        to: receiver.clone(),
+        shares: shares / 2,
+      }
+    );
+
+    // --- The received shares are in the cooldown of the receiver
+    assert_eq!(Network::delegate_stake_cooldown_shares(&receiver, subnet_id), shares / 2);
+
+    assert_err!(
+      Network::remove_delegate_stake(
+        RuntimeOrigin::signed(receiver.clone()),
+        subnet_id,
+        shares / 2,
+      ),
+      Error::<Test>::InsufficientCooldown
+    );
+
+    assert_err!(
+      Network::transfer_delegate_stake_shares(
+        RuntimeOrigin::signed(receiver.clone()),
+        delegator.clone(),
+        subnet_id,
+        shares / 2,
+      ),
+      Error::<Test>::InsufficientCooldown
+    );
+
+    increase_epochs(DelegateStakeCooldownEpochs::get());
+
+    assert_ok!(
+      Network::remove_delegate_stake(
+        RuntimeOrigin::signed(receiver.clone()),
+        subnet_id,
+        shares / 2,
+      )
+    );
+  });
+}
+
+#[test]
+fn test_transfer_delegate_stake_shares_cooldown_only_received_shares() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, _, delegator) = build_delegated_subnet(amount);
+
+    let shares = AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id);
+
+    let sender = account(2000);
+    let _ = Balances::deposit_creating(&sender, amount + 500);
+    assert_ok!(
+      Network::add_to_delegate_stake(
+        RuntimeOrigin::signed(sender.clone()),
+        subnet_id,
+        amount,
+      )
+    );
+    let sender_shares = AccountSubnetDelegateStakeShares::<Test>::get(sender.clone(), subnet_id);
+
+    // --- Shares sent to an account don't lock the rest of its position
+    assert_ok!(
+      Network::transfer_delegate_stake_shares(
+        RuntimeOrigin::signed(sender.clone()),
+        delegator.clone(),
+        subnet_id,
+        sender_shares / 2,
+      )
+    );
+    assert_eq!(Network::delegate_stake_cooldown_shares(&delegator, subnet_id), sender_shares / 2);
+
+    assert_err!(
+      Network::remove_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        shares + 1,
+      ),
+      Error::<Test>::InsufficientCooldown
+    );
+
+    assert_ok!(
+      Network::remove_delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        subnet_id,
+        shares,
+      )
+    );
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id), sender_shares / 2);
+
+    increase_epochs(DelegateStakeCooldownEpochs::get());
+
+    assert_eq!(Network::delegate_stake_cooldown_shares(&delegator, subnet_id), 0);
+  });
+}
+
+#[test]
+fn test_transfer_delegate_stake_shares_min_delegate_stake() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, _, delegator) = build_delegated_subnet(amount);
+
+    let shares = AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id);
+
+    // --- The receiver would be under the minimum balance
+    assert_err!(
+      Network::transfer_delegate_stake_shares(
+        RuntimeOrigin::signed(delegator.clone()),
+        account(2000),
+        subnet_id,
+        1,
+      ),
+      Error::<Test>::MinDelegateStake
+    );
+
+    // --- The sender would be under the minimum balance
+    assert_err!(
+      Network::transfer_delegate_stake_shares(
+        RuntimeOrigin::signed(delegator.clone()),
+        account(2000),
+        subnet_id,
+        shares - 1,
+      ),
+      Error::<Test>::MinDelegateStake
+    );
+
+    // --- The whole position can be transferred
+    assert_ok!(
+      Network::transfer_delegate_stake_shares(
+        RuntimeOrigin::signed(delegator.clone()),
+        account(2000),
+        subnet_id,
+        shares,
+      )
+    );
+    assert_eq!(AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id), 0);
+  });
+}
+
+#[test]
+fn test_transfer_delegate_stake_shares_invalid() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, subnet_node_id, delegator) = build_delegated_subnet(amount);
+
+    let shares = AccountSubnetDelegateStakeShares::<Test>::get(delegator.clone(), subnet_id);
+
+    assert_err!(
+      Network::transfer_delegate_stake_shares(
+        RuntimeOrigin::signed(delegator.clone()),
+        delegator.clone(),
+        subnet_id,
+        shares,
+      ),
+      Error::<Test>::InvalidDelegateStakeSharesTransfer
+    );
+
+    assert_err!(
+      Network::transfer_delegate_stake_shares(
+        RuntimeOrigin::signed(delegator.clone()),
+        account(2000),
+        subnet_id,
+        shares + 1,
+      ),
+      Error::<Test>::NotEnoughStakeToWithdraw
+    );
+
+    assert_err!(
+      Network::transfer_node_delegate_stake_shares(
+        RuntimeOrigin::signed(delegator.clone()),
+        account(2000),
+        subnet_id + 1,
+        subnet_node_id,
+        shares,
+      ),
+      Error::<Test>::SubnetNotExist
+    );
+  });
+}
+
+#[test]
+fn test_transfer_node_delegate_stake_shares() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (subnet_id, subnet_node_id, delegator) = build_delegated_subnet(amount);
+
+    let shares = AccountNodeDelegateStakeShares::<Test>::get((delegator.clone(), subnet_id, subnet_node_id));
+    let total_shares = TotalNodeDelegateStakeShares::<Test>::get(subnet_id, subnet_node_id);
+    let receiver = account(2000);
+
+    assert_err!(
+      Network::transfer_node_delegate_stake_shares(
+        RuntimeOrigin::signed(delegator.clone()),
+        receiver.clone(),
+        subnet_id,
+        subnet_node_id,
+        1,
+      ),
+      Error::<Test>::MinDelegateStake
+    );
+
+    assert_ok!(
+      Network::transfer_node_delegate_stake_shares(
+        RuntimeOrigin::signed(delegator.clone()),
+        receiver.clone(),
+        subnet_id,
+        subnet_node_id,
+        shares / 2,
+      )
+    );
+
+    assert_eq!(AccountNodeDelegateStakeShares::<Test>::get((delegator.clone(), subnet_id, subnet_node_id)), shares - shares / 2);
+    assert_eq!(AccountNodeDelegateStakeShares::<Test>::get((receiver.clone(), subnet_id, subnet_node_id)), shares / 2);
+    assert_eq!(TotalNodeDelegateStakeShares::<Test>::get(subnet_id, subnet_node_id), total_shares);
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::NodeDelegateStakeSharesTransferred {
+        subnet_id: subnet_id,
+        subnet_node_id: subnet_node_id,
+        from: delegator.clone(),
+        to: receiver.clone(),
+        shares: shares / 2,
+      }
+    );
+
+    assert_err!(
+      Network::remove_node_delegate_stake(
+        RuntimeOrigin::signed(receiver.clone()),
+        subnet_id,
+        subnet_node_id,
+        shares / 2,
+      ),
+      Error::<Test>::InsufficientCooldown
+    );
+
+    // --- Only the received shares are in the cooldown, not the subnet delegate stake of the receiver
+    assert_eq!(Network::node_delegate_stake_cooldown_shares(&receiver, subnet_id, subnet_node_id), shares / 2);
+    assert_eq!(Network::delegate_stake_cooldown_shares(&receiver, subnet_id), 0);
+  });
+}
@@ -0,0 +1,212 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_ok, assert_err,
+};
+use crate::{
+  Error,
+  HotkeySubnetNodeId,
+  MinStakeBalance,
+  MaxStakeBalance,
+  StakeTransferPeriod,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Transfer stake
+//
+//
+//
+//
+//
+//
+//
+
+fn build_transfer_subnets(amount: u128) -> (u32, u32) {
+  let from_subnet_path: Vec<u8> = "petals-team/StableBeluga".into();
+  let (from_subnet_id, _, _, _) = build_epoch_subnet(from_subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+
+  let to_subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+  let (to_subnet_id, _, _, _) = build_epoch_subnet(to_subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+
+  let subnet_node_id = HotkeySubnetNodeId::<Test>::get(from_subnet_id, account(1)).unwrap();
+
+  assert_ok!(
+    Network::add_to_stake(
+      RuntimeOrigin::signed(account(1)),
+      from_subnet_id,
+      subnet_node_id,
+      account(1),
+      amount,
+    )
+  );
+
+  (from_subnet_id, to_subnet_id)
+}
+
+#[test]
+fn test_transfer_stake() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+    let (from_subnet_id, to_subnet_id) = build_transfer_subnets(amount);
+
+    assert_ok!(
+      Network::transfer_stake(
+        RuntimeOrigin::signed(account(1)),
+        from_subnet_id,
+        account(1),
+        to_subnet_id,
+        account(1),
+        amount,
+      )
+    );
+
+    assert_eq!(Network::account_subnet_stake(account(1), from_subnet_id), stake_amount);
+    assert_eq!(Network::account_subnet_stake(account(1), to_subnet_id), stake_amount + amount);
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::StakeTransferred {
+        coldkey: account(1),
+        from_subnet_id: from_subnet_id,
+        from_hotkey: account(1),
+        to_subnet_id: to_subnet_id,
+        to_hotkey: account(1),
+        amount: amount,
+      }
+    );
+  });
+}
+
+#[test]
+fn test_transfer_stake_min_stake_not_reached() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (from_subnet_id, to_subnet_id) = build_transfer_subnets(amount);
+
+    assert_err!(
+      Network::transfer_stake(
+        RuntimeOrigin::signed(account(1)),
+        from_subnet_id,
+        account(1),
+        to_subnet_id,
+        account(1),
+        amount + 1,
+      ),
+      Error::<Test>::MinStakeNotReached
+    );
+  });
+}
+
+#[test]
+fn test_transfer_stake_max_stake_reached() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (from_subnet_id, to_subnet_id) = build_transfer_subnets(amount);
+
+    MaxStakeBalance::<Test>::set(Network::account_subnet_stake(account(1), to_subnet_id) + amount - 1);
+
+    assert_err!(
+      Network::transfer_stake(
+        RuntimeOrigin::signed(account(1)),
+        from_subnet_id,
+        account(1),
+        to_subnet_id,
+        account(1),
+        amount,
+      ),
+      Error::<Test>::MaxStakeReached
+    );
+  });
+}
+
+#[test]
+fn test_transfer_stake_period_exceeded() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (from_subnet_id, to_subnet_id) = build_transfer_subnets(amount);
+
+    assert_ok!(
+      Network::transfer_stake(
+        RuntimeOrigin::signed(account(1)),
+        from_subnet_id,
+        account(1),
+        to_subnet_id,
+        account(1),
+        amount / 2,
+      )
+    );
+
+    assert_err!(
+      Network::transfer_stake(
+        RuntimeOrigin::signed(account(1)),
+        from_subnet_id,
+        account(1),
+        to_subnet_id,
+        account(1),
+        amount / 2,
+      ),
+      Error::<Test>::StakeTransferPeriodExceeded
+    );
+
+    System::set_block_number(System::block_number() + StakeTransferPeriod::<Test>::get());
+
+    assert_ok!(
+      Network::transfer_stake(
+        RuntimeOrigin::signed(account(1)),
+        from_subnet_id,
+        account(1),
+        to_subnet_id,
+        account(1),
+        amount / 2,
+      )
+    );
+  });
+}
+
+#[test]
+fn test_transfer_stake_not_key_owner() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (from_subnet_id, to_subnet_id) = build_transfer_subnets(amount);
+
+    assert_err!(
+      Network::transfer_stake(
+        RuntimeOrigin::signed(account(1)),
+        from_subnet_id,
+        account(1),
+        to_subnet_id,
+        account(2),
+        amount,
+      ),
+      Error::<Test>::NotKeyOwner
+    );
+  });
+}
+
+#[test]
+fn test_transfer_stake_same_pair() {
+  new_test_ext().execute_with(|| {
+    let amount: u128 = 1000000000000000000000;
+    let (from_subnet_id, _) = build_transfer_subnets(amount);
+
+    assert_err!(
+      Network::transfer_stake(
+        RuntimeOrigin::signed(account(1)),
+        from_subnet_id,
+        account(1),
+        from_subnet_id,
+        account(1),
+        amount,
+      ),
+      Error::<Test>::InvalidStakeTransfer
+    );
+  });
+}
@@ -0,0 +1,55 @@
+use super::mock::*;
+use super::test_utils::*;
+use frame_support::{
+	assert_ok,
+};
+use crate::{
+  TotalStake,
+  TotalDelegateStake,
+  TotalActiveSubnetNodes,
+  HotkeySubnetNodeId,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// try_state invariants
+//
+//
+//
+//
+//
+//
+//
+
+#[test]
+fn test_try_state_detects_drift() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, _, _, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Activated, |_| ());
+
+    assert_ok!(Network::do_try_state());
+
+    TotalStake::<Test>::mutate(|n| *n += 1);
+    assert!(Network::do_try_state().is_err());
+    TotalStake::<Test>::mutate(|n| *n -= 1);
+
+    TotalDelegateStake::<Test>::mutate(|n| *n += 1);
+    assert!(Network::do_try_state().is_err());
+    TotalDelegateStake::<Test>::mutate(|n| *n -= 1);
+
+    TotalActiveSubnetNodes::<Test>::mutate(subnet_id, |n| *n += 1);
+    assert!(Network::do_try_state().is_err());
+    TotalActiveSubnetNodes::<Test>::mutate(subnet_id, |n| *n -= 1);
+
+    let subnet_node_id = HotkeySubnetNodeId::<Test>::take(subnet_id, account(1)).unwrap();
+    assert!(Network::do_try_state().is_err());
+    HotkeySubnetNodeId::<Test>::insert(subnet_id, account(1), subnet_node_id);
+
+    assert_ok!(Network::do_try_state());
+  });
+}
@@ -0,0 +1,203 @@
+use super::mock::*;
+use super::test_utils::*;
+use frame_support::{
+	assert_ok, assert_err,
+  BoundedVec,
+};
+use codec::Encode;
+use crate::{
+  Error,
+  SubnetRewardsValidator,
+  SubnetPaths,
+  TotalSubnetNodes,
+  SubnetNodeIdHotkey,
+  MinStakeBalance,
+  MinSubnetNodes,
+  RegistrationSubnetData,
+  SubnetValidatorArgsType,
+  ValidatorArgsType,
+  ValidatorArgsValue,
+  DefaultValidatorArgsLimit,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Validator args
+//
+//
+//
+//
+//
+//
+//
+
+fn registration_data(subnet_path: Vec<u8>, validator_args_type: Option<ValidatorArgsType>) -> RegistrationSubnetData<AccountIdOf<Test>> {
+  RegistrationSubnetData {
+    path: subnet_path.into(),
+    max_node_registration_epochs: 16,
+    node_registration_interval: 0,
+    node_activation_interval: 0,
+    node_queue_period: 1,
+    max_node_penalties: 3,
+    coldkey_whitelist: get_coldkey_whitelist(0, MinSubnetNodes::<Test>::get()),
+    validator_args_type: validator_args_type,
+  }
+}
+
+fn args_type() -> ValidatorArgsType {
+  ValidatorArgsType::Tuple(vec![
+    ValidatorArgsType::U32,
+    ValidatorArgsType::Vec(Box::new(ValidatorArgsType::U8)),
+    ValidatorArgsType::Option(Box::new(ValidatorArgsType::Bool)),
+  ])
+}
+
+fn to_args(bytes: Vec<u8>) -> Option<BoundedVec<u8, DefaultValidatorArgsLimit>> {
+  Some(BoundedVec::try_from(bytes).unwrap())
+}
+
+#[test]
+fn test_register_subnet_validator_args_type() {
+  new_test_ext().execute_with(|| {
+    let epoch_length = EpochLength::get();
+    let epoch = System::block_number() / epoch_length;
+    let next_registration_epoch = Network::get_next_registration_epoch(epoch);
+    increase_epochs(next_registration_epoch.saturating_sub(epoch));
+
+    let epoch = System::block_number() / epoch_length;
+    let cost = Network::registration_cost(epoch);
+    let _ = Balances::deposit_creating(&account(0), cost+1000);
+
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+    // --- Nested over the max depth
+    let mut nested = ValidatorArgsType::U8;
+    for _ in 0..Network::MAX_VALIDATOR_ARGS_DEPTH {
+      nested = ValidatorArgsType::Vec(Box::new(nested));
+    }
+
+    assert_err!(
+      Network::register_subnet(
+        RuntimeOrigin::signed(account(0)),
+        registration_data(subnet_path.clone(), Some(nested)),
+      ),
+      Error::<Test>::InvalidValidatorArgsType
+    );
+
+    // --- Empty tuples
+    assert_err!(
+      Network::register_subnet(
+        RuntimeOrigin::signed(account(0)),
+        registration_data(subnet_path.clone(), Some(ValidatorArgsType::Vec(Box::new(ValidatorArgsType::Tuple(vec![]))))),
+      ),
+      Error::<Test>::InvalidValidatorArgsType
+    );
+
+    assert_ok!(
+      Network::register_subnet(
+        RuntimeOrigin::signed(account(0)),
+        registration_data(subnet_path.clone(), Some(args_type())),
+      )
+    );
+
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    assert_eq!(SubnetValidatorArgsType::<Test>::get(subnet_id), Some(args_type()));
+  });
+}
+
+#[test]
+fn test_decode_validator_args() {
+  new_test_ext().execute_with(|| {
+    let subnet_id: u32 = 1;
+
+    // --- No args type
+    assert_eq!(Network::decode_validator_args(subnet_id, &to_args(7u32.encode())), None);
+    assert_ok!(Network::ensure_validator_args(subnet_id, &None));
+
+    SubnetValidatorArgsType::<Test>::insert(subnet_id, args_type());
+
+    let expected = ValidatorArgsValue::Tuple(vec![
+      ValidatorArgsValue::U32(7),
+      ValidatorArgsValue::Vec(vec![ValidatorArgsValue::U8(1), ValidatorArgsValue::U8(2)]),
+      ValidatorArgsValue::Option(Some(Box::new(ValidatorArgsValue::Bool(true)))),
+    ]);
+
+    let args: Vec<u8> = (7u32, vec![1u8, 2u8], Some(true)).encode();
+    assert_eq!(Network::decode_validator_args(subnet_id, &to_args(args.clone())), Some(expected));
+
+    // --- Args must be fully consumed
+    let mut extra = args.clone();
+    extra.push(0);
+    assert_eq!(Network::decode_validator_args(subnet_id, &to_args(extra)), None);
+
+    // --- Truncated
+    assert_eq!(Network::decode_validator_args(subnet_id, &to_args(args[..args.len() - 1].to_vec())), None);
+
+    // --- Vec length over the remaining bytes
+    let oversized: Vec<u8> = (7u32, codec::Compact(u32::MAX), 0u8).encode();
+    assert_eq!(Network::decode_validator_args(subnet_id, &to_args(oversized)), None);
+
+    assert_err!(Network::ensure_validator_args(subnet_id, &None), Error::<Test>::InvalidValidatorArgs);
+  });
+}
+
+#[test]
+fn test_validate_validator_args() {
+  new_test_ext().execute_with(|| {
+    let deposit_amount: u128 = 10000000000000000000000;
+    let stake_amount: u128 = MinStakeBalance::<Test>::get();
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+    build_activated_subnet(subnet_path.clone(), 0, 12, deposit_amount, stake_amount);
+
+    let subnet_id = SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+    SubnetValidatorArgsType::<Test>::insert(subnet_id, args_type());
+
+    let epoch_length = EpochLength::get();
+    let epoch = System::block_number() / epoch_length;
+
+    Network::do_epoch_preliminaries(System::block_number(), epoch, epoch_length);
+
+    let validator_id = SubnetRewardsValidator::<Test>::get(subnet_id, epoch).unwrap();
+    let validator = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(validator.clone()),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        to_args(7u32.encode()),
+      ),
+      Error::<Test>::InvalidValidatorArgs
+    );
+
+    let args: Vec<u8> = (7u32, vec![1u8, 2u8], None::<bool>).encode();
+
+    assert_ok!(
+      Network::validate(
+        RuntimeOrigin::signed(validator),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        to_args(args.clone()),
+      )
+    );
+
+    let consensus_data = Network::get_consensus_data(subnet_id, epoch).unwrap();
+    assert_eq!(consensus_data.args, to_args(args));
+    assert_eq!(
+      consensus_data.decoded_args,
+      Some(ValidatorArgsValue::Tuple(vec![
+        ValidatorArgsValue::U32(7),
+        ValidatorArgsValue::Vec(vec![ValidatorArgsValue::U8(1), ValidatorArgsValue::U8(2)]),
+        ValidatorArgsValue::Option(None),
+      ]))
+    );
+  });
+}
@@ -0,0 +1,292 @@
+use super::mock::*;
+use super::test_utils::*;
+use crate::Event;
+use frame_support::{
+	assert_noop, assert_ok, assert_err
+};
+use crate::{
+  Error,
+  SubnetNodeData,
+  SubnetRewardsValidator,
+  SubnetRewardsSubmission,
+  SubnetValidatorCommittee,
+  SubnetBackupValidators,
+  SubnetValidatorCommitteeSize,
+  SubnetCommitteeSubmissions,
+  MaxValidatorCommitteeSize,
+  SubnetNodeIdHotkey,
+};
+use sp_std::collections::btree_set::BTreeSet;
+
+//
+//
+//
+//
+//
+//
+//
+// Validator committees
+//
+//
+//
+//
+//
+//
+//
+
+fn update_committee_size(subnet_id: u32, committee_size: u32) {
+  assert_ok!(
+    Network::owner_update_validator_committee_size(
+      RuntimeOrigin::signed(account(0)),
+      subnet_id,
+      committee_size,
+    )
+  );
+}
+
+fn scaled_subnet_node_data(start: u32, end: u32, multiplier: u128) -> Vec<SubnetNodeData> {
+  subnet_node_data(start, end)
+    .into_iter()
+    .map(|x| SubnetNodeData { peer_id: x.peer_id, score: x.score * multiplier })
+    .collect()
+}
+
+#[test]
+fn test_owner_update_validator_committee_size() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+
+    let (subnet_id, _, _, _) = build_epoch_subnet(subnet_path, 0, EpochSubnetStage::Activated, |_| ());
+
+    assert_err!(
+      Network::owner_update_validator_committee_size(
+        RuntimeOrigin::signed(account(1)),
+        subnet_id,
+        3,
+      ),
+      Error::<Test>::NotSubnetOwner
+    );
+
+    assert_err!(
+      Network::owner_update_validator_committee_size(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        0,
+      ),
+      Error::<Test>::InvalidValidatorCommitteeSize
+    );
+
+    assert_err!(
+      Network::owner_update_validator_committee_size(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        MaxValidatorCommitteeSize::<Test>::get() + 1,
+      ),
+      Error::<Test>::InvalidValidatorCommitteeSize
+    );
+
+    assert_ok!(
+      Network::owner_update_validator_committee_size(
+        RuntimeOrigin::signed(account(0)),
+        subnet_id,
+        3,
+      )
+    );
+
+    assert_eq!(SubnetValidatorCommitteeSize::<Test>::get(subnet_id), 3);
+
+    assert_eq!(
+      *network_events().last().unwrap(),
+      Event::SubnetValidatorCommitteeSizeUpdate {
+        subnet_id: subnet_id,
+        owner: account(0),
+        value: 3,
+      }
+    );
+  });
+}
+
+#[test]
+fn test_choose_validator_committee() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, _, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |subnet_id| update_committee_size(subnet_id, 3));
+
+    let committee = SubnetValidatorCommittee::<Test>::get(subnet_id, epoch).unwrap();
+    assert_eq!(committee.len(), 3);
+
+    let unique: BTreeSet<u32> = committee.iter().copied().collect();
+    assert_eq!(unique.len(), 3);
+
+    // --- The first member is the primary validator
+    assert_eq!(SubnetRewardsValidator::<Test>::get(subnet_id, epoch), Some(committee[0]));
+    assert_eq!(Network::get_subnet_validator_committee(subnet_id, epoch).len(), 3);
+  });
+}
+
+#[test]
+fn test_committee_validate_weighted_median() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |subnet_id| update_committee_size(subnet_id, 3));
+
+    let committee = SubnetValidatorCommittee::<Test>::get(subnet_id, epoch).unwrap();
+    let primary = committee[0];
+
+    for (i, validator_id) in committee.iter().enumerate() {
+      let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+      // --- The last member leaves out the last subnet node
+      let end = if i == 2 { total_subnet_nodes - 1 } else { total_subnet_nodes };
+
+      assert_ok!(
+        Network::validate(
+          RuntimeOrigin::signed(hotkey.clone()),
+          subnet_id,
+          scaled_subnet_node_data(0, end, i as u128 + 1),
+          None,
+        )
+      );
+
+      assert_noop!(
+        Network::validate(
+          RuntimeOrigin::signed(hotkey),
+          subnet_id,
+          subnet_node_data(0, total_subnet_nodes),
+          None,
+        ),
+        Error::<Test>::SubnetRewardsAlreadySubmitted
+      );
+    }
+
+    assert_eq!(SubnetCommitteeSubmissions::<Test>::get(subnet_id, epoch).len(), 3);
+
+    let submission = SubnetRewardsSubmission::<Test>::get(subnet_id, epoch).unwrap();
+    assert_eq!(submission.validator_id, primary);
+    assert_eq!(submission.attests.len(), 3);
+    // --- Omitted by one of three equally staked members, still included
+    assert_eq!(submission.data.len(), total_subnet_nodes as usize);
+
+    for data in submission.data.iter() {
+      if data.peer_id == peer(total_subnet_nodes) {
+        // --- (None, 1x, 2x) median
+        assert_eq!(data.score, DEFAULT_SCORE);
+      } else {
+        // --- (1x, 2x, 3x) median
+        assert_eq!(data.score, DEFAULT_SCORE * 2);
+      }
+    }
+  });
+}
+
+#[test]
+fn test_committee_validate_not_member() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |subnet_id| update_committee_size(subnet_id, 3));
+
+    let committee = SubnetValidatorCommittee::<Test>::get(subnet_id, epoch).unwrap();
+    let backups = SubnetBackupValidators::<Test>::get(subnet_id, epoch);
+    let not_member = (1..total_subnet_nodes + 1).find(|x| !committee.contains(x) && !backups.contains(x)).unwrap();
+
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(account(not_member)),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      ),
+      Error::<Test>::InvalidValidator
+    );
+  });
+}
+
+#[test]
+fn test_weighted_median() {
+  new_test_ext().execute_with(|| {
+    assert_eq!(Network::get_weighted_median(Vec::new()), None);
+    assert_eq!(Network::get_weighted_median(vec![(Some(5), 1)]), Some(5));
+    assert_eq!(Network::get_weighted_median(vec![(Some(1), 1), (Some(2), 1), (Some(3), 1)]), Some(2));
+    // --- Heavy weight wins
+    assert_eq!(Network::get_weighted_median(vec![(Some(1), 1), (Some(2), 1), (Some(3), 5)]), Some(3));
+    // --- Exclusion backed by half the weight
+    assert_eq!(Network::get_weighted_median(vec![(None, 1), (Some(2), 1)]), None);
+  });
+}
+
+#[test]
+fn test_committee_submissions_close_before_attesting() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |subnet_id| update_committee_size(subnet_id, 3));
+
+    let committee = SubnetValidatorCommittee::<Test>::get(subnet_id, epoch).unwrap();
+    let not_member = (1..total_subnet_nodes + 1).find(|x| !committee.contains(x)).unwrap();
+    let end_block = Network::get_committee_submission_end_block(epoch);
+    assert!(System::block_number() < end_block);
+
+    let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, committee[0]).unwrap();
+    assert_ok!(
+      Network::validate(
+        RuntimeOrigin::signed(hotkey),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes),
+        None,
+      )
+    );
+
+    // --- Can't attest while the other members can still change the data
+    assert_err!(
+      Network::attest(
+        RuntimeOrigin::signed(account(not_member)),
+        subnet_id,
+      ),
+      Error::<Test>::CommitteeSubmissionOpen
+    );
+
+    System::set_block_number(end_block);
+
+    assert_ok!(
+      Network::attest(
+        RuntimeOrigin::signed(account(not_member)),
+        subnet_id,
+      )
+    );
+
+    // --- Late members can't change the attested data
+    let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, committee[1]).unwrap();
+    assert_err!(
+      Network::validate(
+        RuntimeOrigin::signed(hotkey),
+        subnet_id,
+        subnet_node_data(0, total_subnet_nodes - 1),
+        None,
+      ),
+      Error::<Test>::CommitteeSubmissionClosed
+    );
+
+    let submission = SubnetRewardsSubmission::<Test>::get(subnet_id, epoch).unwrap();
+    assert!(submission.attests.contains_key(&not_member));
+    assert_eq!(submission.data.len(), total_subnet_nodes as usize);
+  });
+}
+
+#[test]
+fn test_removed_committee_member() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, _, epoch, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::ValidatorChosen, |subnet_id| update_committee_size(subnet_id, 3));
+
+    let committee = SubnetValidatorCommittee::<Test>::get(subnet_id, epoch).unwrap();
+    let removed = committee[1];
+    let block = System::block_number();
+
+    Network::perform_remove_subnet_node(block, subnet_id, removed);
+
+    let committee = SubnetValidatorCommittee::<Test>::get(subnet_id, epoch).unwrap();
+    assert_eq!(committee.len(), 2);
+    assert!(!committee.contains(&removed));
+
+    // --- Removed members have nothing left to slash
+    Network::slash_validator(subnet_id, removed, 0, block);
+  });
+}
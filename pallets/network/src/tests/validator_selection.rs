@@ -0,0 +1,114 @@
+use super::mock::*;
+use super::test_utils::*;
+use frame_support::{
+	assert_noop, assert_ok
+};
+use sp_runtime::DispatchError;
+use crate::{
+  SubnetRewardsValidator,
+  SubnetValidatorSelectionProof,
+  AccountSubnetStake,
+  ValidatorSelectionStakeCap,
+  SubnetNodeIdHotkey,
+  SubnetNodeClass,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// Stake-weighted validator selection
+//
+//
+//
+//
+//
+//
+//
+
+#[test]
+fn test_get_validator_selection_weight() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, _, _, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Activated, |_| ());
+
+    let subnet_node_id: u32 = 1;
+    let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, subnet_node_id).unwrap();
+    let stake = AccountSubnetStake::<Test>::get(&hotkey, subnet_id);
+
+    assert_eq!(Network::get_validator_selection_weight(subnet_id, subnet_node_id), stake);
+
+    Network::do_increase_node_delegate_stake(subnet_id, subnet_node_id, 500);
+    assert_eq!(Network::get_validator_selection_weight(subnet_id, subnet_node_id), stake + 500);
+
+    assert_ok!(Network::do_set_validator_selection_stake_cap(stake));
+    assert_eq!(ValidatorSelectionStakeCap::<Test>::get(), stake);
+    assert_eq!(Network::get_validator_selection_weight(subnet_id, subnet_node_id), stake);
+  });
+}
+
+#[test]
+fn test_set_validator_selection_stake_cap_not_root() {
+  new_test_ext().execute_with(|| {
+    assert_noop!(
+      Network::set_validator_selection_stake_cap(
+        RuntimeOrigin::signed(account(0)),
+        1,
+      ),
+      DispatchError::BadOrigin
+    );
+  });
+}
+
+#[test]
+fn test_select_validator_committee_by_stake() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, total_subnet_nodes, _, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Activated, |_| ());
+
+    // --- Only subnet node 3 has a balance
+    for subnet_node_id in 1..total_subnet_nodes+1 {
+      if subnet_node_id == 3 {
+        continue
+      }
+      let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, subnet_node_id).unwrap();
+      let stake = AccountSubnetStake::<Test>::get(&hotkey, subnet_id);
+      Network::decrease_account_stake(&hotkey, subnet_id, stake);
+    }
+
+    let subnet_node_ids: Vec<u32> = (1..total_subnet_nodes+1).collect();
+    for block in 0..20 {
+      let (committee, _) = Network::select_validator_committee(block, subnet_id, subnet_node_ids.clone());
+      assert_eq!(committee, vec![3]);
+    }
+  });
+}
+
+#[test]
+fn test_choose_validator_matches_prediction() {
+  new_test_ext().execute_with(|| {
+    let subnet_path: Vec<u8> = "petals-team/StableBeluga2".into();
+    let (subnet_id, _, _, _) = build_epoch_subnet(subnet_path, 12, EpochSubnetStage::Activated, |_| ());
+
+    let epoch_length = EpochLength::get();
+    let block = System::block_number();
+    let epoch = block / epoch_length;
+
+    let predicted = Network::get_subnet_validator_selection(subnet_id, block);
+    // --- Reproducible from the same seed
+    assert_eq!(predicted, Network::get_subnet_validator_selection(subnet_id, block));
+
+    let subnet_node_ids: Vec<u32> = Network::get_classified_subnet_node_ids(subnet_id, &SubnetNodeClass::Validator, epoch);
+    Network::choose_validator(block, subnet_id, subnet_node_ids, 0, epoch);
+
+    assert_eq!(SubnetRewardsValidator::<Test>::get(subnet_id, epoch), Some(predicted.0[0]));
+
+    let proof = SubnetValidatorSelectionProof::<Test>::get(subnet_id, epoch).unwrap();
+    assert_eq!(proof.block, block);
+    assert_eq!(proof.randomness, Network::get_random_proof(block).0);
+    assert_eq!(Network::get_subnet_validator_proof(subnet_id, epoch), Some(proof));
+  });
+}
@@ -15,6 +15,7 @@
 
 use super::*;
 use frame_system::pallet_prelude::BlockNumberFor;
+use frame_support::weights::Weight;
 
 impl<T: Config> Pallet<T> {
   pub fn get_current_block_as_u64() -> u64 {
@@ -47,7 +48,10 @@ impl<T: Config> Pallet<T> {
     current_block.saturating_div(epoch_length)
   }
 
-  pub fn do_epoch_preliminaries(block: u32, epoch: u32, epoch_length: u32) {
+  /// Remove subnets that don't meet the requirements and choose the validators of the remaining subnets
+  ///
+  /// Returns the weight from the amount of subnets and their validators
+  pub fn do_epoch_preliminaries(block: u32, epoch: u32, epoch_length: u32) -> Weight {
     let max_subnet_penalty_count = MaxSubnetPenaltyCount::<T>::get();
     let subnet_registration_epochs = SubnetRegistrationEpochs::<T>::get();
     let subnet_activation_enactment_epochs = SubnetActivationEnactmentEpochs::<T>::get();
@@ -57,6 +61,7 @@ impl<T: Config> Pallet<T> {
     let total_subnets: u32 = subnets.len() as u32;
     let excess_subnets: bool = total_subnets > MaxSubnets::<T>::get();
     let mut subnet_delegate_stake: Vec<(Vec<u8>, u128)> = Vec::new();
+    let mut total_subnet_nodes: u32 = 0;
 
     for (subnet_id, data) in subnets {
       // ==========================
@@ -133,6 +138,7 @@ impl<T: Config> Pallet<T> {
       // --- Get all possible validators
       let subnet_node_ids: Vec<u32> = Self::get_classified_subnet_node_ids(subnet_id, &SubnetNodeClass::Validator, epoch);
       let subnet_nodes_count = subnet_node_ids.len();
+      total_subnet_nodes = total_subnet_nodes.saturating_add(subnet_nodes_count as u32);
       
       // --- Ensure min nodes are active
       // Only choose validator if min nodes are present
@@ -175,5 +181,7 @@ impl<T: Config> Pallet<T> {
 
     // --- TODO: Push subnet_ids and subnet_nodes into mapping and choose validator after possible removal of subnet
     // Avoid randomization if there are max subnets
+
+    T::WeightInfo::do_epoch_preliminaries(total_subnets, total_subnet_nodes)
   }
 }
\ No newline at end of file
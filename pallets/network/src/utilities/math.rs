@@ -19,8 +19,8 @@
 // @to-do: Increase precision to 100.0000
 
 use super::*;
-use libm::pow;
 use sp_core::U256;
+use sp_runtime::{FixedPointNumber, FixedU128, traits::{One, Zero}};
 
 impl<T: Config> Pallet<T> {
   // Percentages are defined by default with 2 decimals of precision (100.00). 
@@ -91,13 +91,96 @@ impl<T: Config> Pallet<T> {
     x.saturating_mul(Self::PERCENTAGE_FACTOR).saturating_div(y).saturating_add(u128::from(x % y == 0))
   }
 
-  /// Get percentage in decimal format that uses `PERCENTAGE_FACTOR` as f64
-  pub fn get_percent_as_f64(v: u128) -> f64 {
-    v as f64 / Self::PERCENTAGE_FACTOR as f64
+  /// Get percentage in decimal format that uses `PERCENTAGE_FACTOR` as a fixed-point number
+  pub fn get_percent_as_fixed(v: u128) -> FixedU128 {
+    FixedU128::checked_from_rational(v, Self::PERCENTAGE_FACTOR).unwrap_or_default()
   }
 
-  pub fn pow(x: f64, exp: f64) -> f64 {
-    pow(x, exp)
+  /// Fixed-point math
+  //
+  // Consensus-critical math is done in fixed-point so every node computes the same results.
+  // Results match the previous `f64` versions within `FIXED_POW_TOLERANCE`, see tests/fixed_point.rs
+
+  /// Bits of the fractional part of an exponent used in `fixed_pow`
+  pub const FIXED_POW_ITERATIONS: u32 = 40;
+
+  /// Relative tolerance of `fixed_pow` against `libm::pow`, in `PERCENTAGE_FACTOR` (0.0001%)
+  pub const FIXED_POW_TOLERANCE: u128 = 1000;
+
+  /// Integer square root, rounded down
+  pub fn isqrt(x: u128) -> u128 {
+    if x < 2 {
+      return x
+    }
+
+    // --- Newton's method from an initial guess above the root
+    let bits: u32 = 128 - x.leading_zeros();
+    let mut x0: u128 = 1u128 << ((bits + 1) / 2);
+    loop {
+      let x1: u128 = (x0 + x / x0) / 2;
+      if x1 >= x0 {
+        return x0
+      }
+      x0 = x1;
+    }
+  }
+
+  /// Square root of a fixed-point number, rounded down
+  pub fn fixed_sqrt(x: FixedU128) -> FixedU128 {
+    let inner: u128 = x.into_inner();
+    // sqrt(inner / DIV) * DIV == sqrt(inner * DIV)
+    match inner.checked_mul(FixedU128::DIV) {
+      Some(v) => FixedU128::from_inner(Self::isqrt(v)),
+      // --- Values above ~340 lose precision below 1e-9
+      None => FixedU128::from_inner(Self::isqrt(inner).saturating_mul(Self::isqrt(FixedU128::DIV))),
+    }
+  }
+
+  /// `base` to the power of `exp`, both fixed-point
+  ///
+  /// The integer part of `exp` is applied by squaring. The fractional part is applied bit by bit
+  /// with repeated square roots, i.e. base^0.75 == base^(1/2) * base^(1/4)
+  pub fn fixed_pow(base: FixedU128, exp: FixedU128) -> FixedU128 {
+    if exp.is_zero() {
+      return FixedU128::one()
+    }
+    if base.is_zero() {
+      return FixedU128::zero()
+    }
+
+    let exp_inner: u128 = exp.into_inner();
+
+    // --- Integer part
+    let mut result: FixedU128 = FixedU128::one();
+    let mut square: FixedU128 = base;
+    let mut int: u128 = exp_inner / FixedU128::DIV;
+    while int > 0 {
+      if int & 1 == 1 {
+        result = result.saturating_mul(square);
+      }
+      int >>= 1;
+      if int > 0 {
+        square = square.saturating_mul(square);
+      }
+    }
+
+    // --- Fractional part
+    let mut frac: u128 = exp_inner % FixedU128::DIV;
+    let mut bit: u128 = FixedU128::DIV;
+    let mut root: FixedU128 = base;
+    for _ in 0..Self::FIXED_POW_ITERATIONS {
+      if frac == 0 || result.is_zero() {
+        break
+      }
+      bit /= 2;
+      root = Self::fixed_sqrt(root);
+      if frac >= bit {
+        frac -= bit;
+        result = result.saturating_mul(root);
+      }
+    }
+
+    result
   }
 
 
@@ -153,4 +236,22 @@ impl<T: Config> Pallet<T> {
   pub fn wmul(x: u128, y: u128) -> u128 {
     ((x * y) + (1e+18 as u128 / 2)) / 1e+18 as u128
   }
+
+  /// Lower weighted median of `(value, weight)` pairs
+  /// `None` values are ordered lowest
+  pub fn get_weighted_median(mut values: Vec<(Option<u128>, u128)>) -> Option<u128> {
+    values.sort();
+
+    let total_weight: u128 = values.iter().fold(0u128, |acc, x| acc.saturating_add(x.1));
+
+    let mut cumulative_weight: u128 = 0;
+    for (value, weight) in values {
+      cumulative_weight = cumulative_weight.saturating_add(weight);
+      if cumulative_weight.saturating_mul(2) >= total_weight {
+        return value
+      }
+    }
+
+    None
+  }
 }
\ No newline at end of file
@@ -4,6 +4,9 @@ pub mod era;
 pub mod misc;
 pub mod subnet_node;
 pub mod subnet;
+pub mod subnet_removal;
 pub mod delegate_staking;
 pub mod randomness;
 pub mod math;
+#[cfg(any(feature = "try-runtime", test))]
+pub mod try_state;
@@ -92,6 +92,126 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
+  pub fn do_owner_update_commit_reveal(origin: T::RuntimeOrigin, subnet_id: u32, value: bool) -> DispatchResult {
+    let coldkey: T::AccountId = ensure_signed(origin)?;
+
+    ensure!(
+      Self::is_subnet_owner(&coldkey, subnet_id),
+      Error::<T>::NotSubnetOwner
+    );
+
+    SubnetCommitReveal::<T>::insert(subnet_id, value);
+
+    // --- Drop the commitments of the current epoch, validators submit directly instead
+    if !value {
+      let epoch: u32 = Self::get_current_epoch_as_u32();
+      let _ = SubnetRewardsCommit::<T>::clear_prefix((subnet_id, epoch), u32::MAX, None);
+    }
+
+    Self::deposit_event(Event::SubnetCommitRevealUpdate { 
+      subnet_id: subnet_id,
+      owner: coldkey, 
+      value: value 
+    });
+
+    Ok(())
+  }
+
+  pub fn do_owner_update_delegate_stake_token(origin: T::RuntimeOrigin, subnet_id: u32, value: bool) -> DispatchResult {
+    let coldkey: T::AccountId = ensure_signed(origin)?;
+
+    ensure!(
+      Self::is_subnet_owner(&coldkey, subnet_id),
+      Error::<T>::NotSubnetOwner
+    );
+
+    SubnetDelegateStakeToken::<T>::insert(subnet_id, value);
+
+    Self::deposit_event(Event::SubnetDelegateStakeTokenUpdate { 
+      subnet_id: subnet_id,
+      owner: coldkey, 
+      value: value 
+    });
+
+    Ok(())
+  }
+
+  pub fn do_owner_update_max_subnet_delegate_stake(origin: T::RuntimeOrigin, subnet_id: u32, value: Option<u128>) -> DispatchResult {
+    let coldkey: T::AccountId = ensure_signed(origin)?;
+
+    ensure!(
+      Self::is_subnet_owner(&coldkey, subnet_id),
+      Error::<T>::NotSubnetOwner
+    );
+
+    match value {
+      Some(max_subnet_delegate_stake) => {
+        ensure!(
+          max_subnet_delegate_stake > 0,
+          Error::<T>::InvalidMaxSubnetDelegateStake
+        );
+        MaxSubnetDelegateStake::<T>::insert(subnet_id, max_subnet_delegate_stake);
+      },
+      None => MaxSubnetDelegateStake::<T>::remove(subnet_id),
+    }
+
+    Self::deposit_event(Event::SubnetMaxDelegateStakeUpdate { 
+      subnet_id: subnet_id,
+      owner: coldkey, 
+      value: value 
+    });
+
+    Ok(())
+  }
+
+  pub fn do_owner_update_validator_committee_size(origin: T::RuntimeOrigin, subnet_id: u32, value: u32) -> DispatchResult {
+    let coldkey: T::AccountId = ensure_signed(origin)?;
+
+    ensure!(
+      Self::is_subnet_owner(&coldkey, subnet_id),
+      Error::<T>::NotSubnetOwner
+    );
+
+    ensure!(
+      value > 0 && value <= MaxValidatorCommitteeSize::<T>::get(),
+      Error::<T>::InvalidValidatorCommitteeSize
+    );
+
+    SubnetValidatorCommitteeSize::<T>::insert(subnet_id, value);
+
+    Self::deposit_event(Event::SubnetValidatorCommitteeSizeUpdate { 
+      subnet_id: subnet_id,
+      owner: coldkey, 
+      value: value 
+    });
+
+    Ok(())
+  }
+
+  pub fn do_owner_update_max_node_reward_share(origin: T::RuntimeOrigin, subnet_id: u32, value: u128) -> DispatchResult {
+    let coldkey: T::AccountId = ensure_signed(origin)?;
+
+    ensure!(
+      Self::is_subnet_owner(&coldkey, subnet_id),
+      Error::<T>::NotSubnetOwner
+    );
+
+    ensure!(
+      value > 0 && value <= Self::PERCENTAGE_FACTOR,
+      Error::<T>::InvalidMaxNodeRewardShare
+    );
+
+    MaxNodeRewardShare::<T>::insert(subnet_id, value);
+
+    Self::deposit_event(Event::SubnetMaxNodeRewardShareUpdate { 
+      subnet_id: subnet_id,
+      owner: coldkey, 
+      value: value 
+    });
+
+    Ok(())
+  }
+
   pub fn do_owner_add_to_coldkey_whitelist(origin: T::RuntimeOrigin, subnet_id: u32, coldkeys: BTreeSet<T::AccountId>) -> DispatchResult {
     let coldkey: T::AccountId = ensure_signed(origin)?;
 
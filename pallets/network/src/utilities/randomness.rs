@@ -43,13 +43,45 @@ impl<T: Config> Pallet<T> {
 		random_number % max
 	}
 
+	/// Get the randomness source output for ``seed`` and the block it became known
+	pub fn get_random_proof(seed: u32) -> (T::Hash, u32) {
+		let (random_seed, known_since) = T::Randomness::random(&(T::PalletId::get(), seed).encode());
+		(random_seed, Self::convert_block_as_u32(known_since))
+	}
+
+	/// Get a random number up to ``max`` for balance weighted draws
+	pub fn get_random_u128(max: u128, seed: u32) -> u128 {
+		if max == 0 {
+			return 0
+		}
+
+		let mut random_number = Self::generate_random_u128(seed);
+
+		// Best effort attempt to remove bias from modulus operator.
+		let mut i: u32 = 1;
+		while random_number >= u128::MAX - u128::MAX % max {
+			random_number = Self::generate_random_u128(seed.wrapping_add(i));
+			i += 1;
+		}
+
+		random_number % max
+	}
+
+	pub fn generate_random_u128(seed: u32) -> u128 {
+		let (random_seed, _) = T::Randomness::random(&(T::PalletId::get(), seed).encode());
+		let random_number = <u128>::decode(&mut random_seed.as_ref())
+			.expect("secure hashes should always be bigger than u128; qed");
+
+		random_number
+	}
+
 	/// Generate a random number from a given seed.
 	/// Note that there is potential bias introduced by using modulus operator.
 	/// You should call this function with different seed values until the random
 	/// number lies within `u32::MAX - u32::MAX % n`.
 	/// TODO: deal with randomness freshness
 	/// https://github.com/paritytech/substrate/issues/8311
-  /// This is not a secure random number generator but serves its purpose for choosing random numbers
+  /// Only as secure as the runtimes ``Randomness`` source
 	pub fn generate_random_number(seed: u32) -> u32 {
 		let (random_seed, _) = T::Randomness::random(&(T::PalletId::get(), seed).encode());
 		let random_number = <u32>::decode(&mut random_seed.as_ref())
@@ -48,6 +48,28 @@ impl<T: Config> Pallet<T> {
           Ok(())
         }
       );
+
+      // --- Remove from the validator committee
+      SubnetValidatorCommittee::<T>::mutate_exists(
+        subnet_id,
+        epoch,
+        |maybe_committee| {
+          if let Some(committee) = maybe_committee {
+            committee.retain(|x| *x != subnet_node_id);
+          }
+        }
+      );
+
+      // --- Remove from dissents
+      SubnetRewardsDissents::<T>::mutate_exists(
+        subnet_id,
+        epoch,
+        |maybe_dissents| {
+          if let Some(dissents) = maybe_dissents {
+            dissents.remove(&subnet_node_id);
+          }
+        }
+      );
     
       let subnet_node = SubnetNodesData::<T>::take(subnet_id, subnet_node_id);
 
@@ -67,7 +89,9 @@ impl<T: Config> Pallet<T> {
       // Reset sequential absent subnet node count
       SubnetNodePenalties::<T>::remove(subnet_id, subnet_node_id);
 
-      TotalActiveNodes::<T>::mutate(|n: &mut u32| n.saturating_dec());
+      if subnet_node.classification.class >= SubnetNodeClass::Queue {
+        Self::decrease_active_subnet_nodes(subnet_id);
+      }
 
 			Self::deposit_event(Event::SubnetNodeRemoved { subnet_id: subnet_id, subnet_node_id: subnet_node_id });
     }
@@ -224,6 +248,18 @@ impl<T: Config> Pallet<T> {
     0
   }
 
+  /// Count a subnet node activated into `Queue` or above
+  pub fn increase_active_subnet_nodes(subnet_id: u32) {
+    TotalActiveSubnetNodes::<T>::mutate(subnet_id, |n: &mut u32| n.saturating_inc());
+    TotalActiveNodes::<T>::mutate(|n: &mut u32| n.saturating_inc());
+  }
+
+  /// Uncount an activated subnet node on deactivation or removal
+  pub fn decrease_active_subnet_nodes(subnet_id: u32) {
+    TotalActiveSubnetNodes::<T>::mutate(subnet_id, |n: &mut u32| n.saturating_dec());
+    TotalActiveNodes::<T>::mutate(|n: &mut u32| n.saturating_dec());
+  }
+
   pub fn get_subnet_churn_limit(subnet_id: u32) -> u32 {
     let min_churn = 4;
     let active_nodes = TotalActiveSubnetNodes::<T>::get(subnet_id);
@@ -0,0 +1,167 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Cleans up the storage of removed subnets across blocks
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+  /// Teardown steps before the `clear_prefix` steps of `clear_subnet_storage`
+  const UNBOND_STAKE_STEP: u32 = 0;
+  const UNBOND_DELEGATE_STAKE_STEP: u32 = 1;
+  const UNBOND_NODE_DELEGATE_STAKE_STEP: u32 = 2;
+  const REMOVE_NODE_DELEGATE_POOLS_STEP: u32 = 3;
+  const CLEAR_STORAGE_STEP: u32 = 4;
+  /// Total teardown steps, one per storage map cleared by `clear_subnet_storage`
+  const SUBNET_REMOVAL_STEPS: u32 = Self::CLEAR_STORAGE_STEP + 20;
+
+  /// Weight of each storage item of a removed subnet cleaned up
+  pub fn subnet_removal_item_weight() -> Weight {
+    T::DbWeight::get().reads_writes(5, 5)
+  }
+
+  /// Clean up to `max_items` storage items of the removed subnets
+  ///
+  /// Subnets are cleaned up one at a time
+  pub fn do_remove_subnets_step(max_items: u32) -> Weight {
+    let mut weight: Weight = T::DbWeight::get().reads(1);
+    let mut items: u32 = 0;
+
+    while items < max_items {
+      let (subnet_id, mut cursor) = match RemovingSubnets::<T>::iter().next() {
+        Some(removing) => removing,
+        None => break,
+      };
+      weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+      let (completed, next_cursor, count) = Self::remove_subnet_step(
+        subnet_id,
+        cursor.step,
+        cursor.cursor.take(),
+        max_items - items,
+      );
+      weight = weight.saturating_add(Self::subnet_removal_item_weight().saturating_mul(count.into()));
+      // --- Steps with nothing left to clean up still count towards the limit
+      items = items.saturating_add(count.max(1));
+
+      if !completed {
+        cursor.cursor = next_cursor;
+        RemovingSubnets::<T>::insert(subnet_id, cursor);
+        continue
+      }
+
+      cursor.step.saturating_inc();
+      cursor.cursor = None;
+      if cursor.step >= Self::SUBNET_REMOVAL_STEPS {
+        RemovingSubnets::<T>::remove(subnet_id);
+        Self::deposit_event(Event::SubnetRemoved { subnet_id: subnet_id });
+      } else {
+        RemovingSubnets::<T>::insert(subnet_id, cursor);
+      }
+    }
+
+    weight
+  }
+
+  /// Run a teardown step of a removed subnet
+  ///
+  /// Returns if the step completed, the cursor to continue the step from, and the items cleaned up
+  fn remove_subnet_step(
+    subnet_id: u32,
+    step: u32,
+    cursor: Option<Vec<u8>>,
+    max_items: u32,
+  ) -> (bool, Option<Vec<u8>>, u32) {
+    if step == Self::UNBOND_STAKE_STEP {
+      let (completed, last_key, count, stake) = Self::unbond_subnet_stake(subnet_id, cursor, max_items);
+      if !stake.is_empty() {
+        Self::deposit_subnet_balances_unbonded(subnet_id, stake, Vec::new(), Vec::new());
+      }
+      (completed, Some(last_key), count)
+    } else if step == Self::UNBOND_DELEGATE_STAKE_STEP {
+      let (completed, last_key, count, delegate_stake) = Self::unbond_subnet_delegate_stake(subnet_id, cursor, max_items);
+      if !delegate_stake.is_empty() {
+        Self::deposit_subnet_balances_unbonded(subnet_id, Vec::new(), delegate_stake, Vec::new());
+      }
+      (completed, Some(last_key), count)
+    } else if step == Self::UNBOND_NODE_DELEGATE_STAKE_STEP {
+      let (completed, last_key, count, node_delegate_stake) = Self::unbond_subnet_node_delegate_stake(subnet_id, cursor, max_items);
+      if !node_delegate_stake.is_empty() {
+        Self::deposit_subnet_balances_unbonded(subnet_id, Vec::new(), Vec::new(), node_delegate_stake);
+      }
+      (completed, Some(last_key), count)
+    } else if step == Self::REMOVE_NODE_DELEGATE_POOLS_STEP {
+      let (completed, count) = Self::remove_subnet_node_delegate_pools(subnet_id, max_items);
+      (completed, None, count)
+    } else {
+      match Self::clear_subnet_storage(step.saturating_sub(Self::CLEAR_STORAGE_STEP), subnet_id, max_items, cursor.as_deref()) {
+        Some((maybe_cursor, count)) => (maybe_cursor.is_none(), maybe_cursor, count),
+        None => (true, None, 0),
+      }
+    }
+  }
+
+  /// Clear up to `limit` entries of a storage map prefixed by the subnet ID
+  ///
+  /// Returns the `clear_prefix` cursor and the entries removed, or None past the last map
+  ///
+  /// Keep `SUBNET_REMOVAL_STEPS` in sync when adding maps
+  fn clear_subnet_storage(
+    index: u32,
+    subnet_id: u32,
+    limit: u32,
+    cursor: Option<&[u8]>,
+  ) -> Option<(Option<Vec<u8>>, u32)> {
+    let result = match index {
+      0 => SubnetNodesData::<T>::clear_prefix(subnet_id, limit, cursor),
+      1 => PeerIdSubnetNode::<T>::clear_prefix(subnet_id, limit, cursor),
+      2 => BootstrapPeerIdSubnetNode::<T>::clear_prefix(subnet_id, limit, cursor),
+      3 => SubnetNodeUniqueParam::<T>::clear_prefix(subnet_id, limit, cursor),
+      4 => HotkeySubnetNodeId::<T>::clear_prefix(subnet_id, limit, cursor),
+      5 => SubnetNodeIdHotkey::<T>::clear_prefix(subnet_id, limit, cursor),
+      6 => SubnetNodeNonUniqueParamLastSet::<T>::clear_prefix(subnet_id, limit, cursor),
+      7 => SubnetNodePenalties::<T>::clear_prefix(subnet_id, limit, cursor),
+      8 => SubnetRewardsValidator::<T>::clear_prefix(subnet_id, limit, cursor),
+      9 => SubnetRewardsSubmission::<T>::clear_prefix(subnet_id, limit, cursor),
+      10 => SubnetRewardsDissents::<T>::clear_prefix(subnet_id, limit, cursor),
+      11 => SubnetRewardsCommit::<T>::clear_prefix((subnet_id,), limit, cursor),
+      12 => SubnetValidatorCommittee::<T>::clear_prefix(subnet_id, limit, cursor),
+      13 => SubnetValidatorSelectionProof::<T>::clear_prefix(subnet_id, limit, cursor),
+      14 => SubnetBackupValidators::<T>::clear_prefix(subnet_id, limit, cursor),
+      15 => SubnetCommitteeSubmissions::<T>::clear_prefix(subnet_id, limit, cursor),
+      16 => SubnetEpochRewards::<T>::clear_prefix(subnet_id, limit, cursor),
+      17 => SubnetNodeEpochRewards::<T>::clear_prefix((subnet_id,), limit, cursor),
+      18 => Proposals::<T>::clear_prefix(subnet_id, limit, cursor),
+      19 => TotalNodeDelegateStakeShares::<T>::clear_prefix(subnet_id, limit, cursor),
+      _ => return None,
+    };
+
+    Some((result.maybe_cursor, result.loops))
+  }
+
+  fn deposit_subnet_balances_unbonded(
+    subnet_id: u32,
+    stake: Vec<(T::AccountId, u128)>,
+    delegate_stake: Vec<(T::AccountId, u128)>,
+    node_delegate_stake: Vec<(T::AccountId, u32, u128)>,
+  ) {
+    Self::deposit_event(Event::SubnetBalancesUnbonded {
+      subnet_id: subnet_id,
+      stake: stake,
+      delegate_stake: delegate_stake,
+      node_delegate_stake: node_delegate_stake,
+    });
+  }
+}
@@ -0,0 +1,201 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use sp_runtime::TryRuntimeError;
+
+impl<T: Config> Pallet<T> {
+  /// Check the pallet's redundant totals and indexes against the storage they summarize
+  ///
+  /// * Runs in `try_state` and after every test in the mock runtime.
+  pub fn do_try_state() -> Result<(), TryRuntimeError> {
+    Self::check_stake()?;
+    Self::check_delegate_stake()?;
+    Self::check_reserved_delegate_stake_shares()?;
+    Self::check_node_delegate_stake()?;
+    Self::check_subnet_nodes()?;
+    Self::check_active_subnet_nodes()?;
+    Ok(())
+  }
+
+  /// `TotalStake` and `TotalSubnetStake` match the sum of `AccountSubnetStake`
+  fn check_stake() -> Result<(), TryRuntimeError> {
+    let mut subnet_stakes: BTreeMap<u32, u128> = BTreeMap::new();
+    let mut total_stake: u128 = 0;
+    for (_, subnet_id, stake) in AccountSubnetStake::<T>::iter() {
+      subnet_stakes.entry(subnet_id).or_default().saturating_accrue(stake);
+      total_stake.saturating_accrue(stake);
+    }
+
+    ensure!(
+      TotalStake::<T>::get() == total_stake,
+      "TotalStake doesn't match the sum of AccountSubnetStake"
+    );
+
+    for (subnet_id, total_subnet_stake) in TotalSubnetStake::<T>::iter() {
+      ensure!(
+        subnet_stakes.remove(&subnet_id).unwrap_or(0) == total_subnet_stake,
+        "TotalSubnetStake doesn't match the sum of AccountSubnetStake"
+      );
+    }
+
+    // --- Stake in subnets without a `TotalSubnetStake` entry
+    ensure!(
+      subnet_stakes.values().all(|stake| *stake == 0),
+      "AccountSubnetStake has no TotalSubnetStake"
+    );
+
+    Ok(())
+  }
+
+  /// `TotalDelegateStake` matches the sum of `TotalSubnetDelegateStakeBalance`
+  fn check_delegate_stake() -> Result<(), TryRuntimeError> {
+    let total_delegate_stake: u128 = TotalSubnetDelegateStakeBalance::<T>::iter_values()
+      .fold(0, |acc, balance| acc.saturating_add(balance));
+
+    ensure!(
+      TotalDelegateStake::<T>::get() == total_delegate_stake,
+      "TotalDelegateStake doesn't match the sum of TotalSubnetDelegateStakeBalance"
+    );
+
+    Ok(())
+  }
+
+  /// Reserved delegate stake shares are part of the accounts shares
+  fn check_reserved_delegate_stake_shares() -> Result<(), TryRuntimeError> {
+    for (account_id, subnet_id, reserved) in ReservedSubnetDelegateStakeShares::<T>::iter() {
+      ensure!(
+        AccountSubnetDelegateStakeShares::<T>::get(&account_id, subnet_id) >= reserved,
+        "ReservedSubnetDelegateStakeShares exceeds AccountSubnetDelegateStakeShares"
+      );
+    }
+
+    Ok(())
+  }
+
+  /// `TotalNodeDelegateStake` matches the sum of `TotalNodeDelegateStakeBalance`
+  fn check_node_delegate_stake() -> Result<(), TryRuntimeError> {
+    let total_node_delegate_stake: u128 = TotalNodeDelegateStakeBalance::<T>::iter_values()
+      .fold(0, |acc, balance| acc.saturating_add(balance));
+
+    ensure!(
+      TotalNodeDelegateStake::<T>::get() == total_node_delegate_stake,
+      "TotalNodeDelegateStake doesn't match the sum of TotalNodeDelegateStakeBalance"
+    );
+
+    Ok(())
+  }
+
+  /// Subnet nodes are indexed by hotkey and peer ID, both ways, and counted in `TotalSubnetNodes`
+  ///
+  /// Removed subnets are skipped while their storage is cleaned up
+  fn check_subnet_nodes() -> Result<(), TryRuntimeError> {
+    let mut subnet_nodes: BTreeMap<u32, u32> = BTreeMap::new();
+    for (subnet_id, subnet_node_id, subnet_node) in SubnetNodesData::<T>::iter() {
+      if RemovingSubnets::<T>::contains_key(subnet_id) {
+        continue
+      }
+      subnet_nodes.entry(subnet_id).or_default().saturating_inc();
+
+      ensure!(subnet_node.id == subnet_node_id, "Subnet node ID doesn't match its key");
+      ensure!(
+        HotkeySubnetNodeId::<T>::get(subnet_id, &subnet_node.hotkey) == Some(subnet_node_id),
+        "Subnet node hotkey isn't in HotkeySubnetNodeId"
+      );
+      ensure!(
+        SubnetNodeIdHotkey::<T>::get(subnet_id, subnet_node_id) == Some(subnet_node.hotkey),
+        "Subnet node isn't in SubnetNodeIdHotkey"
+      );
+      ensure!(
+        PeerIdSubnetNode::<T>::try_get(subnet_id, &subnet_node.peer_id) == Ok(subnet_node_id),
+        "Subnet node peer ID isn't in PeerIdSubnetNode"
+      );
+    }
+
+    for (subnet_id, hotkey, subnet_node_id) in HotkeySubnetNodeId::<T>::iter() {
+      if RemovingSubnets::<T>::contains_key(subnet_id) {
+        continue
+      }
+      ensure!(
+        SubnetNodeIdHotkey::<T>::get(subnet_id, subnet_node_id) == Some(hotkey),
+        "HotkeySubnetNodeId doesn't match SubnetNodeIdHotkey"
+      );
+    }
+
+    for (subnet_id, subnet_node_id, hotkey) in SubnetNodeIdHotkey::<T>::iter() {
+      if RemovingSubnets::<T>::contains_key(subnet_id) {
+        continue
+      }
+      ensure!(
+        HotkeySubnetNodeId::<T>::get(subnet_id, &hotkey) == Some(subnet_node_id),
+        "SubnetNodeIdHotkey doesn't match HotkeySubnetNodeId"
+      );
+    }
+
+    for (subnet_id, peer_id, subnet_node_id) in PeerIdSubnetNode::<T>::iter() {
+      if RemovingSubnets::<T>::contains_key(subnet_id) {
+        continue
+      }
+      let subnet_node = SubnetNodesData::<T>::try_get(subnet_id, subnet_node_id)
+        .map_err(|_| "PeerIdSubnetNode has no subnet node")?;
+      ensure!(subnet_node.peer_id == peer_id, "PeerIdSubnetNode doesn't match the subnet node peer ID");
+    }
+
+    for (subnet_id, total_subnet_nodes) in TotalSubnetNodes::<T>::iter() {
+      if RemovingSubnets::<T>::contains_key(subnet_id) {
+        continue
+      }
+      ensure!(
+        subnet_nodes.remove(&subnet_id).unwrap_or(0) == total_subnet_nodes,
+        "TotalSubnetNodes doesn't match SubnetNodesData"
+      );
+    }
+
+    ensure!(subnet_nodes.is_empty(), "SubnetNodesData has no TotalSubnetNodes");
+
+    Ok(())
+  }
+
+  /// `TotalActiveSubnetNodes` counts the activated subnet nodes of each subnet, and
+  /// `TotalActiveNodes` those of all subnets
+  fn check_active_subnet_nodes() -> Result<(), TryRuntimeError> {
+    let mut active_subnet_nodes: BTreeMap<u32, u32> = BTreeMap::new();
+    for (subnet_id, _, subnet_node) in SubnetNodesData::<T>::iter() {
+      if RemovingSubnets::<T>::contains_key(subnet_id) {
+        continue
+      }
+      if subnet_node.classification.class >= SubnetNodeClass::Queue {
+        active_subnet_nodes.entry(subnet_id).or_default().saturating_inc();
+      }
+    }
+
+    let mut total_active_nodes: u32 = 0;
+    for (subnet_id, total_active_subnet_nodes) in TotalActiveSubnetNodes::<T>::iter() {
+      ensure!(
+        active_subnet_nodes.remove(&subnet_id).unwrap_or(0) == total_active_subnet_nodes,
+        "TotalActiveSubnetNodes doesn't match the activated subnet nodes"
+      );
+      total_active_nodes.saturating_accrue(total_active_subnet_nodes);
+    }
+
+    ensure!(active_subnet_nodes.is_empty(), "Activated subnet nodes have no TotalActiveSubnetNodes");
+    ensure!(
+      TotalActiveNodes::<T>::get() == total_active_nodes,
+      "TotalActiveNodes doesn't match the sum of TotalActiveSubnetNodes"
+    );
+
+    Ok(())
+  }
+}
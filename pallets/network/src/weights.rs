@@ -1,11 +1,10 @@
-
-//! Autogenerated weights for `pallet_network`
+//! Weights for `pallet_network`
+//!
+//! Only `do_deactivation_ledger` is generated, by the command below with the Substrate benchmark
+//! CLI version 42.0.0 on 2025-02-09 (STEPS: `5`, REPEAT: `2`, CHAIN: `Some("dev")`).
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 42.0.0
-//! DATE: 2025-02-09, STEPS: `5`, REPEAT: `2`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Bob`, CPU: `11th Gen Intel(R) Core(TM) i7-11800H @ 2.30GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//! The weights of the rewards and epoch hooks are hand-written estimates. Their benchmarks are in
+//! `benchmarking.rs` and this file should be regenerated once they are run.
 
 // Executed Command:
 // ./target/release/solochain-template-node
@@ -32,6 +31,11 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_network`.
 pub trait WeightInfo {
 	fn do_deactivation_ledger(x: u32, d: u32, ) -> Weight;
+	fn begin_reward_subnets(s: u32, ) -> Weight;
+	fn reward_subnet(n: u32, d: u32, ) -> Weight;
+	fn reward_subnet_node(d: u32, ) -> Weight;
+	fn do_epoch_preliminaries(s: u32, n: u32, ) -> Weight;
+	fn do_queue(s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_network` using the Substrate node and recommended hardware.
@@ -68,6 +72,55 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 198).saturating_mul(d.into()))
 			.saturating_add(Weight::from_parts(0, 3112).saturating_mul(x.into()))
 	}
+	/// The range of component `s` is `[0, 64]`.
+	/// Not benchmarked, estimated from the storage accesses of the function.
+	fn begin_reward_subnets(s: u32, ) -> Weight {
+		Weight::from_parts(100_000_000, 10_000)
+			.saturating_add(Weight::from_parts(25_000_000, 5_000).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `n` is `[1, 512]`.
+	/// The range of component `d` is `[1, 512]`.
+	/// Not benchmarked, estimated from the storage accesses of the function.
+	fn reward_subnet(n: u32, d: u32, ) -> Weight {
+		Weight::from_parts(150_000_000, 20_000)
+			.saturating_add(Weight::from_parts(10_000_000, 6_000).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(100_000, 200).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// The range of component `d` is `[1, 512]`.
+	/// Not benchmarked, estimated from the storage accesses of the function.
+	fn reward_subnet_node(d: u32, ) -> Weight {
+		Weight::from_parts(150_000_000, 25_000)
+			.saturating_add(Weight::from_parts(150_000, 200).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// The range of component `s` is `[1, 64]`.
+	/// The range of component `n` is `[1, 32768]`.
+	/// Not benchmarked, estimated from the storage accesses of the function.
+	fn do_epoch_preliminaries(s: u32, n: u32, ) -> Weight {
+		Weight::from_parts(100_000_000, 15_000)
+			.saturating_add(Weight::from_parts(60_000_000, 6_000).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(25_000_000, 16_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(s.into())))
+	}
+	/// The range of component `s` is `[1, 64]`.
+	/// Not benchmarked, estimated from the storage accesses of the function.
+	fn do_queue(s: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 5_000)
+			.saturating_add(Weight::from_parts(50_000_000, 6_000).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(s.into())))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -103,4 +156,53 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 198).saturating_mul(d.into()))
 			.saturating_add(Weight::from_parts(0, 3112).saturating_mul(x.into()))
 	}
-}
\ No newline at end of file
+	/// The range of component `s` is `[0, 64]`.
+	/// Not benchmarked, estimated from the storage accesses of the function.
+	fn begin_reward_subnets(s: u32, ) -> Weight {
+		Weight::from_parts(100_000_000, 10_000)
+			.saturating_add(Weight::from_parts(25_000_000, 5_000).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `n` is `[1, 512]`.
+	/// The range of component `d` is `[1, 512]`.
+	/// Not benchmarked, estimated from the storage accesses of the function.
+	fn reward_subnet(n: u32, d: u32, ) -> Weight {
+		Weight::from_parts(150_000_000, 20_000)
+			.saturating_add(Weight::from_parts(10_000_000, 6_000).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(100_000, 200).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// The range of component `d` is `[1, 512]`.
+	/// Not benchmarked, estimated from the storage accesses of the function.
+	fn reward_subnet_node(d: u32, ) -> Weight {
+		Weight::from_parts(150_000_000, 25_000)
+			.saturating_add(Weight::from_parts(150_000, 200).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// The range of component `s` is `[1, 64]`.
+	/// The range of component `n` is `[1, 32768]`.
+	/// Not benchmarked, estimated from the storage accesses of the function.
+	fn do_epoch_preliminaries(s: u32, n: u32, ) -> Weight {
+		Weight::from_parts(100_000_000, 15_000)
+			.saturating_add(Weight::from_parts(60_000_000, 6_000).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(25_000_000, 16_000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(s.into())))
+	}
+	/// The range of component `s` is `[1, 64]`.
+	/// Not benchmarked, estimated from the storage accesses of the function.
+	fn do_queue(s: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 5_000)
+			.saturating_add(Weight::from_parts(50_000_000, 6_000).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(s.into())))
+	}
+}
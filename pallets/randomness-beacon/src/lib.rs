@@ -0,0 +1,331 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Commit-reveal randomness beacon among block producers.
+//!
+//! Each round is `RoundLength` blocks. During round `r` block producers commit to the hash of a
+//! secret, during round `r + 1` they reveal it, and at the start of round `r + 2` the revealed
+//! secrets are combined into the output of round `r`. The output of each round is chained with
+//! the previous output.
+//!
+//! Rounds without reveals mix in the parent hash of the finalizing block instead, so their output
+//! can't be computed in advance from the previous output. This fallback is only as strong as the
+//! block hash and can be influenced by the block author.
+//!
+//! Secrets are mixed in hashed under `MIX_DOMAIN`, not as their commitments, so the output of a
+//! round isn't known before its secrets are revealed.
+//!
+//! Every reveal of a round is stored with its commitment so anyone can audit the output of a
+//! round by recomputing it. A producer can only bias the output by withholding its reveal.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+  pallet_prelude::*,
+  traits::{Contains, Randomness},
+};
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::traits::{Hash, Saturating, Zero};
+use sp_std::vec::Vec;
+
+/// Domain of the hash a revealed secret is mixed into the output with
+pub const MIX_DOMAIN: [u8; 10] = *b"beacon-mix";
+
+#[frame_support::pallet]
+pub mod pallet {
+  use super::*;
+
+  #[pallet::pallet]
+  pub struct Pallet<T>(_);
+
+  #[pallet::config]
+  pub trait Config: frame_system::Config {
+    /// `randomness-beacon` events
+    type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+    /// Accounts of the block producers allowed to take part in the beacon
+    type Producers: Contains<Self::AccountId>;
+
+    /// Blocks per beacon round
+    #[pallet::constant]
+    type RoundLength: Get<BlockNumberFor<Self>>;
+
+    /// Max producers that can reveal in a round
+    #[pallet::constant]
+    type MaxProducers: Get<u32>;
+
+    /// Max length of a revealed secret
+    #[pallet::constant]
+    type MaxSecretLength: Get<u32>;
+
+    /// Amount of finalized rounds kept for auditing
+    #[pallet::constant]
+    type HistoryDepth: Get<BlockNumberFor<Self>>;
+  }
+
+  #[pallet::event]
+  #[pallet::generate_deposit(pub(super) fn deposit_event)]
+  pub enum Event<T: Config> {
+    Committed { round: BlockNumberFor<T>, producer: T::AccountId },
+    Revealed { round: BlockNumberFor<T>, producer: T::AccountId },
+    RoundFinalized { round: BlockNumberFor<T>, output: T::Hash, reveals: u32 },
+  }
+
+  #[pallet::error]
+  pub enum Error<T> {
+    /// Caller is not a block producer
+    NotProducer,
+    /// Producer already committed this round
+    AlreadyCommitted,
+    /// No commitment of the producer for the previous round
+    NotCommitted,
+    /// Secret doesn't match the commitment
+    InvalidReveal,
+    /// Max reveals of the round reached
+    TooManyReveals,
+  }
+
+  /// Revealed secret of a block producer
+  #[derive(Encode, Decode, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+  #[scale_info(skip_type_params(T))]
+  #[codec(mel_bound())]
+  pub struct Reveal<T: Config> {
+    pub producer: T::AccountId,
+    pub commitment: T::Hash,
+    pub secret: BoundedVec<u8, T::MaxSecretLength>,
+  }
+
+  /// Output of a finalized round
+  #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+  pub struct RoundOutput<Hash, BlockNumber> {
+    pub output: Hash,
+    pub finalized_at: BlockNumber,
+  }
+
+  #[pallet::storage] // round => producer => commitment
+  pub type Commitments<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    BlockNumberFor<T>,
+    Blake2_128Concat,
+    T::AccountId,
+    T::Hash,
+  >;
+
+  #[pallet::storage] // round => reveals
+  pub type Reveals<T: Config> = StorageMap<
+    _,
+    Twox64Concat,
+    BlockNumberFor<T>,
+    BoundedVec<Reveal<T>, T::MaxProducers>,
+    ValueQuery,
+  >;
+
+  #[pallet::storage] // round => output
+  pub type Rounds<T: Config> = StorageMap<
+    _,
+    Twox64Concat,
+    BlockNumberFor<T>,
+    RoundOutput<T::Hash, BlockNumberFor<T>>,
+  >;
+
+  #[pallet::storage]
+  pub type LatestRound<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+  #[pallet::call]
+  impl<T: Config> Pallet<T> {
+    /// Commit to a secret for the current round
+    ///
+    /// # Arguments
+    ///
+    /// * `commitment` - Hash of the secret.
+    ///
+    #[pallet::call_index(0)]
+    #[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+    pub fn commit(
+      origin: OriginFor<T>,
+      commitment: T::Hash,
+    ) -> DispatchResult {
+      let producer: T::AccountId = ensure_signed(origin)?;
+
+      ensure!(T::Producers::contains(&producer), Error::<T>::NotProducer);
+
+      let round = Self::current_round();
+
+      ensure!(
+        !Commitments::<T>::contains_key(round, &producer),
+        Error::<T>::AlreadyCommitted
+      );
+
+      Commitments::<T>::insert(round, &producer, commitment);
+
+      Self::deposit_event(Event::Committed { round, producer });
+
+      Ok(())
+    }
+
+    /// Reveal the secret committed in the previous round
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - Secret of the commitment.
+    ///
+    #[pallet::call_index(1)]
+    #[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+    pub fn reveal(
+      origin: OriginFor<T>,
+      secret: BoundedVec<u8, T::MaxSecretLength>,
+    ) -> DispatchResult {
+      let producer: T::AccountId = ensure_signed(origin)?;
+
+      let current_round = Self::current_round();
+      ensure!(!current_round.is_zero(), Error::<T>::NotCommitted);
+      let round = current_round.saturating_sub(1u32.into());
+
+      // --- Taking the commitment prevents revealing twice
+      let commitment = Commitments::<T>::take(round, &producer).ok_or(Error::<T>::NotCommitted)?;
+
+      ensure!(
+        T::Hashing::hash(&secret) == commitment,
+        Error::<T>::InvalidReveal
+      );
+
+      Reveals::<T>::try_mutate(round, |reveals| {
+        reveals.try_push(Reveal {
+          producer: producer.clone(),
+          commitment: commitment,
+          secret: secret,
+        })
+      }).map_err(|_| Error::<T>::TooManyReveals)?;
+
+      Self::deposit_event(Event::Revealed { round, producer });
+
+      Ok(())
+    }
+  }
+
+  #[pallet::hooks]
+  impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+    fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+      let round_length = T::RoundLength::get();
+      if round_length.is_zero() || !(block_number % round_length).is_zero() {
+        return Weight::zero()
+      }
+
+      // --- Reveals of round `r` close when round `r + 2` starts
+      let current_round = block_number / round_length;
+      if current_round < 2u32.into() {
+        return Weight::zero()
+      }
+
+      let round = current_round.saturating_sub(2u32.into());
+      let reveals: u32 = Self::finalize_round(round, block_number);
+
+      T::DbWeight::get().reads_writes(3, 4)
+        .saturating_add(T::DbWeight::get().writes(reveals.into()))
+    }
+  }
+}
+
+impl<T: Config> Pallet<T> {
+  pub fn current_round() -> BlockNumberFor<T> {
+    let round_length = T::RoundLength::get();
+    if round_length.is_zero() {
+      return Zero::zero()
+    }
+    frame_system::Pallet::<T>::block_number() / round_length
+  }
+
+  /// Combine the reveals of ``round`` into its output and prune old rounds
+  ///
+  /// * Rounds without reveals use the parent hash of ``block_number`` as their entropy.
+  ///
+  /// Returns the amount of reveals
+  pub fn finalize_round(round: BlockNumberFor<T>, block_number: BlockNumberFor<T>) -> u32 {
+    let reveals: Vec<Reveal<T>> = Reveals::<T>::get(round).into_inner();
+
+    // --- XOR the secret hashes so the order of reveals doesn't change the output
+    let mut combined: T::Hash = T::Hash::default();
+    for reveal in &reveals {
+      let secret_hash = Self::mix_hash(&reveal.secret);
+      for (byte, secret_byte) in combined.as_mut().iter_mut().zip(secret_hash.as_ref()) {
+        *byte ^= secret_byte;
+      }
+    }
+
+    let previous_output: T::Hash = Self::latest_output().output;
+    let output: T::Hash = if reveals.is_empty() {
+      let parent_hash: T::Hash = frame_system::Pallet::<T>::parent_hash();
+      T::Hashing::hash_of(&(previous_output, combined, parent_hash))
+    } else {
+      T::Hashing::hash_of(&(previous_output, combined))
+    };
+
+    Rounds::<T>::insert(round, RoundOutput {
+      output: output,
+      finalized_at: block_number,
+    });
+    LatestRound::<T>::put(round);
+
+    // --- Commitments that were never revealed
+    let _ = Commitments::<T>::clear_prefix(round, u32::MAX, None);
+
+    // --- Prune rounds past the history depth
+    if round >= T::HistoryDepth::get() {
+      let pruned_round = round.saturating_sub(T::HistoryDepth::get());
+      Rounds::<T>::remove(pruned_round);
+      Reveals::<T>::remove(pruned_round);
+    }
+
+    Self::deposit_event(Event::RoundFinalized { round, output, reveals: reveals.len() as u32 });
+
+    reveals.len() as u32
+  }
+
+  /// Hash of a revealed secret mixed into the output
+  ///
+  /// Differs from the commitment of the secret, which is public before the reveal
+  pub fn mix_hash(secret: &[u8]) -> T::Hash {
+    T::Hashing::hash_of(&(MIX_DOMAIN, secret))
+  }
+
+  /// Output of the latest finalized round
+  pub fn latest_output() -> RoundOutput<T::Hash, BlockNumberFor<T>> {
+    match LatestRound::<T>::get() {
+      Some(round) => Rounds::<T>::get(round).unwrap_or_default(),
+      None => RoundOutput::default(),
+    }
+  }
+}
+
+impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
+  /// Random value for ``subject`` from the latest finalized round
+  ///
+  /// The returned block number is the block the round was finalized in. Before the first round is
+  /// finalized the output is the default hash.
+  fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+    let round_output = Self::latest_output();
+    (
+      T::Hashing::hash_of(&(subject, round_output.output)),
+      round_output.finalized_at,
+    )
+  }
+}
@@ -0,0 +1,230 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use super::*;
+use crate as pallet_randomness_beacon;
+
+use frame_support::{assert_noop, assert_ok, derive_impl, traits::{ConstU32, ConstU64, Hooks}};
+use sp_runtime::{traits::BlakeTwo256, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+  pub enum Test
+  {
+    System: frame_system,
+    RandomnessBeacon: pallet_randomness_beacon,
+  }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+  type Block = Block;
+}
+
+pub struct TestProducers;
+impl Contains<u64> for TestProducers {
+  fn contains(who: &u64) -> bool {
+    *who == A || *who == B
+  }
+}
+
+impl Config for Test {
+  type RuntimeEvent = RuntimeEvent;
+  type Producers = TestProducers;
+  type RoundLength = ConstU64<10>;
+  type MaxProducers = ConstU32<2>;
+  type MaxSecretLength = ConstU32<32>;
+  type HistoryDepth = ConstU64<2>;
+}
+
+const A: u64 = 1;
+const B: u64 = 2;
+const C: u64 = 3;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+  let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+  let mut ext: sp_io::TestExternalities = t.into();
+  ext.execute_with(|| System::set_block_number(1));
+  ext
+}
+
+fn run_to_block(n: u64) {
+  while System::block_number() < n {
+    System::set_parent_hash(BlakeTwo256::hash_of(&System::block_number()));
+    System::set_block_number(System::block_number() + 1);
+    RandomnessBeacon::on_initialize(System::block_number());
+  }
+}
+
+fn secret(value: &[u8]) -> BoundedVec<u8, ConstU32<32>> {
+  value.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn commit_requires_producer() {
+  new_test_ext().execute_with(|| {
+    assert_noop!(
+      RandomnessBeacon::commit(RuntimeOrigin::signed(C), BlakeTwo256::hash(b"c")),
+      Error::<Test>::NotProducer
+    );
+  });
+}
+
+#[test]
+fn commit_once_per_round() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(A), BlakeTwo256::hash(b"a")));
+    assert_noop!(
+      RandomnessBeacon::commit(RuntimeOrigin::signed(A), BlakeTwo256::hash(b"a")),
+      Error::<Test>::AlreadyCommitted
+    );
+  });
+}
+
+#[test]
+fn reveal_must_match_commitment() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(A), BlakeTwo256::hash(b"a")));
+
+    // --- Reveals open on the next round
+    assert_noop!(
+      RandomnessBeacon::reveal(RuntimeOrigin::signed(A), secret(b"a")),
+      Error::<Test>::NotCommitted
+    );
+
+    run_to_block(10);
+
+    assert_noop!(
+      RandomnessBeacon::reveal(RuntimeOrigin::signed(A), secret(b"b")),
+      Error::<Test>::InvalidReveal
+    );
+    assert_ok!(RandomnessBeacon::reveal(RuntimeOrigin::signed(A), secret(b"a")));
+    assert_noop!(
+      RandomnessBeacon::reveal(RuntimeOrigin::signed(A), secret(b"a")),
+      Error::<Test>::NotCommitted
+    );
+  });
+}
+
+#[test]
+fn round_output_is_auditable() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(A), BlakeTwo256::hash(b"a")));
+    assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(B), BlakeTwo256::hash(b"b")));
+
+    run_to_block(10);
+
+    // --- Reveal order doesn't change the output
+    assert_ok!(RandomnessBeacon::reveal(RuntimeOrigin::signed(B), secret(b"b")));
+    assert_ok!(RandomnessBeacon::reveal(RuntimeOrigin::signed(A), secret(b"a")));
+
+    assert_eq!(RandomnessBeacon::random(b"subject").1, 0);
+
+    run_to_block(20);
+
+    let reveals = Reveals::<Test>::get(0);
+    assert_eq!(reveals.len(), 2);
+
+    let mut combined = <Test as frame_system::Config>::Hash::default();
+    for reveal in reveals.iter() {
+      let secret_hash = BlakeTwo256::hash_of(&(MIX_DOMAIN, &reveal.secret[..]));
+      for (byte, secret_byte) in combined.as_mut().iter_mut().zip(secret_hash.as_ref()) {
+        *byte ^= secret_byte;
+      }
+    }
+    let expected = BlakeTwo256::hash_of(&(<Test as frame_system::Config>::Hash::default(), combined));
+
+    let round_output = Rounds::<Test>::get(0).unwrap();
+    assert_eq!(round_output.output, expected);
+    assert_eq!(round_output.finalized_at, 20);
+    assert_eq!(LatestRound::<Test>::get(), Some(0));
+
+    let (random, known_since) = RandomnessBeacon::random(b"subject");
+    assert_eq!(random, BlakeTwo256::hash_of(&(&b"subject"[..], expected)));
+    assert_eq!(known_since, 20);
+  });
+}
+
+#[test]
+fn round_output_not_known_from_commitments() {
+  new_test_ext().execute_with(|| {
+    let commitment_a = BlakeTwo256::hash(b"a");
+    let commitment_b = BlakeTwo256::hash(b"b");
+    assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(A), commitment_a));
+    assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(B), commitment_b));
+
+    run_to_block(10);
+
+    assert_ok!(RandomnessBeacon::reveal(RuntimeOrigin::signed(A), secret(b"a")));
+    assert_ok!(RandomnessBeacon::reveal(RuntimeOrigin::signed(B), secret(b"b")));
+
+    run_to_block(20);
+
+    // --- Combining the public commitments doesn't give the output
+    let mut combined = <Test as frame_system::Config>::Hash::default();
+    for commitment in [commitment_a, commitment_b] {
+      for (byte, commitment_byte) in combined.as_mut().iter_mut().zip(commitment.as_ref()) {
+        *byte ^= commitment_byte;
+      }
+    }
+    let from_commitments = BlakeTwo256::hash_of(&(<Test as frame_system::Config>::Hash::default(), combined));
+
+    assert_ne!(Rounds::<Test>::get(0).unwrap().output, from_commitments);
+    assert_ne!(RandomnessBeacon::mix_hash(b"a"), commitment_a);
+  });
+}
+
+#[test]
+fn rounds_without_reveals_still_change() {
+  new_test_ext().execute_with(|| {
+    run_to_block(30);
+
+    let first = Rounds::<Test>::get(0).unwrap().output;
+    let second = Rounds::<Test>::get(1).unwrap().output;
+    assert_ne!(first, second);
+  });
+}
+
+#[test]
+fn rounds_without_reveals_use_parent_hash() {
+  new_test_ext().execute_with(|| {
+    run_to_block(20);
+
+    // --- Parent hash of the finalizing block 20
+    let parent_hash = BlakeTwo256::hash_of(&19u64);
+    let hash = <Test as frame_system::Config>::Hash::default();
+    let expected = BlakeTwo256::hash_of(&(hash, hash, parent_hash));
+
+    assert_eq!(Rounds::<Test>::get(0).unwrap().output, expected);
+    assert_ne!(expected, BlakeTwo256::hash_of(&(hash, hash)));
+  });
+}
+
+#[test]
+fn old_rounds_are_pruned() {
+  new_test_ext().execute_with(|| {
+    run_to_block(50);
+
+    // --- Rounds 0..=3 finalized, history depth of 2
+    assert_eq!(LatestRound::<Test>::get(), Some(3));
+    assert!(Rounds::<Test>::get(0).is_none());
+    assert!(Rounds::<Test>::get(1).is_none());
+    assert!(Rounds::<Test>::get(2).is_some());
+    assert!(Rounds::<Test>::get(3).is_some());
+  });
+}
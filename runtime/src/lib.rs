@@ -296,7 +296,19 @@ impl pallet_multisig::Config for Runtime {
 	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
 }
 
-impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+parameter_types! {
+	pub const RandomnessRoundLength: BlockNumber = 10;
+	pub const RandomnessHistoryDepth: BlockNumber = 1000;
+}
+
+impl pallet_randomness_beacon::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Producers = AuraProducers;
+	type RoundLength = RandomnessRoundLength;
+	type MaxProducers = ConstU32<32>;
+	type MaxSecretLength = ConstU32<32>;
+	type HistoryDepth = RandomnessHistoryDepth;
+}
 
 impl pallet_utility::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -530,9 +542,10 @@ impl pallet_network::Config for Runtime {
 	type MaxDelegateStakeUnlockings = MaxDelegateStakeUnlockings;
 	type MaxStakeUnlockings = MaxStakeUnlockings;
 	type StakeCooldownEpochs = StakeCooldownEpochs;
-	type Randomness = InsecureRandomnessCollectiveFlip;
+	type Randomness = RandomnessBeacon;
 	type MinProposalStake = MinProposalStake;
 	type TreasuryAccount = TreasuryAccount;
+	type RewardPolicy = pallet_network::SqrtDelegateStakePolicy;
 }
 
 pub struct AuraAccountAdapter;
@@ -546,6 +559,16 @@ impl frame_support::traits::FindAuthor<AccountId> for AuraAccountAdapter {
 	}
 }
 
+/// Block producers taking part in the randomness beacon
+pub struct AuraProducers;
+impl frame_support::traits::Contains<AccountId> for AuraProducers {
+	fn contains(who: &AccountId) -> bool {
+		pallet_aura::Authorities::<Runtime>::get().iter().any(|k| {
+			AccountId::try_from(k.as_ref()).ok().as_ref() == Some(who)
+		})
+	}
+}
+
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = AuraAccountAdapter;
 	type EventHandler =  ();
@@ -579,12 +602,99 @@ impl pallet_rewards::Config for Runtime {
 	type IncreaseStakeVault = Network;
 }
 
+/// Atomic swap of balances or of the delegate stake shares of subnets with a delegate stake token
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, MaxEncodedLen, scale_info::TypeInfo)]
+pub enum SwapAction {
+	Balance(pallet_atomic_swap::BalanceSwapAction<AccountId, Balances>),
+	DelegateStakeToken { subnet_id: u32, shares: u128 },
+}
+
+impl pallet_atomic_swap::SwapAction<AccountId, Runtime> for SwapAction {
+	fn reserve(&self, source: &AccountId) -> frame_support::dispatch::DispatchResult {
+		match self {
+			SwapAction::Balance(action) => 
+				pallet_atomic_swap::SwapAction::<AccountId, Runtime>::reserve(action, source),
+			SwapAction::DelegateStakeToken { subnet_id, shares } => 
+				Network::reserve_delegate_stake_token(source, *subnet_id, *shares),
+		}
+	}
+
+	fn claim(&self, source: &AccountId, target: &AccountId) -> bool {
+		match self {
+			SwapAction::Balance(action) => 
+				pallet_atomic_swap::SwapAction::<AccountId, Runtime>::claim(action, source, target),
+			SwapAction::DelegateStakeToken { subnet_id, shares } => 
+				Network::repatriate_reserved_delegate_stake_token(source, target, *subnet_id, *shares).is_ok(),
+		}
+	}
+
+	fn weight(&self) -> Weight {
+		match self {
+			SwapAction::Balance(action) => 
+				pallet_atomic_swap::SwapAction::<AccountId, Runtime>::weight(action),
+			SwapAction::DelegateStakeToken { .. } => 
+				RocksDbWeight::get().reads_writes(8, 6),
+		}
+	}
+
+	fn cancel(&self, source: &AccountId) {
+		match self {
+			SwapAction::Balance(action) => 
+				pallet_atomic_swap::SwapAction::<AccountId, Runtime>::cancel(action, source),
+			SwapAction::DelegateStakeToken { subnet_id, shares } => 
+				Network::unreserve_delegate_stake_token(source, *subnet_id, *shares),
+		}
+	}
+}
+
 impl pallet_atomic_swap::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type SwapAction = pallet_atomic_swap::BalanceSwapAction<AccountId, Balances>;
+	type SwapAction = SwapAction;
 	type ProofLimit = ConstU32<1024>;
 }
 
+/// Pending swap stored before `SwapAction` wrapped balance swaps
+///
+/// The action was a bare `BalanceSwapAction`, which encodes as its value. `SwapAction::Balance`
+/// encodes as the same value prefixed by its variant index `0`
+#[derive(Decode)]
+pub struct BalancePendingSwap {
+	pub source: AccountId,
+	pub hash_type: pallet_atomic_swap::HashType,
+	pub action: pallet_atomic_swap::BalanceSwapAction<AccountId, Balances>,
+	pub end_block: BlockNumber,
+}
+
+/// Migrates `PendingSwaps` of balances to `SwapAction::Balance`
+pub struct InnerMigrateBalancePendingSwaps;
+
+impl frame_support::traits::UncheckedOnRuntimeUpgrade for InnerMigrateBalancePendingSwaps {
+	fn on_runtime_upgrade() -> Weight {
+		let mut translated: u64 = 0;
+
+		pallet_atomic_swap::PendingSwaps::<Runtime>::translate::<BalancePendingSwap, _>(|_, _, swap| {
+			translated += 1;
+			Some(pallet_atomic_swap::PendingSwap {
+				source: swap.source,
+				hash_type: swap.hash_type,
+				action: SwapAction::Balance(swap.action),
+				end_block: swap.end_block,
+			})
+		});
+
+		RocksDbWeight::get().reads_writes(translated, translated)
+	}
+}
+
+/// Migrates `PendingSwaps` from pallet-atomic-swap storage version 0, before `SwapAction`, to 1
+pub type MigrateBalancePendingSwaps = frame_support::migrations::VersionedMigration<
+	0,
+	1,
+	InnerMigrateBalancePendingSwaps,
+	pallet_atomic_swap::Pallet<Runtime>,
+	RocksDbWeight,
+>;
+
 parameter_types! {
 	pub const Burn: Permill = Permill::from_percent(50);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
@@ -658,8 +768,8 @@ mod runtime {
 	#[runtime::pallet_index(8)]
 	pub type Multisig = pallet_multisig;
 
-	#[runtime::pallet_index(9)]
-	pub type InsecureRandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
+	// #[runtime::pallet_index(9)]
+	// pub type InsecureRandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
 
 	#[runtime::pallet_index(10)]
 	pub type Network = pallet_network;
@@ -693,6 +803,9 @@ mod runtime {
 
 	#[runtime::pallet_index(19)]
 	pub type TxPause = pallet_tx_pause;	
+
+	#[runtime::pallet_index(20)]
+	pub type RandomnessBeacon = pallet_randomness_beacon;
 }
 
 /// The address format for describing accounts.
@@ -717,7 +830,16 @@ pub type SignedExtra = (
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	frame_support::migrations::RemovePallet<InsecureRandomnessCollectiveFlipPalletName, RocksDbWeight>,
+	pallet_network::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_network::migrations::v2::MigrateV1ToV2<Runtime>,
+	MigrateBalancePendingSwaps,
+);
+
+parameter_types! {
+	pub const InsecureRandomnessCollectiveFlipPalletName: &'static str = "InsecureRandomnessCollectiveFlip";
+}
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -937,6 +1059,22 @@ impl_runtime_apis! {
 			let result = Network::get_consensus_data(subnet_id, epoch);
 			result.encode()
 		}
+		fn get_subnet_validator_committee(subnet_id: u32, epoch: u32) -> Vec<u8> {
+			let result = Network::get_subnet_validator_committee(subnet_id, epoch);
+			result.encode()
+		}
+		fn get_subnet_validator_selection(subnet_id: u32, block: u32) -> Vec<u8> {
+			let result = Network::get_subnet_validator_selection(subnet_id, block);
+			result.encode()
+		}
+		fn get_subnet_validator_proof(subnet_id: u32, epoch: u32) -> Vec<u8> {
+			let result = Network::get_subnet_validator_proof(subnet_id, epoch);
+			result.encode()
+		}
+		fn get_subnet_rewards_preview(subnet_id: u32, epoch: u32) -> Vec<u8> {
+			let result = Network::get_subnet_rewards_preview(subnet_id, epoch);
+			result.encode()
+		}
 		fn get_minimum_subnet_nodes(memory_mb: u128) -> u32 {
 			let result = Network::get_minimum_subnet_nodes(memory_mb);
 			result